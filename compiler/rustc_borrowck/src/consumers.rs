@@ -1,24 +1,33 @@
 //! This file provides API for compiler consumers.
 
+use rustc_data_structures::fx::FxIndexMap;
 use rustc_hir::def_id::LocalDefId;
 use rustc_index::{IndexSlice, IndexVec};
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_middle::mir::{Body, Promoted};
 use rustc_middle::traits::DefiningAnchor;
-use rustc_middle::ty::TyCtxt;
+use rustc_middle::ty::{self, OpaqueHiddenType, OpaqueTypeKey, TyCtxt};
+use rustc_mir_dataflow::move_paths::MoveData;
+use std::collections::BTreeMap;
 use std::rc::Rc;
 
 use crate::borrow_set::BorrowSet;
+use crate::facts::AllFactsExt;
 
 pub use super::{
     constraints::OutlivesConstraint,
     dataflow::{calculate_borrows_out_of_scope_at_location, BorrowIndex, Borrows},
-    facts::{AllFacts as PoloniusInput, RustcFacts},
+    diagnostics::{RegionErrorHook, RegionErrorKind, RegionErrors},
+    facts::{
+        AllFacts as PoloniusInput, PoloniusBlockFactHook, PoloniusFact, PoloniusFactHook,
+        RustcFacts,
+    },
     location::{LocationTable, RichLocation},
-    nll::PoloniusOutput,
+    nll::{closure_outlives_requirements, NllTimings, OutlivesRequirementSubject, PoloniusOutput},
     place_ext::PlaceExt,
     places_conflict::{places_conflict, PlaceConflictBias},
     region_infer::RegionInferenceContext,
+    universal_regions::{DefiningTy, RegionClassification, UniversalRegions},
 };
 
 /// Options determining the output behavior of [`get_body_with_borrowck_facts`].
@@ -84,6 +93,74 @@ pub struct BodyWithBorrowckFacts<'tcx> {
     /// Polonius output facts. Populated when using
     /// [`ConsumerOptions::PoloniusOutputFacts`].
     pub output_facts: Option<Rc<PoloniusOutput>>,
+    /// The move paths of `body`, which the `MovePathIndex`es in the input facts refer to,
+    /// e.g. in `path_moved_at_base`. Populated along with the input facts.
+    pub move_data: Option<MoveData<'tcx>>,
+    /// How long computing the regions of `body` took, phase by phase.
+    pub timings: NllTimings,
+    /// The region variable standing for each placeholder region, i.e. each bound region of a
+    /// higher-ranked type that was instantiated while type checking `body`. These regions appear in
+    /// the constraints of [`Self::region_inference_context`].
+    pub placeholder_regions: FxIndexMap<ty::PlaceholderRegion, ty::Region<'tcx>>,
+    /// The hidden type of each opaque type defined by `body`, remapped to the generic parameters
+    /// of the opaque type as for `mir_borrowck`. Empty when
+    /// [`ConsumerHooks::remap_opaque_types`] is off.
+    pub opaque_type_values: FxIndexMap<LocalDefId, OpaqueHiddenType<'tcx>>,
+    /// The hidden types of the opaque types as found by type checking `body`, in terms of its own
+    /// regions. Only populated when [`ConsumerHooks::remap_opaque_types`] is off.
+    pub raw_opaque_type_values: Option<FxIndexMap<OpaqueTypeKey<'tcx>, OpaqueHiddenType<'tcx>>>,
+}
+
+impl<'tcx> BodyWithBorrowckFacts<'tcx> {
+    /// The Polonius input facts, serialized as they would be by `-Z nll-facts`: the contents of
+    /// each `.facts` file, keyed by relation name. This avoids going through a temporary
+    /// directory when the facts are to be compared or saved elsewhere.
+    ///
+    /// Returns `None` unless the input facts were computed, see [`Self::input_facts`].
+    pub fn input_facts_in_memory(&self) -> Option<BTreeMap<String, Vec<u8>>> {
+        let input_facts = self.input_facts.as_ref()?;
+        let location_table = self.location_table.as_ref()?;
+        Some(input_facts.write_to_buffers(location_table))
+    }
+}
+
+/// The callbacks and switches with which consumers observe or change what borrowck does for a
+/// body, see [`get_body_with_borrowck_facts_with_hooks`]. The default is what `mir_borrowck` does:
+/// no hooks, and remapped opaque types.
+///
+/// The hooks are called regardless of the [`ConsumerOptions`]. The facts they see are then also
+/// returned in the [`BodyWithBorrowckFacts`], as if they had been asked for.
+pub struct ConsumerHooks<'a, 'tcx> {
+    /// Passed each Polonius input fact once the step generating it is done: first the facts of
+    /// the type check and liveness, then those of each step of `polonius::emit_facts` in order.
+    /// This lets e.g. an external analysis stream the facts in.
+    pub fact_hook: Option<PoloniusFactHook<'a>>,
+    /// Passed each basic block, in order, right after its CFG edges, loan kills and loan
+    /// invalidations are generated, with the facts generated so far. All these calls happen
+    /// before `fact_hook` sees those facts. This lets e.g. an incremental solver start working
+    /// before the whole body is done.
+    pub block_fact_hook: Option<PoloniusBlockFactHook<'a>>,
+    /// Passed each region error after region inference, before any of them is reported. This lets
+    /// a consumer classify the errors without parsing diagnostics. The errors are still reported
+    /// as usual.
+    pub region_error_hook: Option<RegionErrorHook<'a, 'tcx>>,
+    /// Whether to remap the hidden types of opaque types to the generic parameters of their
+    /// definitions, as `mir_borrowck` needs. Remapping can report errors, e.g. for non-defining
+    /// uses, which a consumer may not care about. When this is off, the hidden types are instead
+    /// returned as the type checker found them, in
+    /// [`BodyWithBorrowckFacts::raw_opaque_type_values`].
+    pub remap_opaque_types: bool,
+}
+
+impl Default for ConsumerHooks<'_, '_> {
+    fn default() -> Self {
+        ConsumerHooks {
+            fact_hook: None,
+            block_fact_hook: None,
+            region_error_hook: None,
+            remap_opaque_types: true,
+        }
+    }
 }
 
 /// This function computes borrowck facts for the given body. The [`ConsumerOptions`]
@@ -105,9 +182,20 @@ pub fn get_body_with_borrowck_facts(
     def: LocalDefId,
     options: ConsumerOptions,
 ) -> BodyWithBorrowckFacts<'_> {
+    get_body_with_borrowck_facts_with_hooks(tcx, def, options, ConsumerHooks::default())
+}
+
+/// Like [`get_body_with_borrowck_facts`], but with the given [`ConsumerHooks`] to observe or
+/// change what borrowck does along the way.
+pub fn get_body_with_borrowck_facts_with_hooks<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    def: LocalDefId,
+    options: ConsumerOptions,
+    hooks: ConsumerHooks<'_, 'tcx>,
+) -> BodyWithBorrowckFacts<'tcx> {
     let (input_body, promoted) = tcx.mir_promoted(def);
     let infcx = tcx.infer_ctxt().with_opaque_type_inference(DefiningAnchor::Bind(def)).build();
     let input_body: &Body<'_> = &input_body.borrow();
     let promoted: &IndexSlice<_, _> = &promoted.borrow();
-    *super::do_mir_borrowck(&infcx, input_body, promoted, Some(options)).1.unwrap()
+    *super::do_mir_borrowck(&infcx, input_body, promoted, Some(options), hooks).1.unwrap()
 }
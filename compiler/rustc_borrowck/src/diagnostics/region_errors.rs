@@ -73,37 +73,58 @@ fn description(&self) -> &'static str {
 ///
 /// Usually we expect this to either be empty or contain a small number of items, so we can avoid
 /// allocation most of the time.
-pub(crate) struct RegionErrors<'tcx>(Vec<(RegionErrorKind<'tcx>, ErrorGuaranteed)>, TyCtxt<'tcx>);
+pub struct RegionErrors<'tcx> {
+    errors: Vec<(RegionErrorKind<'tcx>, Option<ErrorGuaranteed>)>,
+    tcx: TyCtxt<'tcx>,
+    /// Whether these errors are going to be reported. Errors that are only collected for
+    /// inspection, e.g. when re-solving the region constraints on behalf of a consumer, must
+    /// not register a delayed bug.
+    will_report: bool,
+}
 
 impl<'tcx> RegionErrors<'tcx> {
     pub fn new(tcx: TyCtxt<'tcx>) -> Self {
-        Self(vec![], tcx)
+        Self { errors: vec![], tcx, will_report: true }
+    }
+    /// Creates an error buffer whose errors will never be reported to the user.
+    pub(crate) fn unreported(tcx: TyCtxt<'tcx>) -> Self {
+        Self { errors: vec![], tcx, will_report: false }
     }
     #[track_caller]
     pub fn push(&mut self, val: impl Into<RegionErrorKind<'tcx>>) {
         let val = val.into();
-        let guar = self.1.sess.dcx().delayed_bug(format!("{val:?}"));
-        self.0.push((val, guar));
+        let guar = self.will_report.then(|| self.tcx.sess.dcx().delayed_bug(format!("{val:?}")));
+        self.errors.push((val, guar));
     }
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.errors.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.errors.len()
     }
-    pub fn into_iter(self) -> impl Iterator<Item = (RegionErrorKind<'tcx>, ErrorGuaranteed)> {
-        self.0.into_iter()
+    pub fn iter(&self) -> impl Iterator<Item = &RegionErrorKind<'tcx>> {
+        self.errors.iter().map(|(val, _)| val)
+    }
+    pub fn into_iter(self) -> impl Iterator<Item = RegionErrorKind<'tcx>> {
+        self.errors.into_iter().map(|(val, _)| val)
     }
     pub fn has_errors(&self) -> Option<ErrorGuaranteed> {
-        self.0.get(0).map(|x| x.1)
+        self.errors.get(0).and_then(|x| x.1)
     }
 }
 
 impl std::fmt::Debug for RegionErrors<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_tuple("RegionErrors").field(&self.0).finish()
+        f.debug_tuple("RegionErrors").field(&self.errors).finish()
     }
 }
 
+/// A callback that is passed each [`RegionErrorKind`] found by region inference, before any
+/// diagnostic is built for it.
+pub type RegionErrorHook<'a, 'tcx> = &'a mut dyn FnMut(&RegionErrorKind<'tcx>);
+
 #[derive(Clone, Debug)]
-pub(crate) enum RegionErrorKind<'tcx> {
+pub enum RegionErrorKind<'tcx> {
     /// A generic bound failure for a type test (`T: 'a`).
     TypeTestError { type_test: TypeTest<'tcx> },
 
@@ -306,7 +327,7 @@ pub(crate) fn report_region_errors(&mut self, nll_errors: RegionErrors<'tcx>) {
         let mut last_unexpected_hidden_region: Option<(Span, Ty<'_>, ty::OpaqueTypeKey<'tcx>)> =
             None;
 
-        for (nll_error, _) in nll_errors.into_iter() {
+        for nll_error in nll_errors.into_iter() {
             match nll_error {
                 RegionErrorKind::TypeTestError { type_test } => {
                     // Try to convert the lower-bound region into something named we can print for
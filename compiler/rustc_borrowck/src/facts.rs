@@ -3,13 +3,14 @@
 use polonius_engine::AllFacts as PoloniusFacts;
 use polonius_engine::Atom;
 use rustc_macros::extension;
-use rustc_middle::mir::Local;
+use rustc_middle::mir::{BasicBlock, Local};
 use rustc_middle::ty::{RegionVid, TyCtxt};
 use rustc_mir_dataflow::move_paths::MovePathIndex;
+use std::collections::BTreeMap;
 use std::error::Error;
 use std::fmt::Debug;
-use std::fs::{self, File};
-use std::io::{BufWriter, Write};
+use std::fs;
+use std::io::Write;
 use std::path::Path;
 
 #[derive(Copy, Clone, Debug)]
@@ -25,6 +26,90 @@ impl polonius_engine::FactTypes for RustcFacts {
 
 pub type AllFacts = PoloniusFacts<RustcFacts>;
 
+/// A single Polonius input fact: a row of one of the relations of [`AllFacts`].
+#[derive(Copy, Clone, Debug)]
+pub enum PoloniusFact {
+    LoanIssuedAt(RegionVid, BorrowIndex, LocationIndex),
+    UniversalRegion(RegionVid),
+    CfgEdge(LocationIndex, LocationIndex),
+    LoanKilledAt(BorrowIndex, LocationIndex),
+    SubsetBase(RegionVid, RegionVid, LocationIndex),
+    LoanInvalidatedAt(LocationIndex, BorrowIndex),
+    VarUsedAt(Local, LocationIndex),
+    VarDefinedAt(Local, LocationIndex),
+    VarDroppedAt(Local, LocationIndex),
+    UseOfVarDerefsOrigin(Local, RegionVid),
+    DropOfVarDerefsOrigin(Local, RegionVid),
+    ChildPath(MovePathIndex, MovePathIndex),
+    PathIsVar(MovePathIndex, Local),
+    PathAssignedAtBase(MovePathIndex, LocationIndex),
+    PathMovedAtBase(MovePathIndex, LocationIndex),
+    PathAccessedAtBase(MovePathIndex, LocationIndex),
+    KnownPlaceholderSubset(RegionVid, RegionVid),
+    Placeholder(RegionVid, BorrowIndex),
+    /// Where a two-phase borrow is activated. Only emitted under `-Znll-facts-loan-activations`,
+    /// as this relation isn't part of [`AllFacts`].
+    LoanActivatedAt(BorrowIndex, LocationIndex),
+}
+
+/// A callback that is passed each [`PoloniusFact`] as it is generated.
+pub type PoloniusFactHook<'a> = &'a mut dyn FnMut(&PoloniusFact);
+
+/// A callback that is passed each basic block once the facts of its statements and terminator have
+/// been generated, along with all the facts generated so far.
+pub type PoloniusBlockFactHook<'a> = &'a mut dyn FnMut(BasicBlock, &AllFacts);
+
+macro_rules! polonius_relations {
+    ($($field:ident: $row:pat => $fact:expr,)*) => {
+        /// The number of rows in each relation of some [`AllFacts`], used to find the facts that
+        /// a step of the fact generation added.
+        #[derive(Default)]
+        pub(crate) struct FactCounts {
+            $($field: usize,)*
+        }
+
+        impl FactCounts {
+            pub(crate) fn of(all_facts: &AllFacts) -> Self {
+                FactCounts { $($field: all_facts.$field.len(),)* }
+            }
+        }
+
+        /// Passes the facts that were added to `all_facts` since `counts` were taken to `hook`.
+        pub(crate) fn stream_facts_since(
+            all_facts: &AllFacts,
+            counts: &FactCounts,
+            hook: &mut dyn FnMut(&PoloniusFact),
+        ) {
+            $(
+                for &$row in &all_facts.$field[counts.$field..] {
+                    hook(&$fact);
+                }
+            )*
+        }
+    };
+}
+
+polonius_relations! {
+    loan_issued_at: (origin, loan, point) => PoloniusFact::LoanIssuedAt(origin, loan, point),
+    universal_region: origin => PoloniusFact::UniversalRegion(origin),
+    cfg_edge: (from, to) => PoloniusFact::CfgEdge(from, to),
+    loan_killed_at: (loan, point) => PoloniusFact::LoanKilledAt(loan, point),
+    subset_base: (sup, sub, point) => PoloniusFact::SubsetBase(sup, sub, point),
+    loan_invalidated_at: (point, loan) => PoloniusFact::LoanInvalidatedAt(point, loan),
+    var_used_at: (var, point) => PoloniusFact::VarUsedAt(var, point),
+    var_defined_at: (var, point) => PoloniusFact::VarDefinedAt(var, point),
+    var_dropped_at: (var, point) => PoloniusFact::VarDroppedAt(var, point),
+    use_of_var_derefs_origin: (var, origin) => PoloniusFact::UseOfVarDerefsOrigin(var, origin),
+    drop_of_var_derefs_origin: (var, origin) => PoloniusFact::DropOfVarDerefsOrigin(var, origin),
+    child_path: (child, parent) => PoloniusFact::ChildPath(child, parent),
+    path_is_var: (path, var) => PoloniusFact::PathIsVar(path, var),
+    path_assigned_at_base: (path, point) => PoloniusFact::PathAssignedAtBase(path, point),
+    path_moved_at_base: (path, point) => PoloniusFact::PathMovedAtBase(path, point),
+    path_accessed_at_base: (path, point) => PoloniusFact::PathAccessedAtBase(path, point),
+    known_placeholder_subset: (sup, sub) => PoloniusFact::KnownPlaceholderSubset(sup, sub),
+    placeholder: (origin, loan) => PoloniusFact::Placeholder(origin, loan),
+}
+
 #[extension(pub(crate) trait AllFactsExt)]
 impl AllFacts {
     /// Returns `true` if there is a need to gather `AllFacts` given the
@@ -41,21 +126,41 @@ fn write_to_dir(
     ) -> Result<(), Box<dyn Error>> {
         let dir: &Path = dir.as_ref();
         fs::create_dir_all(dir)?;
-        let wr = FactWriter { location_table, dir };
-        macro_rules! write_facts_to_path {
-            ($wr:ident . write_facts_to_path($this:ident . [
+        self.write_relations(location_table, &mut |relation, bytes| {
+            Ok(fs::write(dir.join(format!("{relation}.facts")), bytes)?)
+        })
+    }
+
+    /// Serializes each relation like [`AllFactsExt::write_to_dir`] does, but into memory: the
+    /// contents of each `.facts` file, keyed by relation name.
+    fn write_to_buffers(&self, location_table: &LocationTable) -> BTreeMap<String, Vec<u8>> {
+        let mut buffers = BTreeMap::new();
+        self.write_relations(location_table, &mut |relation, bytes| {
+            buffers.insert(relation.to_string(), bytes);
+            Ok(())
+        })
+        .expect("writing facts to memory cannot fail");
+        buffers
+    }
+
+    /// Serializes each relation and passes it to `sink` along with its name.
+    fn write_relations(
+        &self,
+        location_table: &LocationTable,
+        sink: FactSink<'_>,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut wr = FactWriter { location_table, sink };
+        macro_rules! write_facts {
+            ($wr:ident . write_facts($this:ident . [
                 $($field:ident,)*
             ])) => {
                 $(
-                    $wr.write_facts_to_path(
-                        &$this.$field,
-                        &format!("{}.facts", stringify!($field))
-                    )?;
+                    $wr.write_facts(&$this.$field, stringify!($field))?;
                 )*
             }
         }
-        write_facts_to_path! {
-            wr.write_facts_to_path(self.[
+        write_facts! {
+            wr.write_facts(self.[
                 loan_issued_at,
                 universal_region,
                 cfg_edge,
@@ -80,6 +185,22 @@ macro_rules! write_facts_to_path {
     }
 }
 
+/// Writes the `loan_activated_at` relation, which polonius doesn't know about, next to the other
+/// facts in `dir`.
+pub(crate) fn write_loan_activations_to_dir(
+    dir: impl AsRef<Path>,
+    location_table: &LocationTable,
+    loan_activated_at: &[(BorrowIndex, LocationIndex)],
+) -> Result<(), Box<dyn Error>> {
+    let dir: &Path = dir.as_ref();
+    fs::create_dir_all(dir)?;
+    let mut wr = FactWriter {
+        location_table,
+        sink: &mut |relation, bytes| Ok(fs::write(dir.join(format!("{relation}.facts")), bytes)?),
+    };
+    wr.write_facts(loan_activated_at, "loan_activated_at")
+}
+
 impl Atom for BorrowIndex {
     fn index(self) -> usize {
         self.as_usize()
@@ -92,22 +213,24 @@ fn index(self) -> usize {
     }
 }
 
+/// Where [`FactWriter`] puts each serialized relation, given its name.
+type FactSink<'a> = &'a mut dyn FnMut(&str, Vec<u8>) -> Result<(), Box<dyn Error>>;
+
 struct FactWriter<'w> {
     location_table: &'w LocationTable,
-    dir: &'w Path,
+    sink: FactSink<'w>,
 }
 
 impl<'w> FactWriter<'w> {
-    fn write_facts_to_path<T>(&self, rows: &[T], file_name: &str) -> Result<(), Box<dyn Error>>
+    fn write_facts<T>(&mut self, rows: &[T], relation: &str) -> Result<(), Box<dyn Error>>
     where
         T: FactRow,
     {
-        let file = &self.dir.join(file_name);
-        let mut file = BufWriter::new(File::create(file)?);
+        let mut bytes = Vec::new();
         for row in rows {
-            row.write(&mut file, self.location_table)?;
+            row.write(&mut bytes, self.location_table)?;
         }
-        Ok(())
+        (self.sink)(relation, bytes)
     }
 }
 
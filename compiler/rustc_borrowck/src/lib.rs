@@ -58,7 +58,7 @@
 use self::diagnostics::{AccessKind, IllegalMoveOriginKind, MoveError, RegionName};
 use self::location::LocationTable;
 use self::prefixes::PrefixSet;
-use consumers::{BodyWithBorrowckFacts, ConsumerOptions};
+use consumers::{BodyWithBorrowckFacts, ConsumerHooks, ConsumerOptions};
 
 use self::path_utils::*;
 
@@ -131,7 +131,8 @@ fn mir_borrowck(tcx: TyCtxt<'_>, def: LocalDefId) -> &BorrowCheckResult<'_> {
     let infcx =
         tcx.infer_ctxt().with_opaque_type_inference(DefiningAnchor::Bind(hir_owner.def_id)).build();
     let promoted: &IndexSlice<_, _> = &promoted.borrow();
-    let opt_closure_req = do_mir_borrowck(&infcx, input_body, promoted, None).0;
+    let opt_closure_req =
+        do_mir_borrowck(&infcx, input_body, promoted, None, ConsumerHooks::default()).0;
     debug!("mir_borrowck done");
 
     tcx.arena.alloc(opt_closure_req)
@@ -141,13 +142,15 @@ fn mir_borrowck(tcx: TyCtxt<'_>, def: LocalDefId) -> &BorrowCheckResult<'_> {
 ///
 /// Use `consumer_options: None` for the default behavior of returning
 /// [`BorrowCheckResult`] only. Otherwise, return [`BodyWithBorrowckFacts`] according
-/// to the given [`ConsumerOptions`].
-#[instrument(skip(infcx, input_body, input_promoted), fields(id=?input_body.source.def_id()), level = "debug")]
+/// to the given [`ConsumerOptions`]. `hooks` lets consumers observe the facts and errors as
+/// they are computed; see [`ConsumerHooks`].
+#[instrument(skip(infcx, input_body, input_promoted, hooks), fields(id=?input_body.source.def_id()), level = "debug")]
 fn do_mir_borrowck<'tcx>(
     infcx: &InferCtxt<'tcx>,
     input_body: &Body<'tcx>,
     input_promoted: &IndexSlice<Promoted, Body<'tcx>>,
     consumer_options: Option<ConsumerOptions>,
+    hooks: ConsumerHooks<'_, 'tcx>,
 ) -> (BorrowCheckResult<'tcx>, Option<Box<BodyWithBorrowckFacts<'tcx>>>) {
     let def = input_body.source.def_id().expect_local();
     debug!(?def);
@@ -220,6 +223,9 @@ fn do_mir_borrowck<'tcx>(
         polonius_output,
         opt_closure_req,
         nll_errors,
+        timings,
+        placeholder_regions,
+        raw_opaque_type_values,
     } = nll::compute_regions(
         &infcx,
         free_regions,
@@ -232,6 +238,7 @@ fn do_mir_borrowck<'tcx>(
         &borrow_set,
         tcx.closure_captures(def),
         consumer_options,
+        hooks,
     );
 
     // Dump MIR results into a file, if that is enabled. This let us
@@ -428,6 +435,7 @@ fn visit_operand(&mut self, operand: &Operand<'tcx>, location: Location) {
 
     let body_with_facts = if consumer_options.is_some() {
         let output_facts = mbcx.polonius_output;
+        let move_data = polonius_input.as_ref().map(|_| mdpe.move_data);
         Some(Box::new(BodyWithBorrowckFacts {
             body: body_owned,
             promoted,
@@ -436,6 +444,11 @@ fn visit_operand(&mut self, operand: &Operand<'tcx>, location: Location) {
             location_table: polonius_input.as_ref().map(|_| location_table),
             input_facts: polonius_input,
             output_facts,
+            move_data,
+            timings: timings.unwrap_or_default(),
+            placeholder_regions: placeholder_regions.unwrap_or_default(),
+            opaque_type_values: result.concrete_opaque_types.clone(),
+            raw_opaque_type_values,
         }))
     } else {
         None
@@ -30,7 +30,7 @@ pub(crate) struct MemberConstraintSet<'tcx, R>
 }
 
 /// Represents a `R0 member of [R1..Rn]` constraint
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct NllMemberConstraint<'tcx> {
     next_constraint: Option<NllMemberConstraintIndex>,
 
@@ -143,6 +143,28 @@ pub(crate) fn into_mapped<R2>(
 
         MemberConstraintSet { first_constraints: first_constraints2, constraints, choice_regions }
     }
+
+    /// Produces a copy of this set keyed by the original member region
+    /// of each constraint again, undoing any `into_mapped`. This is used
+    /// to re-solve the region constraints with a different set of SCCs.
+    pub(crate) fn to_member_region_vids(&self) -> MemberConstraintSet<'tcx, ty::RegionVid> {
+        let mut first_constraints = FxIndexMap::default();
+        let mut constraints = IndexVec::with_capacity(self.constraints.len());
+
+        for constraint in &self.constraints {
+            let member_region_vid = constraint.member_region_vid;
+            let next_constraint = first_constraints.get(&member_region_vid).cloned();
+            let index =
+                constraints.push(NllMemberConstraint { next_constraint, ..constraint.clone() });
+            first_constraints.insert(member_region_vid, index);
+        }
+
+        MemberConstraintSet {
+            first_constraints,
+            constraints,
+            choice_regions: self.choice_regions.clone(),
+        }
+    }
 }
 
 impl<'tcx, R> MemberConstraintSet<'tcx, R>
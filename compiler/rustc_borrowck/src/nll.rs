@@ -7,7 +7,7 @@
 use rustc_middle::mir::{create_dump_file, dump_enabled, dump_mir, PassWhere};
 use rustc_middle::mir::{Body, ClosureOutlivesSubject, ClosureRegionRequirements, Promoted};
 use rustc_middle::ty::print::with_no_trimmed_paths;
-use rustc_middle::ty::{self, OpaqueHiddenType, TyCtxt};
+use rustc_middle::ty::{self, OpaqueHiddenType, OpaqueTypeKey, Ty, TyCtxt};
 use rustc_mir_dataflow::impls::MaybeInitializedPlaces;
 use rustc_mir_dataflow::move_paths::MoveData;
 use rustc_mir_dataflow::points::DenseLocationMap;
@@ -18,12 +18,16 @@
 use std::path::PathBuf;
 use std::rc::Rc;
 use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 use crate::{
     borrow_set::BorrowSet,
-    consumers::ConsumerOptions,
+    consumers::{ConsumerHooks, ConsumerOptions},
     diagnostics::RegionErrors,
-    facts::{AllFacts, AllFactsExt, RustcFacts},
+    facts::{
+        stream_facts_since, write_loan_activations_to_dir, AllFacts, AllFactsExt, FactCounts,
+        RustcFacts,
+    },
     location::LocationTable,
     polonius,
     region_infer::RegionInferenceContext,
@@ -35,6 +39,29 @@
 
 pub type PoloniusOutput = Output<RustcFacts>;
 
+/// How long the phases of [`compute_regions`] took for a single body. Only recorded for consumers,
+/// see [`BodyWithBorrowckFacts::timings`](crate::consumers::BodyWithBorrowckFacts::timings).
+#[derive(Copy, Clone, Debug, Default)]
+pub struct NllTimings {
+    /// The MIR type check, including the facts emitted during typeck and liveness.
+    pub type_check: Duration,
+    /// Emitting the rest of the Polonius input facts.
+    pub fact_generation: Duration,
+    /// Running Polonius. Zero if the output facts weren't computed.
+    pub polonius: Duration,
+    /// Solving the region constraints.
+    pub solve: Duration,
+}
+
+/// Runs `f`, adding the time it took to `duration` if timings are being recorded.
+fn timed<R>(duration: Option<&mut Duration>, f: impl FnOnce() -> R) -> R {
+    let Some(duration) = duration else { return f() };
+    let start = Instant::now();
+    let result = f();
+    *duration += start.elapsed();
+    result
+}
+
 /// The output of `nll::compute_regions`. This includes the computed `RegionInferenceContext`, any
 /// closure requirements to propagate, and any generated errors.
 pub(crate) struct NllOutput<'tcx> {
@@ -44,6 +71,13 @@ pub(crate) struct NllOutput<'tcx> {
     pub polonius_output: Option<Rc<PoloniusOutput>>,
     pub opt_closure_req: Option<ClosureRegionRequirements<'tcx>>,
     pub nll_errors: RegionErrors<'tcx>,
+    pub timings: Option<NllTimings>,
+    /// The region variable the type checker created for each placeholder region, only kept for
+    /// consumers.
+    pub placeholder_regions: Option<FxIndexMap<ty::PlaceholderRegion, ty::Region<'tcx>>>,
+    /// The hidden types of the opaque types as the type checker found them, if they weren't
+    /// remapped to `opaque_type_values`.
+    pub raw_opaque_type_values: Option<FxIndexMap<OpaqueTypeKey<'tcx>, OpaqueHiddenType<'tcx>>>,
 }
 
 /// Rewrites the regions in the MIR to use NLL variables, also scraping out the set of universal
@@ -73,7 +107,9 @@ pub(crate) fn replace_regions_in_mir<'tcx>(
 
 /// Computes the (non-lexical) regions from the input MIR.
 ///
-/// This may result in errors being reported.
+/// This may result in errors being reported. Unless `hooks.remap_opaque_types` is set, the hidden
+/// types of the opaque types are returned as the type checker found them, instead of being
+/// remapped to the generic parameters of their definitions.
 pub(crate) fn compute_regions<'cx, 'tcx>(
     infcx: &BorrowckInferCtxt<'_, 'tcx>,
     universal_regions: UniversalRegions<'tcx>,
@@ -86,14 +122,22 @@ pub(crate) fn compute_regions<'cx, 'tcx>(
     borrow_set: &BorrowSet<'tcx>,
     upvars: &[&ty::CapturedPlace<'tcx>],
     consumer_options: Option<ConsumerOptions>,
+    hooks: ConsumerHooks<'_, 'tcx>,
 ) -> NllOutput<'tcx> {
+    let ConsumerHooks { mut fact_hook, block_fact_hook, region_error_hook, remap_opaque_types } =
+        hooks;
     let is_polonius_legacy_enabled = infcx.tcx.sess.opts.unstable_opts.polonius.is_legacy_enabled();
     let polonius_input = consumer_options.map(|c| c.polonius_input()).unwrap_or_default()
         || is_polonius_legacy_enabled;
     let polonius_output = consumer_options.map(|c| c.polonius_output()).unwrap_or_default()
         || is_polonius_legacy_enabled;
-    let mut all_facts =
-        (polonius_input || AllFacts::enabled(infcx.tcx)).then_some(AllFacts::default());
+    let mut all_facts = (polonius_input
+        || fact_hook.is_some()
+        || block_fact_hook.is_some()
+        || AllFacts::enabled(infcx.tcx))
+    .then_some(AllFacts::default());
+
+    let mut timings = consumer_options.map(|_| NllTimings::default());
 
     let universal_regions = Rc::new(universal_regions);
 
@@ -101,21 +145,23 @@ pub(crate) fn compute_regions<'cx, 'tcx>(
 
     // Run the MIR type-checker.
     let MirTypeckResults { constraints, universal_region_relations, opaque_type_values } =
-        type_check::type_check(
-            infcx,
-            param_env,
-            body,
-            promoted,
-            &universal_regions,
-            location_table,
-            borrow_set,
-            &mut all_facts,
-            flow_inits,
-            move_data,
-            elements,
-            upvars,
-            polonius_input,
-        );
+        timed(timings.as_mut().map(|t| &mut t.type_check), || {
+            type_check::type_check(
+                infcx,
+                param_env,
+                body,
+                promoted,
+                &universal_regions,
+                location_table,
+                borrow_set,
+                &mut all_facts,
+                flow_inits,
+                move_data,
+                elements,
+                upvars,
+                polonius_input,
+            )
+        });
 
     // Create the region inference context, taking ownership of the
     // region inference data that was contained in `infcx`, and the
@@ -123,26 +169,48 @@ pub(crate) fn compute_regions<'cx, 'tcx>(
     let var_origins = infcx.get_region_var_origins();
     let MirTypeckRegionConstraints {
         placeholder_indices,
-        placeholder_index_to_region: _,
+        placeholder_index_to_region,
         liveness_constraints,
         outlives_constraints,
         member_constraints,
         universe_causes,
         type_tests,
     } = constraints;
+    let placeholder_regions = consumer_options.map(|_| {
+        placeholder_index_to_region
+            .iter_enumerated()
+            .map(|(index, &region)| (placeholder_indices.lookup_placeholder(index), region))
+            .collect()
+    });
     let placeholder_indices = Rc::new(placeholder_indices);
 
+    // The facts emitted during typeck and liveness are only handed out once they are done.
+    if let (Some(all_facts), Some(fact_hook)) = (&all_facts, fact_hook.as_deref_mut()) {
+        stream_facts_since(all_facts, &FactCounts::default(), fact_hook);
+    }
+
     // If requested, emit legacy polonius facts.
-    polonius::emit_facts(
-        &mut all_facts,
-        infcx.tcx,
-        location_table,
-        body,
-        borrow_set,
-        move_data,
-        &universal_regions,
-        &universal_region_relations,
-    );
+    timed(timings.as_mut().map(|t| &mut t.fact_generation), || {
+        polonius::emit_facts(
+            &mut all_facts,
+            fact_hook,
+            block_fact_hook,
+            infcx.tcx,
+            location_table,
+            body,
+            borrow_set,
+            move_data,
+            &universal_regions,
+            &universal_region_relations,
+        )
+    });
+
+    if let Some(all_facts) = &all_facts
+        && infcx.tcx.sess.opts.unstable_opts.nll_facts
+        && infcx.tcx.sess.opts.unstable_opts.nll_facts_verify
+    {
+        polonius::verify_cfg_edges(infcx.tcx, all_facts, location_table, body);
+    }
 
     let mut regioncx = RegionInferenceContext::new(
         infcx,
@@ -160,12 +228,22 @@ pub(crate) fn compute_regions<'cx, 'tcx>(
 
     // If requested: dump NLL facts, and run legacy polonius analysis.
     let polonius_output = all_facts.as_ref().and_then(|all_facts| {
-        if infcx.tcx.sess.opts.unstable_opts.nll_facts {
-            let def_id = body.source.def_id();
+        let unstable_opts = &infcx.tcx.sess.opts.unstable_opts;
+        let def_id = body.source.def_id();
+        if unstable_opts.nll_facts
+            && unstable_opts.nll_facts_filter.as_ref().map_or(true, |filter| {
+                with_no_trimmed_paths!(infcx.tcx.def_path_str(def_id)).contains(filter.as_str())
+            })
+        {
             let def_path = infcx.tcx.def_path(def_id);
-            let dir_path = PathBuf::from(&infcx.tcx.sess.opts.unstable_opts.nll_facts_dir)
+            let dir_path = PathBuf::from(&unstable_opts.nll_facts_dir)
                 .join(def_path.to_filename_friendly_no_crate());
-            all_facts.write_to_dir(dir_path, location_table).unwrap();
+            all_facts.write_to_dir(&dir_path, location_table).unwrap();
+            if unstable_opts.nll_facts_loan_activations {
+                let loan_activated_at = polonius::loan_activations(location_table, borrow_set);
+                write_loan_activations_to_dir(&dir_path, location_table, &loan_activated_at)
+                    .unwrap();
+            }
         }
 
         if polonius_output {
@@ -174,7 +252,10 @@ pub(crate) fn compute_regions<'cx, 'tcx>(
             let algorithm = Algorithm::from_str(&algorithm).unwrap();
             debug!("compute_regions: using polonius algorithm {:?}", algorithm);
             let _prof_timer = infcx.tcx.prof.generic_activity("polonius_analysis");
-            Some(Rc::new(Output::compute(all_facts, algorithm, false)))
+            let output = timed(timings.as_mut().map(|t| &mut t.polonius), || {
+                Output::compute(all_facts, algorithm, false)
+            });
+            Some(Rc::new(output))
         } else {
             None
         }
@@ -182,14 +263,36 @@ pub(crate) fn compute_regions<'cx, 'tcx>(
 
     // Solve the region constraints.
     let (closure_region_requirements, nll_errors) =
-        regioncx.solve(infcx, body, polonius_output.clone());
+        timed(timings.as_mut().map(|t| &mut t.solve), || {
+            regioncx.solve(infcx, body, polonius_output.clone())
+        });
+    if let Some(region_error_hook) = region_error_hook {
+        nll_errors.iter().for_each(region_error_hook);
+    }
+
+    // Whether a loan is still live on return is only known once the regions are solved.
+    if let Some(all_facts) = &all_facts
+        && infcx.tcx.sess.opts.unstable_opts.nll_facts
+        && infcx.tcx.sess.opts.unstable_opts.nll_facts_verify
+    {
+        polonius::verify_loan_kills(infcx.tcx, all_facts, body, borrow_set, &regioncx);
+    }
 
     if let Some(guar) = nll_errors.has_errors() {
         // Suppress unhelpful extra errors in `infer_opaque_types`.
         infcx.set_tainted_by_errors(guar);
     }
 
-    let remapped_opaque_tys = regioncx.infer_opaque_types(infcx, opaque_type_values);
+    let (remapped_opaque_tys, raw_opaque_type_values) = if remap_opaque_types {
+        let mut remapped_opaque_tys = regioncx.infer_opaque_types(infcx, opaque_type_values);
+        // The opaque types are inferred in the order the type checker registered them. Sort them
+        // so that their order, e.g. in the `#[rustc_regions]` dump, doesn't depend on it.
+        // Consumers only look them up by key, so this doesn't change anything else.
+        remapped_opaque_tys.sort_by(|a, _, b, _| a.local_def_index.cmp(&b.local_def_index));
+        (remapped_opaque_tys, None)
+    } else {
+        (FxIndexMap::default(), Some(opaque_type_values))
+    };
 
     NllOutput {
         regioncx,
@@ -198,6 +301,9 @@ pub(crate) fn compute_regions<'cx, 'tcx>(
         polonius_output,
         opt_closure_req: closure_region_requirements,
         nll_errors,
+        timings,
+        placeholder_regions,
+        raw_opaque_type_values,
     }
 }
 
@@ -249,6 +355,13 @@ pub(super) fn dump_mir_results<'tcx>(
         let mut file = create_dump_file(infcx.tcx, "regioncx.scc.dot", false, "nll", &0, body)?;
         regioncx.dump_graphviz_scc_constraints(&mut file)?;
     };
+
+    if infcx.tcx.sess.opts.unstable_opts.dump_mir_nll_regions_json {
+        let _: io::Result<()> = try {
+            let mut file = create_dump_file(infcx.tcx, "regions.json", false, "nll", &0, body)?;
+            regioncx.dump_live_regions_json(body, &mut file)?;
+        };
+    }
 }
 
 #[allow(rustc::diagnostic_outside_of_impl)]
@@ -293,6 +406,13 @@ pub(super) fn dump_annotation<'tcx>(
         })
         .unwrap();
 
+        if tcx.sess.opts.unstable_opts.dump_region_requirements_json {
+            let _: io::Result<()> = try {
+                let mut file = create_dump_file(tcx, "requirements.json", false, "nll", &0, body)?;
+                dump_region_requirements_json(tcx, closure_region_requirements, &mut file)?;
+            };
+        }
+
         err
     } else {
         let mut err = tcx.dcx().struct_span_note(def_span, "no external requirements");
@@ -308,26 +428,93 @@ pub(super) fn dump_annotation<'tcx>(
     diags.buffer_non_error(err);
 }
 
+/// The subject of an outlives requirement a closure propagates to its creator.
+#[derive(Copy, Clone, Debug)]
+pub enum OutlivesRequirementSubject<'tcx> {
+    /// A requirement like `'a: 'b`, where the subject is `'a`.
+    Region(ty::RegionVid),
+    /// A requirement like `T: 'a`, where the subject is `T`. Any regions in the type are the
+    /// closure's external region variables.
+    Ty(Ty<'tcx>),
+}
+
+/// Returns the outlives requirements of a closure as `(subject, outlived_free_region)` pairs, in
+/// the order they were recorded.
+pub fn closure_outlives_requirements<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    closure_region_requirements: &ClosureRegionRequirements<'tcx>,
+) -> Vec<(OutlivesRequirementSubject<'tcx>, ty::RegionVid)> {
+    closure_region_requirements
+        .outlives_requirements
+        .iter()
+        .map(|req| {
+            let subject = match req.subject {
+                ClosureOutlivesSubject::Region(subject) => {
+                    OutlivesRequirementSubject::Region(subject)
+                }
+                ClosureOutlivesSubject::Ty(ty) => OutlivesRequirementSubject::Ty(
+                    ty.instantiate(tcx, |vid| ty::Region::new_var(tcx, vid)),
+                ),
+            };
+            (subject, req.outlived_free_region)
+        })
+        .collect()
+}
+
 fn for_each_region_constraint<'tcx>(
     tcx: TyCtxt<'tcx>,
     closure_region_requirements: &ClosureRegionRequirements<'tcx>,
     with_msg: &mut dyn FnMut(String) -> io::Result<()>,
 ) -> io::Result<()> {
-    for req in &closure_region_requirements.outlives_requirements {
-        let subject = match req.subject {
-            ClosureOutlivesSubject::Region(subject) => format!("{subject:?}"),
-            ClosureOutlivesSubject::Ty(ty) => {
-                with_no_trimmed_paths!(format!(
-                    "{}",
-                    ty.instantiate(tcx, |vid| ty::Region::new_var(tcx, vid))
-                ))
-            }
+    for_each_region_requirement(tcx, closure_region_requirements, &mut |subject, outlived| {
+        with_msg(format!("where {subject}: {outlived:?}"))
+    })
+}
+
+/// Invokes `with_requirement` with each outlives requirement of a closure, its subject printed as
+/// in the `#[rustc_regions]` notes.
+fn for_each_region_requirement<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    closure_region_requirements: &ClosureRegionRequirements<'tcx>,
+    with_requirement: &mut dyn FnMut(String, ty::RegionVid) -> io::Result<()>,
+) -> io::Result<()> {
+    for (subject, outlived_free_region) in
+        closure_outlives_requirements(tcx, closure_region_requirements)
+    {
+        let subject = match subject {
+            OutlivesRequirementSubject::Region(subject) => format!("{subject:?}"),
+            OutlivesRequirementSubject::Ty(ty) => with_no_trimmed_paths!(format!("{ty}")),
         };
-        with_msg(format!("where {}: {:?}", subject, req.outlived_free_region,))?;
+        with_requirement(subject, outlived_free_region)?;
     }
     Ok(())
 }
 
+/// Writes out the external requirements of a closure as a JSON object, with the number of external
+/// region variables and an array of `{"subject", "outlived_free_region"}` records.
+fn dump_region_requirements_json<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    closure_region_requirements: &ClosureRegionRequirements<'tcx>,
+    out: &mut dyn io::Write,
+) -> io::Result<()> {
+    let json_string = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+    writeln!(out, "{{")?;
+    writeln!(out, "  \"num_external_vids\": {},", closure_region_requirements.num_external_vids)?;
+    write!(out, "  \"outlives_requirements\": [")?;
+    let mut separator = "\n";
+    for_each_region_requirement(tcx, closure_region_requirements, &mut |subject, outlived| {
+        write!(
+            out,
+            "{separator}    {{ \"subject\": {}, \"outlived_free_region\": {} }}",
+            json_string(&subject),
+            json_string(&format!("{outlived:?}")),
+        )?;
+        separator = ",\n";
+        Ok(())
+    })?;
+    writeln!(out, "\n  ]\n}}")
+}
+
 pub(crate) trait ConstraintDescription {
     fn description(&self) -> &'static str;
 }
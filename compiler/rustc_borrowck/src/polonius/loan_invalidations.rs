@@ -12,18 +12,22 @@
     ReadOrWrite, Reservation, Shallow, Write, WriteKind,
 };
 
-/// Emit `loan_invalidated_at` facts.
+/// Emit the `loan_invalidated_at` facts of `blocks`.
 pub(super) fn emit_loan_invalidations<'tcx>(
     tcx: TyCtxt<'tcx>,
     all_facts: &mut AllFacts,
     location_table: &LocationTable,
     body: &Body<'tcx>,
     borrow_set: &BorrowSet<'tcx>,
+    blocks: impl IntoIterator<Item = BasicBlock>,
 ) {
     let dominators = body.basic_blocks.dominators();
     let mut visitor =
         LoanInvalidationsGenerator { all_facts, borrow_set, tcx, location_table, body, dominators };
-    visitor.visit_body(body);
+    // Only the statements and terminators of the blocks generate facts.
+    for bb in blocks {
+        visitor.visit_basic_block_data(bb, &body[bb]);
+    }
 }
 
 struct LoanInvalidationsGenerator<'cx, 'tcx> {
@@ -1,23 +1,61 @@
 use rustc_middle::mir::visit::Visitor;
 use rustc_middle::mir::{
-    Body, Local, Location, Place, PlaceRef, ProjectionElem, Rvalue, Statement, StatementKind,
-    Terminator, TerminatorKind,
+    BasicBlock, Body, Local, Location, Place, PlaceRef, ProjectionElem, Rvalue, Statement,
+    StatementKind, Terminator, TerminatorKind,
 };
 use rustc_middle::ty::TyCtxt;
 
 use crate::{borrow_set::BorrowSet, facts::AllFacts, location::LocationTable, places_conflict};
 
-/// Emit `loan_killed_at` and `cfg_edge` facts at the same time.
+/// Emit the `cfg_edge` facts of `blocks`: each point flows into the next one within a statement or
+/// terminator, and from one statement to the next, or from a terminator to its successors.
+pub(super) fn emit_cfg_edges(
+    all_facts: &mut AllFacts,
+    location_table: &LocationTable,
+    body: &Body<'_>,
+    blocks: impl IntoIterator<Item = BasicBlock>,
+) {
+    for block in blocks {
+        let data = &body[block];
+        for statement_index in 0..data.statements.len() {
+            let location = Location { block, statement_index };
+            all_facts.cfg_edge.push((
+                location_table.start_index(location),
+                location_table.mid_index(location),
+            ));
+            all_facts.cfg_edge.push((
+                location_table.mid_index(location),
+                location_table.start_index(location.successor_within_block()),
+            ));
+        }
+
+        let location = body.terminator_loc(block);
+        all_facts
+            .cfg_edge
+            .push((location_table.start_index(location), location_table.mid_index(location)));
+        let successor_blocks = data.terminator().successors();
+        all_facts.cfg_edge.reserve(successor_blocks.size_hint().0);
+        for successor_block in successor_blocks {
+            all_facts.cfg_edge.push((
+                location_table.mid_index(location),
+                location_table.start_index(successor_block.start_location()),
+            ));
+        }
+    }
+}
+
+/// Emit the `loan_killed_at` facts of `blocks`.
 pub(super) fn emit_loan_kills<'tcx>(
     tcx: TyCtxt<'tcx>,
     all_facts: &mut AllFacts,
     location_table: &LocationTable,
     body: &Body<'tcx>,
     borrow_set: &BorrowSet<'tcx>,
+    blocks: impl IntoIterator<Item = BasicBlock>,
 ) {
     let mut visitor = LoanKillsGenerator { borrow_set, tcx, location_table, all_facts, body };
-    for (bb, data) in body.basic_blocks.iter_enumerated() {
-        visitor.visit_basic_block_data(bb, data);
+    for bb in blocks {
+        visitor.visit_basic_block_data(bb, &body[bb]);
     }
 }
 
@@ -31,17 +69,6 @@ struct LoanKillsGenerator<'cx, 'tcx> {
 
 impl<'cx, 'tcx> Visitor<'tcx> for LoanKillsGenerator<'cx, 'tcx> {
     fn visit_statement(&mut self, statement: &Statement<'tcx>, location: Location) {
-        // Also record CFG facts here.
-        self.all_facts.cfg_edge.push((
-            self.location_table.start_index(location),
-            self.location_table.mid_index(location),
-        ));
-
-        self.all_facts.cfg_edge.push((
-            self.location_table.mid_index(location),
-            self.location_table.start_index(location.successor_within_block()),
-        ));
-
         // If there are borrows on this now dead local, we need to record them as `killed`.
         if let StatementKind::StorageDead(local) = statement.kind {
             self.record_killed_borrows_for_local(local, location);
@@ -58,21 +85,6 @@ fn visit_assign(&mut self, place: &Place<'tcx>, rvalue: &Rvalue<'tcx>, location:
     }
 
     fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location) {
-        // Also record CFG facts here.
-        self.all_facts.cfg_edge.push((
-            self.location_table.start_index(location),
-            self.location_table.mid_index(location),
-        ));
-
-        let successor_blocks = terminator.successors();
-        self.all_facts.cfg_edge.reserve(successor_blocks.size_hint().0);
-        for successor_block in successor_blocks {
-            self.all_facts.cfg_edge.push((
-                self.location_table.mid_index(location),
-                self.location_table.start_index(successor_block.start_location()),
-            ));
-        }
-
         // A `Call` terminator's return value can be a local which has borrows,
         // so we need to record those as `killed` as well.
         if let TerminatorKind::Call { destination, .. } = terminator.kind {
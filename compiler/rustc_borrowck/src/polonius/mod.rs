@@ -3,18 +3,30 @@
 //! Will be removed in the future, once the in-tree `-Zpolonius=next` implementation reaches feature
 //! parity.
 
-use rustc_middle::mir::{Body, LocalKind, Location, START_BLOCK};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_index::bit_set::BitSet;
+use rustc_index::IndexVec;
+use rustc_middle::mir::{
+    traversal, BasicBlock, Body, LocalKind, Location, StatementKind, TerminatorKind, START_BLOCK,
+};
 use rustc_middle::ty::TyCtxt;
 use rustc_mir_dataflow::move_paths::{InitKind, InitLocation, MoveData};
 
-use crate::borrow_set::BorrowSet;
-use crate::facts::AllFacts;
-use crate::location::LocationTable;
+use crate::borrow_set::{BorrowData, BorrowSet};
+use crate::facts::{
+    stream_facts_since, AllFacts, FactCounts, PoloniusBlockFactHook, PoloniusFact,
+    PoloniusFactHook,
+};
+use crate::location::{LocationIndex, LocationTable};
+use crate::region_infer::RegionInferenceContext;
 use crate::type_check::free_region_relations::UniversalRegionRelations;
 use crate::universal_regions::UniversalRegions;
+use crate::BorrowIndex;
 
 mod loan_invalidations;
 mod loan_kills;
+#[cfg(test)]
+mod tests;
 
 /// When requested, emit most of the facts needed by polonius:
 /// - moves and assignments
@@ -22,10 +34,20 @@
 /// - CFG points and edges
 /// - loan kills
 /// - loan invalidations
+/// - two-phase borrow activations, under `-Znll-facts-loan-activations`
 ///
 /// The rest of the facts are emitted during typeck and liveness.
+///
+/// If given, `fact_hook` is passed the facts of each of these steps as soon as it is done.
+///
+/// If given, `block_fact_hook` is called on each basic block once its CFG, loan kills and loan
+/// invalidations facts are emitted. These are then emitted one block after the other, instead of
+/// one kind of fact after the other, which changes neither the order of the rows in each relation
+/// nor the order in which `fact_hook` sees them.
 pub(crate) fn emit_facts<'tcx>(
     all_facts: &mut Option<AllFacts>,
+    mut fact_hook: Option<PoloniusFactHook<'_>>,
+    block_fact_hook: Option<PoloniusBlockFactHook<'_>>,
     tcx: TyCtxt<'tcx>,
     location_table: &LocationTable,
     body: &Body<'tcx>,
@@ -39,15 +61,177 @@ pub(crate) fn emit_facts<'tcx>(
         return;
     };
     let _prof_timer = tcx.prof.generic_activity("polonius_fact_generation");
-    emit_move_facts(all_facts, move_data, location_table, body);
-    emit_universal_region_facts(
-        all_facts,
-        borrow_set,
-        &universal_regions,
-        &universal_region_relations,
-    );
-    emit_cfg_and_loan_kills_facts(all_facts, tcx, location_table, body, borrow_set);
-    emit_loan_invalidations_facts(all_facts, tcx, location_table, body, borrow_set);
+    emit_step(all_facts, &mut fact_hook, |all_facts| {
+        emit_move_facts(all_facts, move_data, location_table, body)
+    });
+    emit_step(all_facts, &mut fact_hook, |all_facts| {
+        emit_universal_region_facts(
+            all_facts,
+            borrow_set,
+            &universal_regions,
+            &universal_region_relations,
+        )
+    });
+    if let Some(block_fact_hook) = block_fact_hook {
+        emit_step(all_facts, &mut fact_hook, |all_facts| {
+            for block in body.basic_blocks.indices() {
+                let blocks = [block];
+                emit_cfg_and_loan_kills_facts(
+                    all_facts,
+                    Some(tcx),
+                    location_table,
+                    body,
+                    borrow_set,
+                    blocks,
+                );
+                emit_loan_invalidations_facts(
+                    all_facts,
+                    tcx,
+                    location_table,
+                    body,
+                    borrow_set,
+                    blocks,
+                );
+                block_fact_hook(block, all_facts);
+            }
+        });
+    } else {
+        let blocks = body.basic_blocks.indices();
+        emit_step(all_facts, &mut fact_hook, |all_facts| {
+            emit_cfg_and_loan_kills_facts(
+                all_facts,
+                Some(tcx),
+                location_table,
+                body,
+                borrow_set,
+                blocks.clone(),
+            )
+        });
+        emit_step(all_facts, &mut fact_hook, |all_facts| {
+            emit_loan_invalidations_facts(all_facts, tcx, location_table, body, borrow_set, blocks)
+        });
+    }
+    if let Some(fact_hook) = fact_hook
+        && tcx.sess.opts.unstable_opts.nll_facts_loan_activations
+    {
+        for (loan, point) in loan_activations(location_table, borrow_set) {
+            fact_hook(&PoloniusFact::LoanActivatedAt(loan, point));
+        }
+    }
+}
+
+/// The `loan_activated_at` relation: the point where each two-phase borrow goes from reserved to
+/// active. Borrows that aren't two-phase are active as soon as they're issued, and have no rows.
+pub(crate) fn loan_activations(
+    location_table: &LocationTable,
+    borrow_set: &BorrowSet<'_>,
+) -> Vec<(BorrowIndex, LocationIndex)> {
+    borrow_set
+        .activation_map
+        .iter()
+        .flat_map(|(&location, borrows)| {
+            let point = location_table.mid_index(location);
+            borrows.iter().map(move |&borrow| (borrow, point))
+        })
+        .collect()
+}
+
+/// Checks that the `cfg_edge` facts connect the start of every reachable block to the function
+/// entry, and reports the blocks they don't reach as delayed bugs.
+pub(crate) fn verify_cfg_edges<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    all_facts: &AllFacts,
+    location_table: &LocationTable,
+    body: &Body<'tcx>,
+) {
+    let mut successors: IndexVec<LocationIndex, Vec<LocationIndex>> =
+        location_table.all_points().map(|_| Vec::new()).collect();
+    for &(from, to) in &all_facts.cfg_edge {
+        successors[from].push(to);
+    }
+
+    let mut visited = BitSet::new_empty(successors.len());
+    let mut stack =
+        vec![location_table.start_index(Location { block: START_BLOCK, statement_index: 0 })];
+    while let Some(point) = stack.pop() {
+        if visited.insert(point) {
+            stack.extend(successors[point].iter().copied());
+        }
+    }
+
+    for block in traversal::reachable_as_bitset(body).iter() {
+        let start = location_table.start_index(Location { block, statement_index: 0 });
+        if !visited.contains(start) {
+            tcx.dcx().span_delayed_bug(
+                body[block].terminator().source_info.span,
+                format!("{block:?} is not reachable from the entry through `cfg_edge` facts"),
+            );
+        }
+    }
+}
+
+/// Checks that every loan is either killed somewhere, per the `loan_killed_at` facts, or still
+/// live when the function returns: its region contains a `Return` terminator, or it borrows from a
+/// local whose storage is never dead, like an argument. Loans vanishing without explanation are
+/// reported as delayed bugs.
+pub(crate) fn verify_loan_kills<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    all_facts: &AllFacts,
+    body: &Body<'tcx>,
+    borrow_set: &BorrowSet<'tcx>,
+    regioncx: &RegionInferenceContext<'tcx>,
+) {
+    let mut storage_dead = BitSet::new_empty(body.local_decls.len());
+    let mut returns = Vec::new();
+    for (block, data) in body.basic_blocks.iter_enumerated() {
+        for statement in &data.statements {
+            if let StatementKind::StorageDead(local) = statement.kind {
+                storage_dead.insert(local);
+            }
+        }
+        if let TerminatorKind::Return = data.terminator().kind {
+            returns.push(body.terminator_loc(block));
+        }
+    }
+
+    let live_at_return = |loan: BorrowIndex| {
+        let borrow = &borrow_set[loan];
+        !storage_dead.contains(borrow.borrowed_place.local)
+            || returns.iter().any(|&location| regioncx.region_contains(borrow.region, location))
+    };
+    for loan in unexplained_loans(all_facts, borrow_set.indices(), live_at_return) {
+        let BorrowData { reserve_location, borrowed_place, .. } = &borrow_set[loan];
+        tcx.dcx().span_delayed_bug(
+            body.source_info(*reserve_location).span,
+            format!("{loan:?} of `{borrowed_place:?}` is never killed, but is dead on return"),
+        );
+    }
+}
+
+/// The `loans` that are neither killed, per the `loan_killed_at` facts, nor `live_at_return`.
+fn unexplained_loans(
+    all_facts: &AllFacts,
+    loans: impl Iterator<Item = BorrowIndex>,
+    live_at_return: impl Fn(BorrowIndex) -> bool,
+) -> Vec<BorrowIndex> {
+    let killed: FxHashSet<BorrowIndex> =
+        all_facts.loan_killed_at.iter().map(|&(loan, _)| loan).collect();
+    loans.filter(|&loan| !killed.contains(&loan) && !live_at_return(loan)).collect()
+}
+
+/// Runs a single step of the fact generation, and passes the facts it emitted to `fact_hook`.
+fn emit_step(
+    all_facts: &mut AllFacts,
+    fact_hook: &mut Option<PoloniusFactHook<'_>>,
+    step: impl FnOnce(&mut AllFacts),
+) {
+    let Some(fact_hook) = fact_hook else {
+        step(all_facts);
+        return;
+    };
+    let counts = FactCounts::of(all_facts);
+    step(all_facts);
+    stream_facts_since(all_facts, &counts, *fact_hook);
 }
 
 /// Emit facts needed for move/init analysis: moves and assignments.
@@ -152,37 +336,57 @@ fn emit_universal_region_facts(
     }
 
     // 2: the universal region relations `outlives` constraints are emitted as
-    //  `known_placeholder_subset` facts.
-    for (fr1, fr2) in universal_region_relations.known_outlives() {
-        if fr1 != fr2 {
-            debug!(
-                "emit_universal_region_facts: emitting polonius `known_placeholder_subset` \
-                     fr1={:?}, fr2={:?}",
-                fr1, fr2
-            );
-            all_facts.known_placeholder_subset.push((fr1, fr2));
+    //  `known_placeholder_subset` facts. The relation is emitted transitively closed: a function
+    //  with `'b: 'a` and `'c: 'b` also knows `'c: 'a`, even though no bound states it.
+    for fr1 in universal_regions.universal_regions() {
+        for fr2 in universal_region_relations.regions_outlived_by(fr1) {
+            if fr1 != fr2 {
+                debug!(
+                    "emit_universal_region_facts: emitting polonius `known_placeholder_subset` \
+                         fr1={:?}, fr2={:?}",
+                    fr1, fr2
+                );
+                all_facts.known_placeholder_subset.push((fr1, fr2));
+            }
         }
     }
 }
 
-/// Emit facts about loan invalidations.
+/// Emit facts about loan invalidations in `blocks`.
 fn emit_loan_invalidations_facts<'tcx>(
     all_facts: &mut AllFacts,
     tcx: TyCtxt<'tcx>,
     location_table: &LocationTable,
     body: &Body<'tcx>,
     borrow_set: &BorrowSet<'tcx>,
+    blocks: impl IntoIterator<Item = BasicBlock>,
 ) {
-    loan_invalidations::emit_loan_invalidations(tcx, all_facts, location_table, body, borrow_set);
+    loan_invalidations::emit_loan_invalidations(
+        tcx,
+        all_facts,
+        location_table,
+        body,
+        borrow_set,
+        blocks,
+    );
 }
 
-/// Emit facts about CFG points and edges, as well as locations where loans are killed.
+/// Emit facts about CFG points and edges in `blocks`, as well as locations where loans are killed.
+///
+/// The CFG only depends on `body`, and only killing loans needs `tcx`, to find the borrowed places
+/// conflicting with an assigned place. So when `borrow_set` is empty, `tcx` may be `None`, which
+/// lets this run on a handcrafted `body`.
 fn emit_cfg_and_loan_kills_facts<'tcx>(
     all_facts: &mut AllFacts,
-    tcx: TyCtxt<'tcx>,
+    tcx: Option<TyCtxt<'tcx>>,
     location_table: &LocationTable,
     body: &Body<'tcx>,
     borrow_set: &BorrowSet<'tcx>,
+    blocks: impl IntoIterator<Item = BasicBlock> + Clone,
 ) {
-    loan_kills::emit_loan_kills(tcx, all_facts, location_table, body, borrow_set);
+    loan_kills::emit_cfg_edges(all_facts, location_table, body, blocks.clone());
+    if !borrow_set.location_map.is_empty() {
+        let tcx = tcx.expect("killing loans needs a `TyCtxt`");
+        loan_kills::emit_loan_kills(tcx, all_facts, location_table, body, borrow_set, blocks);
+    }
 }
@@ -0,0 +1,81 @@
+//! Tests for the fact generation that doesn't need a `TyCtxt`.
+
+use rustc_index::IndexVec;
+use rustc_middle::mir::{self, BasicBlock, Location, SourceInfo};
+use rustc_span::DUMMY_SP;
+
+use super::*;
+use crate::borrow_set::LocalsStateAtExit;
+
+/// The `cfg_edge` and `loan_killed_at` facts of a `body` without borrows.
+fn cfg_and_loan_kills_facts(body: &Body<'_>) -> (AllFacts, LocationTable) {
+    let location_table = LocationTable::new(body);
+    let borrow_set = BorrowSet {
+        location_map: Default::default(),
+        activation_map: Default::default(),
+        local_map: Default::default(),
+        locals_state_at_exit: LocalsStateAtExit::AllAreInvalidated,
+    };
+    let mut all_facts = AllFacts::default();
+    let blocks = body.basic_blocks.indices();
+    emit_cfg_and_loan_kills_facts(&mut all_facts, None, &location_table, body, &borrow_set, blocks);
+    (all_facts, location_table)
+}
+
+/// A body made of blocks of `Nop`s, each ending with the given terminator.
+fn mock_body<'tcx>(blocks: &[(usize, mir::TerminatorKind<'tcx>)]) -> Body<'tcx> {
+    let source_info = SourceInfo::outermost(DUMMY_SP);
+    let nop = mir::Statement { source_info, kind: mir::StatementKind::Nop };
+    let blocks: IndexVec<BasicBlock, _> = blocks
+        .iter()
+        .map(|(statements, kind)| mir::BasicBlockData {
+            statements: vec![nop.clone(); *statements],
+            terminator: Some(mir::Terminator { source_info, kind: kind.clone() }),
+            is_cleanup: false,
+        })
+        .collect();
+    Body::new_cfg_only(blocks)
+}
+
+#[test]
+fn straight_line_cfg_edges() {
+    let body = mock_body(&[(2, mir::TerminatorKind::Return)]);
+    let (all_facts, location_table) = cfg_and_loan_kills_facts(&body);
+
+    // Start to mid, and mid to the next start, for both statements, then start to mid for the
+    // `return`, which has no successor.
+    assert_eq!(all_facts.cfg_edge.len(), 5);
+    let points: Vec<_> = location_table.all_points().collect();
+    let expected: Vec<_> = points.windows(2).map(|w| (w[0], w[1])).collect();
+    assert_eq!(all_facts.cfg_edge, expected);
+    assert!(all_facts.loan_killed_at.is_empty());
+}
+
+#[test]
+fn cfg_edges_into_successor_blocks() {
+    let target = BasicBlock::from_u32(1);
+    let body =
+        mock_body(&[(1, mir::TerminatorKind::Goto { target }), (0, mir::TerminatorKind::Return)]);
+    let (all_facts, location_table) = cfg_and_loan_kills_facts(&body);
+
+    let goto = Location { block: mir::START_BLOCK, statement_index: 1 };
+    let target_start = location_table.start_index(target.start_location());
+    assert!(all_facts.cfg_edge.contains(&(location_table.mid_index(goto), target_start)));
+    assert_eq!(all_facts.cfg_edge.len(), 5);
+}
+
+#[test]
+fn loans_missing_their_kill_are_unexplained() {
+    let [killed, live, vanished] = [0, 1, 2].map(BorrowIndex::from_u32);
+    let loans = [killed, live, vanished];
+    let live_at_return = |loan| loan == live;
+
+    let mut all_facts = AllFacts::default();
+    all_facts.loan_killed_at.push((killed, LocationIndex::from_u32(0)));
+    all_facts.loan_killed_at.push((vanished, LocationIndex::from_u32(1)));
+    assert!(unexplained_loans(&all_facts, loans.into_iter(), live_at_return).is_empty());
+
+    // Drop the kill of `vanished`, as a broken fact generation would.
+    all_facts.loan_killed_at.pop();
+    assert_eq!(unexplained_loans(&all_facts, loans.into_iter(), live_at_return), [vanished]);
+}
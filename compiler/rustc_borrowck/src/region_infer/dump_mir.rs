@@ -6,6 +6,7 @@
 use super::{OutlivesConstraint, RegionInferenceContext};
 use crate::type_check::Locations;
 use rustc_infer::infer::NllRegionVariableOrigin;
+use rustc_middle::mir::{Body, Location};
 use rustc_middle::ty::TyCtxt;
 use std::io::{self, Write};
 
@@ -55,6 +56,32 @@ pub(crate) fn dump_mir(&self, tcx: TyCtxt<'tcx>, out: &mut dyn Write) -> io::Res
         Ok(())
     }
 
+    /// Write out the regions live at each MIR location as a JSON object, mapping locations like
+    /// `"bb0[1]"` to arrays of region variables like `"'?2"`.
+    pub(crate) fn dump_live_regions_json(
+        &self,
+        body: &Body<'tcx>,
+        out: &mut dyn Write,
+    ) -> io::Result<()> {
+        writeln!(out, "{{")?;
+        let mut first = true;
+        for (block, data) in body.basic_blocks.iter_enumerated() {
+            for statement_index in 0..=data.statements.len() {
+                let location = Location { block, statement_index };
+                let live_regions: Vec<_> = self
+                    .definitions
+                    .indices()
+                    .filter(|&region| self.liveness_constraints.is_live_at(region, location))
+                    .map(|region| format!("\"{region:?}\""))
+                    .collect();
+                let separator = if first { "" } else { ",\n" };
+                first = false;
+                write!(out, "{separator}  \"{location:?}\": [{}]", live_regions.join(", "))?;
+            }
+        }
+        writeln!(out, "\n}}")
+    }
+
     /// Debugging aid: Invokes the `with_msg` callback repeatedly with
     /// our internal region constraints. These are dumped into the
     /// -Zdump-mir file so that we can figure out why the region
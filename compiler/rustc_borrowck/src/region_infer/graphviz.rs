@@ -26,6 +26,15 @@ pub(crate) fn dump_graphviz_scc_constraints(&self, mut w: &mut dyn Write) -> io:
 
         dot::render(&SccConstraints { regioncx: self, nodes_per_scc }, &mut w)
     }
+
+    /// The label of `region` in the graph: its name, like `'a` or `'static`, if it is a named
+    /// universal region, and its vid otherwise.
+    fn region_label(&self, region: RegionVid) -> String {
+        match self.definitions[region].external_name.and_then(|r| r.get_name()) {
+            Some(name) => name.to_string(),
+            None => format!("{region:?}"),
+        }
+    }
 }
 
 struct RawConstraints<'a, 'tcx> {
@@ -46,7 +55,7 @@ fn node_shape(&'this self, _node: &RegionVid) -> Option<dot::LabelText<'this>> {
         Some(dot::LabelText::LabelStr(Cow::Borrowed("box")))
     }
     fn node_label(&'this self, n: &RegionVid) -> dot::LabelText<'this> {
-        dot::LabelText::LabelStr(format!("{n:?}").into())
+        dot::LabelText::LabelStr(self.regioncx.region_label(*n).into())
     }
     fn edge_label(&'this self, e: &OutlivesConstraint<'tcx>) -> dot::LabelText<'this> {
         dot::LabelText::LabelStr(format!("{:?}", e.locations).into())
@@ -96,8 +105,9 @@ fn node_shape(&'this self, _node: &ConstraintSccIndex) -> Option<dot::LabelText<
         Some(dot::LabelText::LabelStr(Cow::Borrowed("box")))
     }
     fn node_label(&'this self, n: &ConstraintSccIndex) -> dot::LabelText<'this> {
-        let nodes = &self.nodes_per_scc[*n];
-        dot::LabelText::LabelStr(format!("{n:?} = {nodes:?}").into())
+        let nodes = self.nodes_per_scc[*n].iter().map(|&r| self.regioncx.region_label(r));
+        let nodes = nodes.collect::<Vec<_>>().join(", ");
+        dot::LabelText::LabelStr(format!("{n:?} = [{nodes}]").into())
     }
 }
 
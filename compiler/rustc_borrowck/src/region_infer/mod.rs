@@ -331,6 +331,38 @@ pub(crate) fn new<'cx>(
         type_tests: Vec<TypeTest<'tcx>>,
         liveness_constraints: LivenessValues,
         elements: &Rc<DenseLocationMap>,
+    ) -> Self {
+        let result = Self::new_inner(
+            var_infos,
+            universal_regions,
+            &placeholder_indices,
+            universal_region_relations,
+            outlives_constraints,
+            member_constraints_in,
+            universe_causes,
+            type_tests,
+            liveness_constraints,
+            elements,
+        );
+
+        if cfg!(debug_assertions) {
+            sccs_info(_infcx, result.constraint_sccs.clone());
+        }
+
+        result
+    }
+
+    fn new_inner(
+        var_infos: VarInfos,
+        universal_regions: Rc<UniversalRegions<'tcx>>,
+        placeholder_indices: &Rc<PlaceholderIndices>,
+        universal_region_relations: Frozen<UniversalRegionRelations<'tcx>>,
+        outlives_constraints: OutlivesConstraintSet<'tcx>,
+        member_constraints_in: MemberConstraintSet<'tcx, RegionVid>,
+        universe_causes: FxIndexMap<ty::UniverseIndex, UniverseInfo<'tcx>>,
+        type_tests: Vec<TypeTest<'tcx>>,
+        liveness_constraints: LivenessValues,
+        elements: &Rc<DenseLocationMap>,
     ) -> Self {
         debug!("universal_regions: {:#?}", universal_regions);
         debug!("outlives constraints: {:#?}", outlives_constraints);
@@ -348,12 +380,8 @@ pub(crate) fn new<'cx>(
         let fr_static = universal_regions.fr_static;
         let constraint_sccs = Rc::new(constraints.compute_sccs(&constraint_graph, fr_static));
 
-        if cfg!(debug_assertions) {
-            sccs_info(_infcx, constraint_sccs.clone());
-        }
-
         let mut scc_values =
-            RegionValues::new(elements, universal_regions.len(), &placeholder_indices);
+            RegionValues::new(elements, universal_regions.len(), placeholder_indices);
 
         for region in liveness_constraints.regions() {
             let scc = constraint_sccs.scc(region);
@@ -591,6 +619,20 @@ pub fn outlives_constraints(&self) -> impl Iterator<Item = OutlivesConstraint<'t
         self.constraints.outlives().iter().copied()
     }
 
+    /// Explains why `sup: sub` holds, as the shortest chain of outlives constraints leading
+    /// from `sup` to `sub`. Each constraint carries the locations and category that caused it.
+    /// This includes the edges that member constraints and `'static` add to the constraint graph.
+    ///
+    /// Returns `None` if the constraints don't relate the two regions, and an empty chain if
+    /// `sup == sub`. Cycles in the constraint graph are fine: each region is visited once.
+    pub fn explain_outlives(
+        &self,
+        sup: RegionVid,
+        sub: RegionVid,
+    ) -> Option<Vec<OutlivesConstraint<'tcx>>> {
+        self.find_constraint_paths_between_regions(sup, |r| r == sub).map(|(path, _)| path)
+    }
+
     /// Adds annotations for `#[rustc_regions]`; see `UniversalRegions::annotate`.
     pub(crate) fn annotate(&self, tcx: TyCtxt<'tcx>, err: &mut Diag<'_, ()>) {
         self.universal_regions.annotate(tcx, err)
@@ -660,12 +702,68 @@ pub(super) fn solve(
         infcx: &InferCtxt<'tcx>,
         body: &Body<'tcx>,
         polonius_output: Option<Rc<PoloniusOutput>>,
+    ) -> (Option<ClosureRegionRequirements<'tcx>>, RegionErrors<'tcx>) {
+        // In Polonius mode, the errors about missing universal region relations are in the output.
+        let polonius_output = infcx.tcx.sess.opts.unstable_opts.polonius.is_legacy_enabled().then(
+            || polonius_output.expect("Polonius output is unavailable despite `-Z polonius`"),
+        );
+        self.solve_into(infcx, body, polonius_output, RegionErrors::new(infcx.tcx))
+    }
+
+    /// Re-solves the region constraints as if the MIR type check had also
+    /// produced the outlives constraints in `added`, but none of those in
+    /// `removed`, and returns the resulting errors. `self` is left untouched,
+    /// and the errors are never reported.
+    ///
+    /// Only the constraint graph and its SCCs are rebuilt: the liveness
+    /// constraints, member constraints and type tests are carried over
+    /// as-is. The errors are always computed by the NLL solver, even when
+    /// running under `-Zpolonius`.
+    pub fn solve_with_constraint_delta(
+        &self,
+        infcx: &InferCtxt<'tcx>,
+        body: &Body<'tcx>,
+        added: &[OutlivesConstraint<'tcx>],
+        removed: &[OutlivesConstraint<'tcx>],
+    ) -> RegionErrors<'tcx> {
+        let mut outlives_constraints = OutlivesConstraintSet::default();
+        for constraint in self.outlives_constraints().filter(|c| !removed.contains(c)) {
+            outlives_constraints.push(constraint);
+        }
+        for &constraint in added {
+            outlives_constraints.push(constraint);
+        }
+
+        let mut regioncx = Self::new_inner(
+            self.var_infos.clone(),
+            self.universal_regions.clone(),
+            self.scc_values.placeholder_indices(),
+            Frozen::freeze((*self.universal_region_relations).clone()),
+            outlives_constraints,
+            self.member_constraints.to_member_region_vids(),
+            self.universe_causes.clone(),
+            self.type_tests.clone(),
+            self.liveness_constraints.clone(),
+            self.scc_values.elements(),
+        );
+        let (_, errors) =
+            regioncx.solve_into(infcx, body, None, RegionErrors::unreported(infcx.tcx));
+        errors
+    }
+
+    /// Solves the region constraints, collecting the errors into
+    /// `errors_buffer`. If `polonius_output` is given, the errors about
+    /// universal regions are taken from it.
+    fn solve_into(
+        &mut self,
+        infcx: &InferCtxt<'tcx>,
+        body: &Body<'tcx>,
+        polonius_output: Option<Rc<PoloniusOutput>>,
+        mut errors_buffer: RegionErrors<'tcx>,
     ) -> (Option<ClosureRegionRequirements<'tcx>>, RegionErrors<'tcx>) {
         let mir_def_id = body.source.def_id();
         self.propagate_constraints();
 
-        let mut errors_buffer = RegionErrors::new(infcx.tcx);
-
         // If this is a closure, we can propagate unsatisfied
         // `outlives_requirements` to our creator, so create a vector
         // to store those. Otherwise, we'll pass in `None` to the
@@ -681,11 +779,11 @@ pub(super) fn solve(
         // In Polonius mode, the errors about missing universal region relations are in the output
         // and need to be emitted or propagated. Otherwise, we need to check whether the
         // constraints were too strong, and if so, emit or propagate those errors.
-        if infcx.tcx.sess.opts.unstable_opts.polonius.is_legacy_enabled() {
+        if let Some(polonius_output) = polonius_output {
             self.check_polonius_subset_errors(
                 outlives_requirements.as_mut(),
                 &mut errors_buffer,
-                polonius_output.expect("Polonius output is unavailable despite `-Z polonius`"),
+                polonius_output,
             );
         } else {
             self.check_universal_regions(outlives_requirements.as_mut(), &mut errors_buffer);
@@ -1985,7 +2083,8 @@ pub(crate) fn upper_bound_in_region_scc(&self, r: RegionVid, upper: RegionVid) -
         self.scc_values.contains(r_scc, upper)
     }
 
-    pub(crate) fn universal_regions(&self) -> &UniversalRegions<'tcx> {
+    /// The universal regions of the body, e.g. to map its named lifetimes to their region vids.
+    pub fn universal_regions(&self) -> &UniversalRegions<'tcx> {
         self.universal_regions.as_ref()
     }
 
@@ -21,7 +21,7 @@ pub struct PlaceholderIndex {}
 /// An individual element in a region value -- the value of a
 /// particular region variable consists of a set of these elements.
 #[derive(Debug, Clone)]
-pub(crate) enum RegionElement {
+pub enum RegionElement {
     /// A point in the control-flow graph.
     Location(Location),
 
@@ -36,6 +36,7 @@ pub(crate) enum RegionElement {
 
 /// Records the CFG locations where each region is live. When we initially compute liveness, we use
 /// an interval matrix storing liveness ranges for each region-vid.
+#[derive(Clone)]
 pub(crate) struct LivenessValues {
     /// The map from locations to points.
     elements: Rc<DenseLocationMap>,
@@ -57,6 +58,7 @@ pub(crate) struct LivenessValues {
 
 /// Data used to compute the loans that are live at a given point in the CFG, when using
 /// `-Zpolonius=next`.
+#[derive(Clone)]
 pub(crate) struct LiveLoans {
     /// The set of loans that flow into a given region. When individual regions are marked as live
     /// in the CFG, these inflowing loans are recorded as live.
@@ -293,6 +295,16 @@ pub(crate) fn new(
         }
     }
 
+    /// The map from locations to points shared by these values.
+    pub(crate) fn elements(&self) -> &Rc<DenseLocationMap> {
+        &self.elements
+    }
+
+    /// The placeholders that these values may contain.
+    pub(crate) fn placeholder_indices(&self) -> &Rc<PlaceholderIndices> {
+        &self.placeholder_indices
+    }
+
     /// Adds the given element to the value for the given region. Returns whether
     /// the element is newly added (i.e., was not already present).
     pub(crate) fn add_element(&mut self, r: N, elem: impl ToElementIndex) -> bool {
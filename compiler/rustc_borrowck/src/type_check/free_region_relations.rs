@@ -23,7 +23,7 @@
     universal_regions::UniversalRegions,
 };
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct UniversalRegionRelations<'tcx> {
     universal_regions: Rc<UniversalRegions<'tcx>>,
 
@@ -169,11 +169,6 @@ pub(crate) fn outlives(&self, fr1: RegionVid, fr2: RegionVid) -> bool {
     pub(crate) fn regions_outlived_by(&self, fr1: RegionVid) -> Vec<RegionVid> {
         self.outlives.reachable_from(fr1)
     }
-
-    /// Returns the _non-transitive_ set of known `outlives` constraints between free regions.
-    pub(crate) fn known_outlives(&self) -> impl Iterator<Item = (RegionVid, RegionVid)> + '_ {
-        self.outlives.base_edges()
-    }
 }
 
 struct UniversalRegionRelationsBuilder<'this, 'tcx> {
@@ -250,6 +250,8 @@ fn translate_outlives_facts(typeck: &mut TypeChecker<'_, '_>) {
                         location_table.mid_index(from_location),
                     )))
                 } else {
+                    // Constraints from user type ascriptions, like those of `let x: &'a T = ..`,
+                    // are checked with `Locations::All`, and so hold at every point.
                     Either::Right(
                         location_table
                             .all_points()
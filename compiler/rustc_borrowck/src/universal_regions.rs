@@ -238,7 +238,7 @@ impl<'tcx> UniversalRegions<'tcx> {
     /// MIR -- that is, all the regions that appear in the function's
     /// signature. This will also compute the relationships that are
     /// known between those regions.
-    pub fn new(
+    pub(crate) fn new(
         infcx: &BorrowckInferCtxt<'_, 'tcx>,
         mir_def: LocalDefId,
         param_env: ty::ParamEnv<'tcx>,
@@ -683,10 +683,12 @@ macro_rules! untracked {
     untracked!(meta_stats, true);
     untracked!(mir_include_spans, true);
     untracked!(nll_facts, true);
+    untracked!(nll_facts_filter, Some(String::from("abc")));
     untracked!(no_analysis, true);
     untracked!(no_leak_check, true);
     untracked!(no_parallel_llvm, true);
     untracked!(parse_only, true);
+    untracked!(pattern_witness_display_limit, 5);
     // `pre_link_arg` is omitted because it just forwards to `pre_link_args`.
     untracked!(pre_link_args, vec![String::from("abc"), String::from("def")]);
     untracked!(print_codegen_stats, true);
@@ -326,6 +326,7 @@ mir_build_union_pattern = cannot use unions in constant patterns
 mir_build_unreachable_pattern = unreachable pattern
     .label = unreachable pattern
     .catchall_label = matches any value
+    .covered_by_range_label = this range already matches these values
 
 mir_build_unsafe_fn_safe_body = an unsafe function restricts its caller, but its body is safe by default
 mir_build_unsafe_not_inherited = items do not inherit unsafety from separate enclosing items
@@ -573,6 +573,8 @@ pub struct UnreachablePattern {
     pub span: Option<Span>,
     #[label(mir_build_catchall_label)]
     pub catchall: Option<Span>,
+    #[label(mir_build_covered_by_range_label)]
+    pub covered_by: Option<Span>,
 }
 
 #[derive(Diagnostic)]
@@ -3,6 +3,7 @@
     Constructor, DeconstructedPat, MatchArm, RustcMatchCheckCtxt as MatchCheckCtxt, Usefulness,
     UsefulnessReport, WitnessPat,
 };
+use rustc_pattern_analysis::usefulness::RedundancyReason;
 
 use crate::errors::*;
 
@@ -395,6 +396,9 @@ fn new_cx(
             scrut_span,
             refutable,
             known_valid_scrutinee,
+            witness_display_limit: self.tcx.sess.opts.unstable_opts.pattern_witness_display_limit,
+            exclusive_range_witnesses: false,
+            coroutine_states_as_variants: false,
         }
     }
 
@@ -422,7 +426,13 @@ fn analyze_patterns(
                 // Emit lints in the order in which they occur in the file.
                 redundant_subpats.sort_unstable_by_key(|pat| pat.data().unwrap().span);
                 for pat in redundant_subpats {
-                    report_unreachable_pattern(cx, arm.arm_data, pat.data().unwrap().span, None)
+                    report_unreachable_pattern(
+                        cx,
+                        arm.arm_data,
+                        pat.data().unwrap().span,
+                        None,
+                        None,
+                    )
                 }
             }
         }
@@ -457,7 +467,9 @@ fn check_match(
         expr_span: Span,
     ) {
         let scrut = &self.thir[scrut];
-        let cx = self.new_cx(Refutable, Some(expr_span), Some(scrut), scrut.span);
+        let mut cx = self.new_cx(Refutable, Some(expr_span), Some(scrut), scrut.span);
+        cx.exclusive_range_witnesses =
+            uses_only_exclusive_ranges(arms.iter().map(|&arm| &*self.thir.arms[arm].pattern));
 
         let mut tarms = Vec::with_capacity(arms.len());
         for &arm in arms {
@@ -888,12 +900,14 @@ fn report_unreachable_pattern<'p, 'tcx>(
     hir_id: HirId,
     span: Span,
     catchall: Option<Span>,
+    covered_by: Option<Span>,
 ) {
+    let span_label = if catchall.is_some() || covered_by.is_some() { Some(span) } else { None };
     cx.tcx.emit_node_span_lint(
         UNREACHABLE_PATTERNS,
         hir_id,
         span,
-        UnreachablePattern { span: if catchall.is_some() { Some(span) } else { None }, catchall },
+        UnreachablePattern { span: span_label, catchall, covered_by },
     );
 }
 
@@ -903,9 +917,34 @@ fn report_arm_reachability<'p, 'tcx>(
     report: &UsefulnessReport<'p, 'tcx>,
 ) {
     let mut catchall = None;
-    for (arm, is_useful) in report.arm_usefulness.iter() {
+    for (arm_id, (arm, is_useful)) in report.arm_usefulness.iter().enumerate() {
         if matches!(is_useful, Usefulness::Redundant) {
-            report_unreachable_pattern(cx, arm.arm_data, arm.pat.data().unwrap().span, catchall)
+            // Point at the earliest range that covers this arm on its own, if any. A catchall
+            // already explains the redundancy.
+            let covering_pat = match report
+                .redundancy_reasons
+                .iter()
+                .find(|&&(redundant_id, _)| redundant_id == arm_id)
+                .map(|&(_, reason)| reason)
+            {
+                Some(RedundancyReason::SubsumedBy { covering_arm: Some(covering_id) }) => {
+                    Some(report.arm_usefulness[covering_id].0.pat.data().unwrap())
+                }
+                _ => None,
+            };
+            let covered_by = match covering_pat {
+                Some(pat) if catchall.is_none() && matches!(pat.kind, PatKind::Range(_)) => {
+                    Some(pat.span)
+                }
+                _ => None,
+            };
+            report_unreachable_pattern(
+                cx,
+                arm.arm_data,
+                arm.pat.data().unwrap().span,
+                catchall,
+                covered_by,
+            )
         }
         if !arm.has_guard && catchall.is_none() && pat_is_catchall(arm.pat) {
             catchall = Some(arm.pat.data().unwrap().span);
@@ -913,6 +952,27 @@ fn report_arm_reachability<'p, 'tcx>(
     }
 }
 
+/// Whether the patterns contain `lo..hi` ranges and no `lo..=hi` ones, in which case we report
+/// range witnesses with exclusive ends too.
+fn uses_only_exclusive_ranges<'a, 'tcx: 'a>(pats: impl Iterator<Item = &'a Pat<'tcx>>) -> bool {
+    let mut exclusive = false;
+    let mut inclusive = false;
+    for pat in pats {
+        pat.walk_always(|pat| {
+            if let PatKind::Range(range) = &pat.kind
+                && let (PatRangeBoundary::Finite(_), PatRangeBoundary::Finite(_)) =
+                    (range.lo, range.hi)
+            {
+                match range.end {
+                    hir::RangeEnd::Excluded => exclusive = true,
+                    hir::RangeEnd::Included => inclusive = true,
+                }
+            }
+        });
+    }
+    exclusive && !inclusive
+}
+
 /// Checks for common cases of "catchall" patterns that may not be intended as such.
 fn pat_is_catchall(pat: &DeconstructedPat<'_, '_>) -> bool {
     match pat.ctor() {
@@ -1127,17 +1187,18 @@ fn joined_uncovered_patterns<'p, 'tcx>(
     cx: &MatchCheckCtxt<'p, 'tcx>,
     witnesses: &[WitnessPat<'p, 'tcx>],
 ) -> String {
-    const LIMIT: usize = 3;
+    // Always list at least one witness, a bare "10 more not covered" says nothing useful.
+    let limit = cx.witness_display_limit.max(1);
     let pat_to_str = |pat: &WitnessPat<'p, 'tcx>| cx.hoist_witness_pat(pat).to_string();
     match witnesses {
         [] => bug!(),
         [witness] => format!("`{}`", cx.hoist_witness_pat(witness)),
-        [head @ .., tail] if head.len() < LIMIT => {
+        [head @ .., tail] if head.len() < limit => {
             let head: Vec<_> = head.iter().map(pat_to_str).collect();
             format!("`{}` and `{}`", head.join("`, `"), cx.hoist_witness_pat(tail))
         }
         _ => {
-            let (head, tail) = witnesses.split_at(LIMIT);
+            let (head, tail) = witnesses.split_at(limit);
             let head: Vec<_> = head.iter().map(pat_to_str).collect();
             format!("`{}` and {} more", head.join("`, `"), tail.len())
         }
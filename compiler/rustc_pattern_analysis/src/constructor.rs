@@ -471,6 +471,25 @@ fn is_covered_by(self, other: Self) -> bool {
         other.kind.covers_length(self.arity())
     }
 
+    /// The lengths of the values matched by both `self` and `other`, or `None` if they have no
+    /// length in common. `FixedLen(n)` stands for the values of length `n`, and `VarLen(n, 0)`
+    /// for the values of length at least `n`. Subpatterns are not taken into account, so e.g.
+    /// `[true]` and `[false, ..]` overlap on `FixedLen(1)`.
+    pub fn overlapping_lengths(self, other: Self) -> Option<SliceKind> {
+        // Arrays only have a single length.
+        if let Some(len) = self.array_len.or(other.array_len) {
+            let covered = self.kind.covers_length(len) && other.kind.covers_length(len);
+            return covered.then_some(FixedLen(len));
+        }
+        match (self.kind, other.kind) {
+            (FixedLen(len), kind) | (kind, FixedLen(len)) => {
+                kind.covers_length(len).then_some(FixedLen(len))
+            }
+            // `[x, .., y]` and `[x, y, z, ..]` both match any slice that is long enough for both.
+            (VarLen(..), VarLen(..)) => Some(VarLen(max(self.arity(), other.arity()), 0)),
+        }
+    }
+
     /// This computes constructor splitting for variable-length slices, as explained at the top of
     /// the file.
     ///
@@ -756,6 +775,20 @@ pub(crate) fn arity(&self, cx: &Cx, ty: &Cx::Ty) -> usize {
         cx.ctor_arity(self, ty)
     }
 
+    /// The number of fields for this constructor, for the constructors whose arity doesn't depend
+    /// on the type, e.g. `Slice` or `Bool`. Returns `None` for `Struct`, `Variant` and
+    /// `UnionField`, whose arity must be asked of [`TypeCx::ctor_arity`], and for `Or`, which
+    /// doesn't have a fixed arity.
+    pub fn structural_arity(&self) -> Option<usize> {
+        match self {
+            Struct | Variant(_) | UnionField | Or => None,
+            Ref => Some(1),
+            Slice(slice) => Some(slice.arity()),
+            Bool(..) | IntRange(..) | F32Range(..) | F64Range(..) | Str(..) | Opaque(..)
+            | NonExhaustive | Hidden | Missing | PrivateUninhabited | Wildcard => Some(0),
+        }
+    }
+
     /// Returns whether `self` is covered by `other`, i.e. whether `self` is a subset of `other`.
     /// For the simple cases, this is simply checking for equality. For the "grouped" constructors,
     /// this checks for inclusion.
@@ -1049,4 +1082,34 @@ pub fn split<'a>(
 
         SplitConstructorSet { present, missing, missing_empty }
     }
+
+    /// Lists the constructors of the type, without looking at any patterns: each variant of an
+    /// enum (hidden or empty ones included), `false` and `true`, the full range(s) of an integer
+    /// type, and so on. Types whose constructors can't be listed yield just `NonExhaustive`, as do
+    /// `#[non_exhaustive]` enums after their variants.
+    pub fn iter_ctors(&self) -> impl Iterator<Item = Constructor<Cx>> {
+        let mut ctors = Vec::new();
+        match self {
+            ConstructorSet::Struct { .. } => ctors.push(Struct),
+            ConstructorSet::Ref => ctors.push(Ref),
+            ConstructorSet::Union => ctors.push(UnionField),
+            ConstructorSet::Variants { variants, non_exhaustive } => {
+                ctors.extend(variants.iter_enumerated().map(|(idx, _)| Variant(idx)));
+                if *non_exhaustive {
+                    ctors.push(NonExhaustive);
+                }
+            }
+            ConstructorSet::Bool => ctors.extend([Bool(false), Bool(true)]),
+            ConstructorSet::Integers { range_1, range_2 } => {
+                ctors.push(IntRange(*range_1));
+                ctors.extend(range_2.map(IntRange));
+            }
+            ConstructorSet::Slice { array_len, .. } => {
+                ctors.push(Slice(Slice::new(*array_len, VarLen(0, 0))))
+            }
+            ConstructorSet::Unlistable => ctors.push(NonExhaustive),
+            ConstructorSet::NoConstructors => {}
+        }
+        ctors.into_iter()
+    }
 }
@@ -123,6 +123,19 @@ fn write_variant_name(
         pat: &crate::pat::DeconstructedPat<Self>,
     ) -> fmt::Result;
 
+    /// Write the name of the variant represented by `ctor` of type `ty`. Used for the `Display`
+    /// impl returned by [`WitnessPat::display`](crate::pat::WitnessPat::display). Only invoked
+    /// when `ctor` is `Struct | Variant(_) | UnionField`.
+    /// The default implementation writes the `Debug` form of `ctor`.
+    fn write_witness_variant_name(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        ctor: &Constructor<Self>,
+        _ty: &Self::Ty,
+    ) -> fmt::Result {
+        write!(f, "{ctor:?}")
+    }
+
     /// Raise a bug.
     fn bug(&self, fmt: fmt::Arguments<'_>) -> Self::Error;
 
@@ -30,13 +30,24 @@ pub struct DeconstructedPat<Cx: TypeCx> {
     /// Extra data to store in a pattern. `None` if the pattern is a wildcard that does not
     /// correspond to a user-supplied pattern.
     data: Option<Cx::PatData>,
+    /// The data of the binding this pattern is the subpattern of, e.g. of `x @ Some(_)` for the
+    /// pattern `Some(_)`. With nested bindings like `x @ y @ Some(_)` this is the innermost one,
+    /// `y`. Only recorded by frontends that need to map patterns back to bindings.
+    binding: Option<Cx::PatData>,
     /// Globally-unique id used to track usefulness at the level of subpatterns.
     pub(crate) uid: PatId,
 }
 
 impl<Cx: TypeCx> DeconstructedPat<Cx> {
     pub fn wildcard(ty: Cx::Ty) -> Self {
-        DeconstructedPat { ctor: Wildcard, fields: Vec::new(), ty, data: None, uid: PatId::new() }
+        DeconstructedPat {
+            ctor: Wildcard,
+            fields: Vec::new(),
+            ty,
+            data: None,
+            binding: None,
+            uid: PatId::new(),
+        }
     }
 
     pub fn new(
@@ -45,7 +56,16 @@ pub fn new(
         ty: Cx::Ty,
         data: Cx::PatData,
     ) -> Self {
-        DeconstructedPat { ctor, fields, ty, data: Some(data), uid: PatId::new() }
+        DeconstructedPat { ctor, fields, ty, data: Some(data), binding: None, uid: PatId::new() }
+    }
+
+    /// Records that this pattern is the subpattern of a binding, like the `Some(_)` in
+    /// `x @ Some(_)`. `binding` is the data of the binding pattern. Frontends lower nested
+    /// bindings from the inside out, so a binding that was already recorded is kept: for
+    /// `x @ y @ Some(_)` the pattern records `y`.
+    pub fn with_binding(mut self, binding: Cx::PatData) -> Self {
+        self.binding.get_or_insert(binding);
+        self
     }
 
     pub(crate) fn is_or_pat(&self) -> bool {
@@ -63,6 +83,11 @@ pub fn ty(&self) -> &Cx::Ty {
     pub fn data(&self) -> Option<&Cx::PatData> {
         self.data.as_ref()
     }
+    /// Returns the data of the innermost binding this pattern is the subpattern of, if it was
+    /// recorded with [`Self::with_binding`].
+    pub fn binding(&self) -> Option<&Cx::PatData> {
+        self.binding.as_ref()
+    }
 
     pub fn iter_fields<'a>(&'a self) -> impl Iterator<Item = &'a DeconstructedPat<Cx>> {
         self.fields.iter()
@@ -105,6 +130,75 @@ pub(crate) fn specialize<'a>(
         }
     }
 
+    /// Iterates over this pattern and all of its subpatterns, top-down and left-to-right, like
+    /// `walk` without the ability to skip subtrees. The fields of an or-pattern are its
+    /// alternatives: each alternative comes with its own subpatterns before the next alternative.
+    pub fn iter_subpatterns<'a>(&'a self) -> impl Iterator<Item = &'a DeconstructedPat<Cx>> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let pat = stack.pop()?;
+            stack.extend(pat.fields.iter().rev());
+            Some(pat)
+        })
+    }
+
+    /// Distributes the constructors of this pattern over the or-patterns nested in it, e.g. turns
+    /// `Some(1 | 2)` into the alternatives `Some(1)` and `Some(2)`. Or-patterns in several fields
+    /// give the cartesian product of their alternatives, so this returns `None` if there would be
+    /// more than `limit` of them. The alternatives are new patterns with ids of their own.
+    pub fn expand_nested_or_pats(&self, limit: usize) -> Option<Vec<Self>> {
+        if self.is_or_pat() {
+            let mut alternatives = Vec::new();
+            for alt in &self.fields {
+                alternatives.extend(alt.expand_nested_or_pats(limit)?);
+                if alternatives.len() > limit {
+                    return None;
+                }
+            }
+            return Some(alternatives);
+        }
+        let mut field_alternatives = Vec::with_capacity(self.fields.len());
+        let mut count = 1usize;
+        for field in &self.fields {
+            let alternatives = field.expand_nested_or_pats(limit)?;
+            count = count.checked_mul(alternatives.len()).filter(|&count| count <= limit)?;
+            field_alternatives.push(alternatives);
+        }
+        // Enumerate the combinations in lexicographic order, the last field varying fastest.
+        let mut combinations = Vec::with_capacity(count);
+        for mut i in 0..count {
+            let mut fields: Vec<_> = field_alternatives
+                .iter()
+                .rev()
+                .map(|alternatives| {
+                    let field = alternatives[i % alternatives.len()].duplicate();
+                    i /= alternatives.len();
+                    field
+                })
+                .collect();
+            fields.reverse();
+            combinations.push(self.with_fields(fields));
+        }
+        Some(combinations)
+    }
+
+    /// A copy of this pattern with the given fields and a new id.
+    fn with_fields(&self, fields: Vec<Self>) -> Self {
+        DeconstructedPat {
+            ctor: self.ctor.clone(),
+            fields,
+            ty: self.ty.clone(),
+            data: self.data.clone(),
+            binding: self.binding.clone(),
+            uid: PatId::new(),
+        }
+    }
+
+    /// A deep copy of this pattern, with new ids.
+    fn duplicate(&self) -> Self {
+        self.with_fields(self.fields.iter().map(Self::duplicate).collect())
+    }
+
     /// Walk top-down and call `it` in each place where a pattern occurs
     /// starting with the root pattern `walk` is called on. If `it` returns
     /// false then we will descend no further but siblings will be processed.
@@ -119,83 +213,93 @@ pub fn walk<'a>(&'a self, it: &mut impl FnMut(&'a Self) -> bool) {
     }
 }
 
-/// This is best effort and not good enough for a `Display` impl.
-impl<Cx: TypeCx> fmt::Debug for DeconstructedPat<Cx> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let pat = self;
-        let mut first = true;
-        let mut start_or_continue = |s| {
-            if first {
-                first = false;
-                ""
-            } else {
-                s
-            }
-        };
-        let mut start_or_comma = || start_or_continue(", ");
-
-        match pat.ctor() {
-            Struct | Variant(_) | UnionField => {
-                Cx::write_variant_name(f, pat)?;
-                // Without `cx`, we can't know which field corresponds to which, so we can't
-                // get the names of the fields. Instead we just display everything as a tuple
-                // struct, which should be good enough.
-                write!(f, "(")?;
-                for p in pat.iter_fields() {
-                    write!(f, "{}", start_or_comma())?;
-                    write!(f, "{p:?}")?;
-                }
-                write!(f, ")")
+/// Writes a pattern made of `ctor` applied to `fields`. Variant names and fields are written by
+/// the caller, as are the constructors that don't correspond to a pattern the user can write, i.e.
+/// opaque constants and the various wildcards. This is shared by the `Debug` impl of
+/// `DeconstructedPat` and the `Display` impl of `WitnessPat`.
+fn write_pat<Cx: TypeCx, P>(
+    f: &mut fmt::Formatter<'_>,
+    ctor: &Constructor<Cx>,
+    fields: &[P],
+    write_variant_name: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+    write_field: impl Fn(&mut fmt::Formatter<'_>, &P) -> fmt::Result,
+    write_other: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    let write_list = |f: &mut fmt::Formatter<'_>, pats: &[P], sep| {
+        for (i, pat) in pats.iter().enumerate() {
+            if i != 0 {
+                write!(f, "{sep}")?;
             }
-            // Note: given the expansion of `&str` patterns done in `expand_pattern`, we should
-            // be careful to detect strings here. However a string literal pattern will never
-            // be reported as a non-exhaustiveness witness, so we can ignore this issue.
-            Ref => {
-                let subpattern = pat.iter_fields().next().unwrap();
-                write!(f, "&{:?}", subpattern)
-            }
-            Slice(slice) => {
-                let mut subpatterns = pat.iter_fields();
-                write!(f, "[")?;
-                match slice.kind {
-                    SliceKind::FixedLen(_) => {
-                        for p in subpatterns {
-                            write!(f, "{}{:?}", start_or_comma(), p)?;
-                        }
+            write_field(f, pat)?;
+        }
+        Ok(())
+    };
+    match ctor {
+        Struct | Variant(_) | UnionField => {
+            write_variant_name(f)?;
+            // Without `cx`, we can't know which field corresponds to which, so we can't
+            // get the names of the fields. Instead we just display everything as a tuple
+            // struct, which should be good enough.
+            write!(f, "(")?;
+            write_list(f, fields, ", ")?;
+            write!(f, ")")
+        }
+        // Note: given the expansion of `&str` patterns done in `expand_pattern`, we should
+        // be careful to detect strings here. However a string literal pattern will never
+        // be reported as a non-exhaustiveness witness, so we can ignore this issue.
+        Ref => {
+            write!(f, "&")?;
+            write_field(f, &fields[0])
+        }
+        Slice(slice) => {
+            write!(f, "[")?;
+            match slice.kind {
+                SliceKind::FixedLen(_) => write_list(f, fields, ", ")?,
+                SliceKind::VarLen(prefix_len, _) => {
+                    let (prefix, suffix) = fields.split_at(prefix_len);
+                    for pat in prefix {
+                        write_field(f, pat)?;
+                        write!(f, ", ")?;
                     }
-                    SliceKind::VarLen(prefix_len, _) => {
-                        for p in subpatterns.by_ref().take(prefix_len) {
-                            write!(f, "{}{:?}", start_or_comma(), p)?;
-                        }
-                        write!(f, "{}", start_or_comma())?;
-                        write!(f, "..")?;
-                        for p in subpatterns {
-                            write!(f, "{}{:?}", start_or_comma(), p)?;
-                        }
+                    write!(f, "..")?;
+                    for pat in suffix {
+                        write!(f, ", ")?;
+                        write_field(f, pat)?;
                     }
                 }
-                write!(f, "]")
-            }
-            Bool(b) => write!(f, "{b}"),
-            // Best-effort, will render signed ranges incorrectly
-            IntRange(range) => write!(f, "{range:?}"),
-            F32Range(lo, hi, end) => write!(f, "{lo}{end}{hi}"),
-            F64Range(lo, hi, end) => write!(f, "{lo}{end}{hi}"),
-            Str(value) => write!(f, "{value:?}"),
-            Opaque(..) => write!(f, "<constant pattern>"),
-            Or => {
-                for pat in pat.iter_fields() {
-                    write!(f, "{}{:?}", start_or_continue(" | "), pat)?;
-                }
-                Ok(())
-            }
-            Wildcard | Missing | NonExhaustive | Hidden | PrivateUninhabited => {
-                write!(f, "_ : {:?}", pat.ty())
             }
+            write!(f, "]")
+        }
+        Bool(b) => write!(f, "{b}"),
+        // Best-effort, will render signed ranges incorrectly
+        IntRange(range) => write!(f, "{range:?}"),
+        F32Range(lo, hi, end) => write!(f, "{lo}{end}{hi}"),
+        F64Range(lo, hi, end) => write!(f, "{lo}{end}{hi}"),
+        Str(value) => write!(f, "{value:?}"),
+        Or => write_list(f, fields, " | "),
+        Opaque(..) | Wildcard | Missing | NonExhaustive | Hidden | PrivateUninhabited => {
+            write_other(f)
         }
     }
 }
 
+/// This is best effort and not good enough for a `Display` impl.
+impl<Cx: TypeCx> fmt::Debug for DeconstructedPat<Cx> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_pat(
+            f,
+            self.ctor(),
+            &self.fields,
+            |f| Cx::write_variant_name(f, self),
+            |f, field| write!(f, "{field:?}"),
+            |f| match self.ctor() {
+                Opaque(..) => write!(f, "<constant pattern>"),
+                _ => write!(f, "_ : {:?}", self.ty()),
+            },
+        )
+    }
+}
+
 /// Represents either a pattern obtained from user input or a wildcard constructed during the
 /// algorithm. Do not use `Wild` to represent a wildcard pattern comping from user input.
 ///
@@ -288,10 +392,10 @@ fn clone(&self) -> Self {
 }
 
 impl<Cx: TypeCx> WitnessPat<Cx> {
-    pub(crate) fn new(ctor: Constructor<Cx>, fields: Vec<Self>, ty: Cx::Ty) -> Self {
+    pub fn new(ctor: Constructor<Cx>, fields: Vec<Self>, ty: Cx::Ty) -> Self {
         Self { ctor, fields, ty }
     }
-    pub(crate) fn wildcard(ty: Cx::Ty) -> Self {
+    pub fn wildcard(ty: Cx::Ty) -> Self {
         Self::new(Wildcard, Vec::new(), ty)
     }
 
@@ -317,4 +421,38 @@ pub fn ty(&self) -> &Cx::Ty {
     pub fn iter_fields(&self) -> impl Iterator<Item = &WitnessPat<Cx>> {
         self.fields.iter()
     }
+
+    /// For each of [`Self::iter_fields`], whether that field is free, i.e. becomes `_` when the
+    /// witness is turned into a pattern, instead of being constrained to some constructor. This
+    /// helps building minimal patterns from the witness, e.g. to generate match arms.
+    pub fn free_fields(&self) -> impl Iterator<Item = bool> + '_ {
+        self.fields.iter().map(|field| {
+            matches!(field.ctor, Wildcard | NonExhaustive | Hidden | PrivateUninhabited)
+        })
+    }
+
+    /// Renders the witness straight from its constructor and fields, without lowering it to a
+    /// type-specific pattern first. Like the `Debug` impl of `DeconstructedPat` this displays
+    /// structs as tuple structs, but it never panics: float ranges are shown as ranges, and opaque
+    /// constants, which the user can't write, as `_`.
+    pub fn display<'a>(&'a self, cx: &'a Cx) -> impl fmt::Display + 'a {
+        struct DisplayWitness<'a, Cx: TypeCx> {
+            pat: &'a WitnessPat<Cx>,
+            cx: &'a Cx,
+        }
+        impl<'a, Cx: TypeCx> fmt::Display for DisplayWitness<'a, Cx> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let DisplayWitness { pat, cx } = *self;
+                write_pat(
+                    f,
+                    pat.ctor(),
+                    &pat.fields,
+                    |f| cx.write_witness_variant_name(f, pat.ctor(), pat.ty()),
+                    |f, field| write!(f, "{}", field.display(cx)),
+                    |f| write!(f, "_"),
+                )
+            }
+        }
+        DisplayWitness { pat: self, cx }
+    }
 }
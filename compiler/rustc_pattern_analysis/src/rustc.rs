@@ -18,6 +18,7 @@
 use crate::constructor::{
     IntRange, MaybeInfiniteInt, OpaqueId, RangeEnd, Slice, SliceKind, VariantVisibility,
 };
+use crate::usefulness::{compute_match_usefulness, ValidityConstraint};
 use crate::{errors, Captures, PrivateUninhabitedField, TypeCx};
 
 use crate::constructor::Constructor::*;
@@ -85,6 +86,18 @@ pub struct RustcMatchCheckCtxt<'p, 'tcx: 'p> {
     /// Whether the data at the scrutinee is known to be valid. This is false if the scrutinee comes
     /// from a union field, a pointer deref, or a reference deref (pending opsem decisions).
     pub known_valid_scrutinee: bool,
+    /// How many witnesses to list when reporting a non-exhaustive match. Witnesses past that are
+    /// only counted, e.g. "`A`, `B`, `C` and 7 more not covered".
+    pub witness_display_limit: usize,
+    /// Whether the integer ranges being matched on are all written with exclusive ends, like
+    /// `0..10`. If so, range witnesses are reported in the same style where possible.
+    pub exclusive_range_witnesses: bool,
+    /// Whether to list the states of a coroutine (unresumed, returned, poisoned and one per
+    /// suspension point) as fieldless variants instead of treating coroutines as unlistable.
+    /// Patterns can't match on coroutines, so this is only useful to tools that build
+    /// `DeconstructedPat`s themselves; match checking leaves it off. Listing the states requires
+    /// the coroutine's optimized MIR.
+    pub coroutine_states_as_variants: bool,
 }
 
 impl<'p, 'tcx: 'p> fmt::Debug for RustcMatchCheckCtxt<'p, 'tcx> {
@@ -134,7 +147,32 @@ pub fn is_uninhabited(&self, ty: Ty<'tcx>) -> bool {
         )
     }
 
+    /// Returns whether `ty` is visibly uninhabited from the current module. This gives the same
+    /// answer as [`Self::is_uninhabited`] but doesn't build the inhabitedness predicate of the
+    /// whole type: for an enum, we check each variant in turn and stop at the first inhabited one.
+    pub fn ty_is_empty(&self, ty: Ty<'tcx>) -> bool {
+        match ty.kind() {
+            ty::Never => true,
+            ty::Adt(def, args) if def.is_enum() => {
+                // A `#[non_exhaustive]` enum from another crate may gain inhabited variants.
+                if def.is_variant_list_non_exhaustive() && !def.did().is_local() {
+                    return false;
+                }
+                def.variants().iter().all(|v| {
+                    !v.inhabited_predicate(self.tcx, *def)
+                        .instantiate(self.tcx, args)
+                        .apply_revealing_opaque(self.tcx, self.param_env, self.module, &|key| {
+                            self.reveal_opaque_key(key)
+                        })
+                })
+            }
+            _ => self.is_uninhabited(ty),
+        }
+    }
+
     /// Returns whether the given type is an enum from another crate declared `#[non_exhaustive]`.
+    /// Only those get a fictitious `NonExhaustive` constructor: within the defining crate the
+    /// attribute has no effect, so a wildcard after all the variants is unreachable.
     pub fn is_foreign_non_exhaustive_enum(&self, ty: RevealedTy<'tcx>) -> bool {
         match ty.kind() {
             ty::Adt(def, ..) => {
@@ -144,6 +182,24 @@ pub fn is_foreign_non_exhaustive_enum(&self, ty: RevealedTy<'tcx>) -> bool {
         }
     }
 
+    /// Returns whether `pat` alone matches every value of `scrut_ty`, i.e. whether it is
+    /// irrefutable, as the pattern of a `let`-`else` must not be. This checks whether a wildcard is
+    /// still useful after `pat`, so `#[non_exhaustive]` types and empty types are handled exactly
+    /// as they are for a `match`.
+    pub fn pattern_is_irrefutable(
+        &self,
+        pat: &'p DeconstructedPat<'p, 'tcx>,
+        scrut_ty: Ty<'tcx>,
+    ) -> bool {
+        let arms = [MatchArm { pat, has_guard: false, arm_data: self.match_lint_level }];
+        let scrut_ty = self.reveal_opaque_ty(scrut_ty);
+        let scrut_validity = ValidityConstraint::from_bool(self.known_valid_scrutinee);
+        match compute_match_usefulness(self, &arms, scrut_ty, scrut_validity, None) {
+            Ok(report) => report.non_exhaustiveness_witnesses.is_empty(),
+            Err(_) => false,
+        }
+    }
+
     /// Whether the range denotes the fictitious values before `isize::MIN` or after
     /// `usize::MAX`/`isize::MAX` (see doc of [`IntRange::split`] for why these exist).
     pub fn is_range_beyond_boundaries(&self, range: &IntRange, ty: RevealedTy<'tcx>) -> bool {
@@ -174,6 +230,29 @@ pub(crate) fn variant_sub_tys(
         })
     }
 
+    /// Write the name of the variant represented by `ctor` of type `ty`, for
+    /// `TypeCx::write_variant_name` and `TypeCx::write_witness_variant_name`.
+    fn write_ctor_variant_name(
+        f: &mut fmt::Formatter<'_>,
+        ctor: &Constructor<'p, 'tcx>,
+        ty: &RevealedTy<'tcx>,
+    ) -> fmt::Result {
+        match ty.kind() {
+            ty::Adt(adt, _) if adt.is_box() => write!(f, "Box")?,
+            ty::Adt(adt, _) => {
+                let variant = adt.variant(Self::variant_index_for_adt(ctor, *adt));
+                write!(f, "{}", variant.name)?;
+            }
+            ty::Coroutine(..) => {
+                if let Variant(idx) = ctor {
+                    write!(f, "{}", ty::CoroutineArgs::variant_name(*idx))?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
     pub(crate) fn variant_index_for_adt(
         ctor: &Constructor<'p, 'tcx>,
         adt: ty::AdtDef<'tcx>,
@@ -237,6 +316,8 @@ fn reveal_and_alloc<'a, 'tcx>(
                         cx.dropless_arena.alloc_from_iter(tys)
                     }
                 }
+                // Coroutine states are listed without their saved locals.
+                ty::Coroutine(..) if cx.coroutine_states_as_variants => &[],
                 _ => bug!("Unexpected type for constructor `{ctor:?}`: {ty:?}"),
             },
             Ref => match ty.kind() {
@@ -274,6 +355,7 @@ pub(crate) fn ctor_arity(&self, ctor: &Constructor<'p, 'tcx>, ty: RevealedTy<'tc
                         adt.variant(variant_idx).fields.len()
                     }
                 }
+                ty::Coroutine(..) if self.coroutine_states_as_variants => 0,
                 _ => bug!("Unexpected type for constructor `{ctor:?}`: {ty:?}"),
             },
             Ref => 1,
@@ -284,6 +366,14 @@ pub(crate) fn ctor_arity(&self, ctor: &Constructor<'p, 'tcx>, ty: RevealedTy<'tc
         }
     }
 
+    /// Lists the constructors of `ty`; see [`crate::constructor::ConstructorSet::iter_ctors`].
+    pub fn iter_ctors(
+        &self,
+        ty: Ty<'tcx>,
+    ) -> Result<impl Iterator<Item = Constructor<'p, 'tcx>>, ErrorGuaranteed> {
+        Ok(self.ctors_for_ty(self.reveal_opaque_ty(ty))?.iter_ctors())
+    }
+
     /// Creates a set that represents all the constructors of `ty`.
     ///
     /// See [`crate::constructor`] for considerations of emptiness.
@@ -344,13 +434,13 @@ pub fn ctors_for_ty(
             }
             ty::Slice(sub_ty) => ConstructorSet::Slice {
                 array_len: None,
-                subtype_is_empty: cx.is_uninhabited(*sub_ty),
+                subtype_is_empty: cx.ty_is_empty(*sub_ty),
             },
             ty::Array(sub_ty, len) => {
                 // We treat arrays of a constant but unknown length like slices.
                 ConstructorSet::Slice {
                     array_len: len.try_eval_target_usize(cx.tcx, cx.param_env).map(|l| l as usize),
-                    subtype_is_empty: cx.is_uninhabited(*sub_ty),
+                    subtype_is_empty: cx.ty_is_empty(*sub_ty),
                 }
             }
             ty::Adt(def, args) if def.is_enum() => {
@@ -397,6 +487,13 @@ pub fn ctors_for_ty(
             }
             ty::Ref(..) => ConstructorSet::Ref,
             ty::Never => ConstructorSet::NoConstructors,
+            ty::Coroutine(def_id, args) if cx.coroutine_states_as_variants => {
+                let states = args.as_coroutine().variant_range(*def_id, cx.tcx);
+                ConstructorSet::Variants {
+                    variants: IndexVec::from_elem_n(VariantVisibility::Visible, states.end.index()),
+                    non_exhaustive: false,
+                }
+            }
             // This type is one for which we cannot list constructors, like `str` or `f64`.
             // FIXME(Nadrieril): which of these are actually allowed?
             ty::Float(_)
@@ -439,6 +536,15 @@ pub(crate) fn lower_pat_range_bdy(
         }
     }
 
+    /// Returns the `HirId` of the innermost binding `pat` is the subpattern of, e.g. the `HirId` of
+    /// `y` for the lowered `Some(_)` of `x @ y @ Some(_)`.
+    pub fn binding_hir_id(pat: &DeconstructedPat<'p, 'tcx>) -> Option<HirId> {
+        match pat.binding()?.kind {
+            PatKind::Binding { var, .. } => Some(var.0),
+            _ => None,
+        }
+    }
+
     /// Note: the input patterns must have been lowered through
     /// `rustc_mir_build::thir::pattern::check_match::MatchVisitor::lower_pattern`.
     pub fn lower_pat(&self, pat: &'p Pat<'tcx>) -> DeconstructedPat<'p, 'tcx> {
@@ -449,7 +555,10 @@ pub fn lower_pat(&self, pat: &'p Pat<'tcx>) -> DeconstructedPat<'p, 'tcx> {
         match &pat.kind {
             PatKind::AscribeUserType { subpattern, .. }
             | PatKind::InlineConstant { subpattern, .. } => return self.lower_pat(subpattern),
-            PatKind::Binding { subpattern: Some(subpat), .. } => return self.lower_pat(subpat),
+            PatKind::Binding { subpattern: Some(subpat), .. } => {
+                // Keep track of the binding so it can be recovered from the lowered pattern.
+                return self.lower_pat(subpat).with_binding(pat);
+            }
             PatKind::Binding { subpattern: None, .. } | PatKind::Wild => {
                 ctor = Wildcard;
                 fields = vec![];
@@ -718,21 +827,44 @@ pub(crate) fn hoist_pat_range(&self, range: &IntRange, ty: RevealedTy<'tcx>) ->
                 let value = mir::Const::from_ty_const(c, cx.tcx);
                 lo = PatRangeBoundary::Finite(value);
             }
-            let hi = if let Some(hi) = range.hi.minus_one() {
+            // `range.hi` is already an exclusive bound; we can only use it as-is if it fits in the
+            // type, i.e. the range doesn't include `ty::MAX`.
+            let exclusive_hi = if cx.exclusive_range_witnesses {
+                Some(cx.hoist_pat_range_bdy(range.hi, ty))
+                    .filter(|hi| matches!(hi, PatRangeBoundary::Finite(_)))
+            } else {
+                None
+            };
+            let hi = if let Some(hi) = exclusive_hi {
+                end = rustc_hir::RangeEnd::Excluded;
                 hi
+            } else if let Some(hi) = range.hi.minus_one() {
+                cx.hoist_pat_range_bdy(hi, ty)
             } else {
                 // The range encodes `..ty::MIN`, so we can't convert it to an inclusive range.
                 end = rustc_hir::RangeEnd::Excluded;
-                range.hi
+                cx.hoist_pat_range_bdy(range.hi, ty)
             };
-            let hi = cx.hoist_pat_range_bdy(hi, ty);
             PatKind::Range(Box::new(PatRange { lo, hi, end, ty: ty.inner() }))
         };
 
         Pat { ty: ty.inner(), span: DUMMY_SP, kind }
     }
+
+    /// Like [`Self::hoist_witness_pat`], but also returns which fields of the witness are free, as
+    /// given by [`crate::pat::WitnessPat::free_fields`]. The mask is indexed by witness field, not
+    /// by the subpatterns of the returned pattern: these can differ, e.g. the wildcards between the
+    /// prefix and suffix of an array slice are dropped from the pattern.
+    pub fn hoist_witness_pat_with_free_fields(
+        &self,
+        pat: &WitnessPat<'p, 'tcx>,
+    ) -> (Pat<'tcx>, Vec<bool>) {
+        (self.hoist_witness_pat(pat), pat.free_fields().collect())
+    }
+
     /// Convert back to a `thir::Pat` for diagnostic purposes. This panics for patterns that don't
-    /// appear in diagnostics, like float ranges.
+    /// appear in diagnostics, like float ranges; `WitnessPat::display` can render those
+    /// instead.
     pub fn hoist_witness_pat(&self, pat: &WitnessPat<'p, 'tcx>) -> Pat<'tcx> {
         let cx = self;
         let is_wildcard = |pat: &Pat<'_>| matches!(pat.kind, PatKind::Wild);
@@ -767,6 +899,8 @@ pub fn hoist_witness_pat(&self, pat: &WitnessPat<'p, 'tcx>) -> Pat<'tcx> {
                         PatKind::Leaf { subpatterns }
                     }
                 }
+                // There is no pattern syntax for coroutine states.
+                ty::Coroutine(..) if cx.coroutine_states_as_variants => PatKind::Wild,
                 _ => bug!("unexpected ctor for type {:?} {:?}", pat.ctor(), *pat.ty()),
             },
             // Note: given the expansion of `&str` patterns done in `expand_pattern`, we should
@@ -860,15 +994,16 @@ fn write_variant_name(
         f: &mut fmt::Formatter<'_>,
         pat: &crate::pat::DeconstructedPat<Self>,
     ) -> fmt::Result {
-        if let ty::Adt(adt, _) = pat.ty().kind() {
-            if adt.is_box() {
-                write!(f, "Box")?
-            } else {
-                let variant = adt.variant(Self::variant_index_for_adt(pat.ctor(), *adt));
-                write!(f, "{}", variant.name)?;
-            }
-        }
-        Ok(())
+        Self::write_ctor_variant_name(f, pat.ctor(), pat.ty())
+    }
+
+    fn write_witness_variant_name(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        ctor: &Constructor<'p, 'tcx>,
+        ty: &Self::Ty,
+    ) -> fmt::Result {
+        Self::write_ctor_variant_name(f, ctor, ty)
     }
 
     fn bug(&self, fmt: fmt::Arguments<'_>) -> Self::Error {
@@ -1055,6 +1055,11 @@ struct MatrixRow<'p, Cx: TypeCx> {
     /// Note: Because of relevancy we may miss some intersections. The intersections we do find are
     /// correct.
     intersects: BitSet<usize>,
+    /// Tracks which unguarded rows above this one match every value that this row matches. `None`
+    /// until the row has been found to match some value.
+    /// Note: This is only an approximation when the row is useful, since then there are values
+    /// that no row above matches.
+    covered_by: Option<BitSet<usize>>,
 }
 
 impl<'p, Cx: TypeCx> MatrixRow<'p, Cx> {
@@ -1083,6 +1088,7 @@ fn expand_or_pat(&self) -> impl Iterator<Item = MatrixRow<'p, Cx>> + Captures<'_
             is_under_guard: self.is_under_guard,
             useful: false,
             intersects: BitSet::new_empty(0), // Initialized in `Matrix::expand_and_push`.
+            covered_by: None,
         })
     }
 
@@ -1102,6 +1108,7 @@ fn pop_head_constructor(
             is_under_guard: self.is_under_guard,
             useful: false,
             intersects: BitSet::new_empty(0), // Initialized in `Matrix::expand_and_push`.
+            covered_by: None,
         })
     }
 }
@@ -1176,6 +1183,7 @@ fn new(
                 is_under_guard: arm.has_guard,
                 useful: false,
                 intersects: BitSet::new_empty(0), // Initialized in `Matrix::expand_and_push`.
+                covered_by: None,
             };
             matrix.expand_and_push(v);
         }
@@ -1248,6 +1256,22 @@ fn unspecialize(&mut self, specialized: Self) {
                     parent_row.intersects.insert(parent_intersection);
                 }
             }
+            if let Some(child_covered_by) = &child_row.covered_by {
+                // The parent row is covered by the rows that cover all of its children.
+                let mut covered_by = BitSet::new_empty(parent_row_id);
+                for child_covering in child_covered_by.iter() {
+                    let parent_covering = specialized.rows[child_covering].parent_row;
+                    if parent_covering != parent_row_id {
+                        covered_by.insert(parent_covering);
+                    }
+                }
+                match &mut parent_row.covered_by {
+                    Some(parent_covered_by) => {
+                        parent_covered_by.intersect(&covered_by);
+                    }
+                    None => parent_row.covered_by = Some(covered_by),
+                }
+            }
         }
     }
 }
@@ -1606,9 +1630,18 @@ fn compute_exhaustiveness_and_usefulness<'a, 'p, Cx: TypeCx>(
         // The base case: there are no columns in the matrix. We are morally pattern-matching on ().
         // A row is useful iff it has no (unguarded) rows above it.
         let mut useful = true; // Whether the next row is useful.
+        let mut unguarded_rows = Vec::new();
         for (i, row) in matrix.rows_mut().enumerate() {
             row.useful = useful;
             row.intersects.insert_range(0..i);
+            let mut covered_by = BitSet::new_empty(i);
+            for &j in &unguarded_rows {
+                covered_by.insert(j);
+            }
+            row.covered_by = Some(covered_by);
+            if !row.is_under_guard {
+                unguarded_rows.push(i);
+            }
             // The next rows stays useful if this one is under a guard.
             useful &= row.is_under_guard;
         }
@@ -1695,28 +1728,28 @@ pub enum Usefulness<'p, Cx: TypeCx> {
     Redundant,
 }
 
+/// Whether `pat` was found useful during the usefulness computation.
+fn pat_is_useful<'p, Cx: TypeCx>(
+    useful_subpatterns: &FxHashSet<PatId>,
+    pat: &'p DeconstructedPat<Cx>,
+) -> bool {
+    if useful_subpatterns.contains(&pat.uid) {
+        true
+    } else if pat.is_or_pat() && pat.iter_fields().any(|f| pat_is_useful(useful_subpatterns, f)) {
+        // We always expand or patterns in the matrix, so we will never see the actual
+        // or-pattern (the one with constructor `Or`) in the column. As such, it will not be
+        // marked as useful itself, only its children will. We recover this information here.
+        true
+    } else {
+        false
+    }
+}
+
 /// Report whether this pattern was found useful, and its subpatterns that were not useful if any.
 fn collect_pattern_usefulness<'p, Cx: TypeCx>(
     useful_subpatterns: &FxHashSet<PatId>,
     pat: &'p DeconstructedPat<Cx>,
 ) -> Usefulness<'p, Cx> {
-    fn pat_is_useful<'p, Cx: TypeCx>(
-        useful_subpatterns: &FxHashSet<PatId>,
-        pat: &'p DeconstructedPat<Cx>,
-    ) -> bool {
-        if useful_subpatterns.contains(&pat.uid) {
-            true
-        } else if pat.is_or_pat() && pat.iter_fields().any(|f| pat_is_useful(useful_subpatterns, f))
-        {
-            // We always expand or patterns in the matrix, so we will never see the actual
-            // or-pattern (the one with constructor `Or`) in the column. As such, it will not be
-            // marked as useful itself, only its children will. We recover this information here.
-            true
-        } else {
-            false
-        }
-    }
-
     let mut redundant_subpats = Vec::new();
     pat.walk(&mut |p| {
         if pat_is_useful(useful_subpatterns, p) {
@@ -1736,6 +1769,48 @@ fn pat_is_useful<'p, Cx: TypeCx>(
     }
 }
 
+/// An or-pattern some of whose alternatives are redundant, i.e. subsumed by earlier alternatives
+/// of the same or-pattern or by earlier arms. For example, in `Some(1 | 1)` the second `1` is
+/// redundant.
+#[derive(Clone, Debug)]
+pub struct RedundantOrAlternatives<'p, Cx: TypeCx> {
+    /// The or-pattern itself.
+    pub or_pat: &'p DeconstructedPat<Cx>,
+    /// The redundant alternatives of `or_pat`, in source order.
+    pub alternatives: Vec<&'p DeconstructedPat<Cx>>,
+}
+
+/// Collect, for each useful or-pattern nested in `pat`, the alternatives that were not useful.
+/// We don't look inside redundant patterns: their subpatterns are trivially redundant too.
+fn collect_redundant_or_alternatives<'p, Cx: TypeCx>(
+    useful_subpatterns: &FxHashSet<PatId>,
+    pat: &'p DeconstructedPat<Cx>,
+    out: &mut Vec<RedundantOrAlternatives<'p, Cx>>,
+) {
+    pat.walk(&mut |p| {
+        if !pat_is_useful(useful_subpatterns, p) {
+            return false;
+        }
+        if p.is_or_pat() {
+            let alternatives: Vec<_> =
+                p.iter_fields().filter(|alt| !pat_is_useful(useful_subpatterns, alt)).collect();
+            if !alternatives.is_empty() {
+                out.push(RedundantOrAlternatives { or_pat: p, alternatives });
+            }
+        }
+        true
+    });
+}
+
+/// Why a redundant arm is never reached.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RedundancyReason {
+    /// The arms above it already match all the values this arm matches. `covering_arm` is the
+    /// index of the earliest unguarded arm that matches them all on its own, if any. An arm that
+    /// only matches values of an uninhabited type has no covering arm.
+    SubsumedBy { covering_arm: Option<usize> },
+}
+
 /// The output of checking a match for exhaustiveness and arm usefulness.
 pub struct UsefulnessReport<'p, Cx: TypeCx> {
     /// For each arm of the input, whether that arm is useful after the arms above it.
@@ -1743,6 +1818,50 @@ pub struct UsefulnessReport<'p, Cx: TypeCx> {
     /// If the match is exhaustive, this is empty. If not, this contains witnesses for the lack of
     /// exhaustiveness.
     pub non_exhaustiveness_witnesses: Vec<WitnessPat<Cx>>,
+    /// The or-patterns of useful arms that have redundant alternatives, in arm order. Nested
+    /// or-patterns get their own entry.
+    pub redundant_or_alternatives: Vec<RedundantOrAlternatives<'p, Cx>>,
+    /// For each redundant arm, its index and why it is redundant, e.g. the `0..=255` arm that
+    /// makes a later `5` unreachable.
+    pub redundancy_reasons: Vec<(usize, RedundancyReason)>,
+}
+
+/// Explains each redundant arm: it is covered by the earliest unguarded arm above it that matched
+/// every value it matches, if any.
+fn explain_redundant_arms<'p, Cx: TypeCx>(
+    arm_usefulness: &[(MatchArm<'p, Cx>, Usefulness<'p, Cx>)],
+    matrix: &Matrix<'p, Cx>,
+) -> Vec<(usize, RedundancyReason)> {
+    let mut reasons = Vec::new();
+    for (arm_id, (_, usefulness)) in arm_usefulness.iter().enumerate() {
+        if !matches!(usefulness, Usefulness::Redundant) {
+            continue;
+        }
+        // The rows of the top-level matrix remember which arm they come from in `parent_row`. An
+        // arm has several rows if it is an or-pattern; it is covered by the arms that cover all of
+        // them.
+        let mut covering_arms: Option<BitSet<usize>> = None;
+        for row in matrix.rows().filter(|row| row.parent_row == arm_id) {
+            let Some(row_covered_by) = &row.covered_by else { continue };
+            let mut arms = BitSet::new_empty(arm_usefulness.len());
+            for covering_row in row_covered_by.iter() {
+                let covering_arm = matrix.rows[covering_row].parent_row;
+                if covering_arm != arm_id {
+                    arms.insert(covering_arm);
+                }
+            }
+            match &mut covering_arms {
+                Some(covering_arms) => {
+                    covering_arms.intersect(&arms);
+                }
+                None => covering_arms = Some(arms),
+            }
+        }
+        // An arm that never matched a value is uninhabited; no arm covers it.
+        let covering_arm = covering_arms.and_then(|arms| arms.iter().next());
+        reasons.push((arm_id, RedundancyReason::SubsumedBy { covering_arm }));
+    }
+    reasons
 }
 
 /// Computes whether a match is exhaustive and which of its arms are useful.
@@ -1774,5 +1893,25 @@ pub fn compute_match_usefulness<'p, Cx: TypeCx>(
         })
         .collect();
 
-    Ok(UsefulnessReport { arm_usefulness, non_exhaustiveness_witnesses })
+    let mut redundant_or_alternatives = Vec::new();
+    for (arm, usefulness) in &arm_usefulness {
+        if let Usefulness::Useful(redundant_subpats) = usefulness {
+            if !redundant_subpats.is_empty() {
+                collect_redundant_or_alternatives(
+                    &cx.useful_subpatterns,
+                    arm.pat,
+                    &mut redundant_or_alternatives,
+                );
+            }
+        }
+    }
+
+    let redundancy_reasons = explain_redundant_arms(&arm_usefulness, &matrix);
+
+    Ok(UsefulnessReport {
+        arm_usefulness,
+        non_exhaustiveness_witnesses,
+        redundant_or_alternatives,
+        redundancy_reasons,
+    })
 }
@@ -0,0 +1,30 @@
+mod common;
+
+use common::{Cx, Ty};
+use rustc_pattern_analysis::constructor::{Constructor, Slice, SliceKind};
+use rustc_pattern_analysis::TypeCx;
+
+#[test]
+fn slice_arity() {
+    let fixed = Constructor::<Cx>::Slice(Slice::new(None, SliceKind::FixedLen(2)));
+    assert_eq!(fixed.structural_arity(), Some(2));
+    let var_len = Constructor::<Cx>::Slice(Slice::new(None, SliceKind::VarLen(1, 2)));
+    assert_eq!(var_len.structural_arity(), Some(3));
+    // `[x, .., y]` against an array of length 4 only needs to look at the first and last element.
+    let array = Constructor::<Cx>::Slice(Slice::new(Some(4), SliceKind::VarLen(1, 1)));
+    assert_eq!(array.structural_arity(), Some(2));
+}
+
+#[test]
+fn bool_arity() {
+    let ctor = Constructor::<Cx>::Bool(true);
+    assert_eq!(ctor.structural_arity(), Some(0));
+    assert_eq!(ctor.structural_arity(), Some(Cx::default().ctor_arity(&ctor, &Ty::Bool)));
+}
+
+#[test]
+fn type_dependent_arity() {
+    assert_eq!(Constructor::<Cx>::Struct.structural_arity(), None);
+    assert_eq!(Constructor::<Cx>::Variant(1).structural_arity(), None);
+    assert_eq!(Constructor::<Cx>::Or.structural_arity(), None);
+}
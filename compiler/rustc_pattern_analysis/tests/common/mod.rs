@@ -0,0 +1,193 @@
+//! A minimal type context to build and analyze patterns without a compiler session.
+// Each test crate only uses some of these helpers.
+#![allow(dead_code)]
+
+use std::fmt;
+
+use rustc_pattern_analysis::constructor::{
+    Constructor, ConstructorSet, IntRange, MaybeInfiniteInt, RangeEnd, Slice, SliceKind,
+    VariantVisibility,
+};
+use rustc_pattern_analysis::pat::DeconstructedPat;
+use rustc_pattern_analysis::{Captures, PrivateUninhabitedField, TypeCx};
+
+/// The types the patterns can have. `Option` is the usual two-variant enum, `None` being
+/// variant 0 and `Some` variant 1. `Slice` is an unsized slice of its element type. `Never` is an
+/// uninhabited type. `F64` can't be enumerated, like all floats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ty {
+    Bool,
+    U8,
+    F64,
+    Tuple(&'static [Ty]),
+    Option(&'static Ty),
+    Slice(&'static Ty),
+    Never,
+}
+
+impl Ty {
+    fn sub_tys(&self, ctor: &Constructor<Cx>) -> Vec<Ty> {
+        match (self, ctor) {
+            (Ty::Tuple(tys), Constructor::Struct) => tys.to_vec(),
+            (Ty::Option(ty), Constructor::Variant(1)) => vec![**ty],
+            (Ty::Slice(ty), Constructor::Slice(_)) => vec![**ty; ctor.structural_arity().unwrap()],
+            _ => vec![],
+        }
+    }
+
+    fn write_variant_name(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        ctor: &Constructor<Cx>,
+    ) -> fmt::Result {
+        match (self, ctor) {
+            (Ty::Option(..), Constructor::Variant(0)) => write!(f, "None"),
+            (Ty::Option(..), Constructor::Variant(_)) => write!(f, "Some"),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The test context. `exhaustive_patterns` stands for the feature of the same name, which lets the
+/// analysis skip constructors of empty types, e.g. non-empty slices of `Never`.
+#[derive(Debug, Default)]
+pub struct Cx {
+    pub exhaustive_patterns: bool,
+}
+
+impl TypeCx for Cx {
+    type Ty = Ty;
+    type Error = ();
+    type VariantIdx = usize;
+    type StrLit = ();
+    type ArmData = ();
+    type PatData = ();
+
+    fn is_exhaustive_patterns_feature_on(&self) -> bool {
+        self.exhaustive_patterns
+    }
+    fn is_min_exhaustive_patterns_feature_on(&self) -> bool {
+        false
+    }
+
+    fn ctor_arity(&self, ctor: &Constructor<Self>, ty: &Self::Ty) -> usize {
+        ty.sub_tys(ctor).len()
+    }
+
+    fn ctor_sub_tys<'a>(
+        &'a self,
+        ctor: &'a Constructor<Self>,
+        ty: &'a Self::Ty,
+    ) -> impl Iterator<Item = (Self::Ty, PrivateUninhabitedField)> + ExactSizeIterator + Captures<'a>
+    {
+        ty.sub_tys(ctor).into_iter().map(|ty| (ty, PrivateUninhabitedField(false)))
+    }
+
+    fn ctors_for_ty(&self, ty: &Self::Ty) -> Result<ConstructorSet<Self>, Self::Error> {
+        Ok(match ty {
+            Ty::Bool => ConstructorSet::Bool,
+            Ty::U8 => ConstructorSet::Integers { range_1: u8_range(0, 255), range_2: None },
+            Ty::F64 => ConstructorSet::Unlistable,
+            Ty::Tuple(..) => ConstructorSet::Struct { empty: false },
+            Ty::Option(..) => ConstructorSet::Variants {
+                variants: visible_variants(2),
+                non_exhaustive: false,
+            },
+            Ty::Slice(ty) => {
+                ConstructorSet::Slice { array_len: None, subtype_is_empty: **ty == Ty::Never }
+            }
+            Ty::Never => ConstructorSet::NoConstructors,
+        })
+    }
+
+    fn write_variant_name(f: &mut fmt::Formatter<'_>, pat: &DeconstructedPat<Self>) -> fmt::Result {
+        pat.ty().write_variant_name(f, pat.ctor())
+    }
+
+    fn write_witness_variant_name(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        ctor: &Constructor<Self>,
+        ty: &Self::Ty,
+    ) -> fmt::Result {
+        ty.write_variant_name(f, ctor)
+    }
+
+    fn bug(&self, fmt: fmt::Arguments<'_>) -> Self::Error {
+        panic!("{fmt}")
+    }
+
+    fn complexity_exceeded(&self) -> Result<(), Self::Error> {
+        Err(())
+    }
+}
+
+#[cfg(feature = "rustc")]
+pub fn visible_variants(
+    n: usize,
+) -> rustc_pattern_analysis::index::IdxContainer<usize, VariantVisibility> {
+    rustc_pattern_analysis::index::IdxContainer::from_elem_n(VariantVisibility::Visible, n)
+}
+
+#[cfg(not(feature = "rustc"))]
+pub fn visible_variants(
+    n: usize,
+) -> rustc_pattern_analysis::index::IdxContainer<usize, VariantVisibility> {
+    rustc_pattern_analysis::index::IdxContainer(
+        (0..n).map(|i| (i, VariantVisibility::Visible)).collect(),
+    )
+}
+
+fn u8_range(lo: u128, hi: u128) -> IntRange {
+    IntRange::from_range(
+        MaybeInfiniteInt::new_finite_uint(lo),
+        MaybeInfiniteInt::new_finite_uint(hi),
+        RangeEnd::Included,
+    )
+}
+
+/// Builders for patterns.
+pub mod pat {
+    use super::*;
+
+    pub fn wild(ty: Ty) -> DeconstructedPat<Cx> {
+        DeconstructedPat::wildcard(ty)
+    }
+
+    pub fn u8(value: u8) -> DeconstructedPat<Cx> {
+        let range = u8_range(value.into(), value.into());
+        DeconstructedPat::new(Constructor::IntRange(range), vec![], Ty::U8, ())
+    }
+
+    /// The inclusive range `lo..=hi`.
+    pub fn range(lo: u8, hi: u8) -> DeconstructedPat<Cx> {
+        let range = u8_range(lo.into(), hi.into());
+        DeconstructedPat::new(Constructor::IntRange(range), vec![], Ty::U8, ())
+    }
+
+    pub fn bool(value: bool) -> DeconstructedPat<Cx> {
+        DeconstructedPat::new(Constructor::Bool(value), vec![], Ty::Bool, ())
+    }
+
+    pub fn none(ty: Ty) -> DeconstructedPat<Cx> {
+        DeconstructedPat::new(Constructor::Variant(0), vec![], ty, ())
+    }
+
+    pub fn some(ty: Ty, field: DeconstructedPat<Cx>) -> DeconstructedPat<Cx> {
+        DeconstructedPat::new(Constructor::Variant(1), vec![field], ty, ())
+    }
+
+    pub fn tuple(ty: Ty, fields: Vec<DeconstructedPat<Cx>>) -> DeconstructedPat<Cx> {
+        DeconstructedPat::new(Constructor::Struct, fields, ty, ())
+    }
+
+    /// The fixed-length slice pattern `[fields..]`.
+    pub fn slice(ty: Ty, fields: Vec<DeconstructedPat<Cx>>) -> DeconstructedPat<Cx> {
+        let slice = Slice::new(None, SliceKind::FixedLen(fields.len()));
+        DeconstructedPat::new(Constructor::Slice(slice), fields, ty, ())
+    }
+
+    pub fn or(ty: Ty, alternatives: Vec<DeconstructedPat<Cx>>) -> DeconstructedPat<Cx> {
+        DeconstructedPat::new(Constructor::Or, alternatives, ty, ())
+    }
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use common::*;
+use rustc_pattern_analysis::constructor::{Constructor, ConstructorSet};
+use rustc_pattern_analysis::TypeCx;
+
+#[test]
+fn bool_ctors() {
+    let set = Cx::default().ctors_for_ty(&Ty::Bool).unwrap();
+    let ctors: Vec<_> = set.iter_ctors().map(|ctor| format!("{ctor:?}")).collect();
+    assert_eq!(ctors, ["Bool(false)", "Bool(true)"]);
+}
+
+#[test]
+fn enum_ctors() {
+    let set: ConstructorSet<Cx> =
+        ConstructorSet::Variants { variants: visible_variants(3), non_exhaustive: false };
+    let mut variants: Vec<_> = set.iter_ctors().map(|ctor| format!("{ctor:?}")).collect();
+    variants.sort();
+    assert_eq!(variants, ["Variant(0)", "Variant(1)", "Variant(2)"]);
+}
+
+#[test]
+fn unlistable_ctors() {
+    let set: ConstructorSet<Cx> = ConstructorSet::Unlistable;
+    let ctors: Vec<_> = set.iter_ctors().map(|ctor| format!("{ctor:?}")).collect();
+    assert_eq!(ctors, ["NonExhaustive"]);
+}
+
+#[test]
+fn split_reports_missing_variants() {
+    const OPTION_BOOL: Ty = Ty::Option(&Ty::Bool);
+    let set = Cx::default().ctors_for_ty(&OPTION_BOOL).unwrap();
+    let present = [Constructor::Variant(0)];
+    let split = set.split(present.iter());
+    let fmt = |ctors: &[Constructor<Cx>]| -> Vec<_> {
+        ctors.iter().map(|ctor| format!("{ctor:?}")).collect()
+    };
+    assert_eq!(fmt(&split.present), ["Variant(0)"]);
+    assert_eq!(fmt(&split.missing), ["Variant(1)"]);
+    assert!(split.missing_empty.is_empty());
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use common::*;
+use rustc_pattern_analysis::constructor::{Constructor, Slice, SliceKind};
+use rustc_pattern_analysis::pat::{DeconstructedPat, WitnessPat};
+use rustc_pattern_analysis::usefulness::{compute_match_usefulness, ValidityConstraint};
+use rustc_pattern_analysis::MatchArm;
+
+fn witnesses(ty: Ty, pats: &[DeconstructedPat<Cx>]) -> Vec<WitnessPat<Cx>> {
+    let arms: Vec<_> =
+        pats.iter().map(|pat| MatchArm { pat, has_guard: false, arm_data: () }).collect();
+    let cx = Cx { exhaustive_patterns: true };
+    let report = compute_match_usefulness(&cx, &arms, ty, ValidityConstraint::ValidOnly, None);
+    report.unwrap().non_exhaustiveness_witnesses
+}
+
+const NEVER_SLICE: Ty = Ty::Slice(&Ty::Never);
+
+#[test]
+fn empty_slice_of_uninhabited_is_exhaustive() {
+    let pats = [pat::slice(NEVER_SLICE, vec![])];
+    assert!(witnesses(NEVER_SLICE, &pats).is_empty());
+}
+
+#[test]
+fn slice_of_uninhabited_is_only_missing_empty_slice() {
+    let pats = [pat::slice(NEVER_SLICE, vec![pat::wild(Ty::Never)])];
+    let witnesses = witnesses(NEVER_SLICE, &pats);
+    assert_eq!(witnesses.len(), 1);
+    let empty = Slice::new(None, SliceKind::FixedLen(0));
+    assert!(matches!(witnesses[0].ctor(), Constructor::Slice(slice) if *slice == empty));
+}
+
+#[test]
+fn slice_of_inhabited_needs_all_lengths() {
+    const BOOL_SLICE: Ty = Ty::Slice(&Ty::Bool);
+    let pats = [pat::slice(BOOL_SLICE, vec![])];
+    let witnesses = witnesses(BOOL_SLICE, &pats);
+    assert_eq!(witnesses.len(), 1);
+    let non_empty = Slice::new(None, SliceKind::VarLen(1, 0));
+    assert!(matches!(witnesses[0].ctor(), Constructor::Slice(slice) if *slice == non_empty));
+}
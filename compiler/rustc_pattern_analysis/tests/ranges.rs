@@ -0,0 +1,38 @@
+mod common;
+
+use common::{pat, Cx, Ty};
+use rustc_pattern_analysis::pat::DeconstructedPat;
+use rustc_pattern_analysis::usefulness::{compute_match_usefulness, Usefulness, ValidityConstraint};
+use rustc_pattern_analysis::MatchArm;
+
+/// Whether each arm is reachable after the ones above it.
+fn arm_reachability(pats: &[DeconstructedPat<Cx>]) -> Vec<bool> {
+    let cx = Cx::default();
+    let arms: Vec<_> =
+        pats.iter().map(|pat| MatchArm { pat, has_guard: false, arm_data: () }).collect();
+    let report =
+        compute_match_usefulness(&cx, &arms, Ty::U8, ValidityConstraint::ValidOnly, None).unwrap();
+    report
+        .arm_usefulness
+        .iter()
+        .map(|(_, usefulness)| matches!(usefulness, Usefulness::Useful(_)))
+        .collect()
+}
+
+#[test]
+fn range_covered_by_union_of_earlier_ranges() {
+    let pats = [pat::range(0, 3), pat::range(4, 10), pat::range(3, 4), pat::wild(Ty::U8)];
+    assert_eq!(arm_reachability(&pats), [true, true, false, true]);
+}
+
+#[test]
+fn range_partially_covered_by_earlier_ranges() {
+    let pats = [pat::range(0, 3), pat::range(5, 10), pat::range(3, 5), pat::wild(Ty::U8)];
+    assert_eq!(arm_reachability(&pats), [true, true, true, true]);
+}
+
+#[test]
+fn values_covered_by_union_of_ranges_and_singletons() {
+    let pats = [pat::u8(0), pat::range(1, 127), pat::range(128, 255), pat::range(0, 10)];
+    assert_eq!(arm_reachability(&pats), [true, true, true, false]);
+}
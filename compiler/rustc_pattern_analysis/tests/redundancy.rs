@@ -0,0 +1,96 @@
+mod common;
+
+use common::*;
+use rustc_pattern_analysis::pat::DeconstructedPat;
+use rustc_pattern_analysis::usefulness::{
+    compute_match_usefulness, RedundancyReason, ValidityConstraint,
+};
+use rustc_pattern_analysis::MatchArm;
+
+/// The reasons given for the redundant arms of a match on `ty`.
+fn redundancy_reasons(ty: Ty, pats: &[DeconstructedPat<Cx>]) -> Vec<(usize, RedundancyReason)> {
+    let cx = Cx::default();
+    let arms: Vec<_> =
+        pats.iter().map(|pat| MatchArm { pat, has_guard: false, arm_data: () }).collect();
+    let report = compute_match_usefulness(&cx, &arms, ty, ValidityConstraint::ValidOnly, None);
+    report.unwrap().redundancy_reasons
+}
+
+/// The or-patterns with redundant alternatives in a match on `ty`, with those alternatives.
+fn redundant_or_alternatives(ty: Ty, pats: &[DeconstructedPat<Cx>]) -> Vec<(String, Vec<String>)> {
+    let cx = Cx::default();
+    let arms: Vec<_> =
+        pats.iter().map(|pat| MatchArm { pat, has_guard: false, arm_data: () }).collect();
+    let report = compute_match_usefulness(&cx, &arms, ty, ValidityConstraint::ValidOnly, None);
+    report
+        .unwrap()
+        .redundant_or_alternatives
+        .into_iter()
+        .map(|or| {
+            let alternatives = or.alternatives.iter().map(|alt| format!("{alt:?}")).collect();
+            (format!("{:?}", or.or_pat), alternatives)
+        })
+        .collect()
+}
+
+#[test]
+fn arm_subsumed_by_single_arm() {
+    let pats = [pat::range(0, 10), pat::u8(5), pat::wild(Ty::U8)];
+    assert_eq!(
+        redundancy_reasons(Ty::U8, &pats),
+        [(1, RedundancyReason::SubsumedBy { covering_arm: Some(0) })]
+    );
+}
+
+#[test]
+fn arm_subsumed_by_later_single_arm() {
+    // `0..=4` and `5..=9` match the values of `3..=7` first, but only `0..=10` matches them all.
+    let pats = [
+        pat::range(0, 4),
+        pat::range(5, 9),
+        pat::range(0, 10),
+        pat::range(3, 7),
+        pat::wild(Ty::U8),
+    ];
+    assert_eq!(
+        redundancy_reasons(Ty::U8, &pats),
+        [(3, RedundancyReason::SubsumedBy { covering_arm: Some(2) })]
+    );
+}
+
+#[test]
+fn arm_subsumed_by_several_arms() {
+    let pats = [pat::bool(true), pat::bool(false), pat::wild(Ty::Bool)];
+    assert_eq!(
+        redundancy_reasons(Ty::Bool, &pats),
+        [(2, RedundancyReason::SubsumedBy { covering_arm: None })]
+    );
+}
+
+#[test]
+fn arm_on_uninhabited_scrutinee() {
+    let pats = [pat::wild(Ty::Never)];
+    assert_eq!(
+        redundancy_reasons(Ty::Never, &pats),
+        [(0, RedundancyReason::SubsumedBy { covering_arm: None })]
+    );
+}
+
+#[test]
+fn or_alternatives_subsumed_by_earlier_alternative_or_arm() {
+    const OPTION_U8: Ty = Ty::Option(&Ty::U8);
+    let pats = [
+        pat::some(OPTION_U8, pat::or(Ty::U8, vec![pat::u8(1), pat::u8(2), pat::u8(1)])),
+        pat::some(OPTION_U8, pat::or(Ty::U8, vec![pat::u8(2), pat::u8(3)])),
+        // Redundant as a whole, so its alternatives aren't reported.
+        pat::some(OPTION_U8, pat::or(Ty::U8, vec![pat::u8(1), pat::u8(3)])),
+        pat::wild(OPTION_U8),
+    ];
+    assert_eq!(
+        redundant_or_alternatives(OPTION_U8, &pats),
+        [
+            ("1 | 2 | 1".to_string(), vec!["1".to_string()]),
+            ("2 | 3".to_string(), vec!["2".to_string()]),
+        ]
+    );
+}
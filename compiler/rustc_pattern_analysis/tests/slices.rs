@@ -0,0 +1,32 @@
+use rustc_pattern_analysis::constructor::{Slice, SliceKind};
+
+#[test]
+fn fixed_and_var_len_overlap() {
+    // `[a]` and `[a, ..]` both match slices of length 1.
+    let fixed = Slice::new(None, SliceKind::FixedLen(1));
+    let var_len = Slice::new(None, SliceKind::VarLen(1, 0));
+    assert_eq!(fixed.overlapping_lengths(var_len), Some(SliceKind::FixedLen(1)));
+    assert_eq!(var_len.overlapping_lengths(fixed), Some(SliceKind::FixedLen(1)));
+    // `[a, .., b]` needs at least two elements.
+    let var_len = Slice::new(None, SliceKind::VarLen(1, 1));
+    assert_eq!(fixed.overlapping_lengths(var_len), None);
+}
+
+#[test]
+fn disjoint_fixed_lengths() {
+    let one = Slice::new(None, SliceKind::FixedLen(1));
+    let two = Slice::new(None, SliceKind::FixedLen(2));
+    assert_eq!(one.overlapping_lengths(two), None);
+    assert_eq!(two.overlapping_lengths(two), Some(SliceKind::FixedLen(2)));
+}
+
+#[test]
+fn var_len_overlap() {
+    // `[a, ..]` and `[.., b, c]` overlap on every slice of length at least 2.
+    let prefix = Slice::new(None, SliceKind::VarLen(1, 0));
+    let suffix = Slice::new(None, SliceKind::VarLen(0, 2));
+    assert_eq!(prefix.overlapping_lengths(suffix), Some(SliceKind::VarLen(2, 0)));
+    // Against an array, only the array's length counts.
+    let array = Slice::new(Some(3), SliceKind::VarLen(1, 0));
+    assert_eq!(array.overlapping_lengths(suffix), Some(SliceKind::FixedLen(3)));
+}
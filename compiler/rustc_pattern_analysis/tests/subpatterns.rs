@@ -0,0 +1,76 @@
+mod common;
+
+use common::{pat, Ty};
+
+const OPTION_U8: Ty = Ty::Option(&Ty::U8);
+
+fn subpatterns(pat: &rustc_pattern_analysis::pat::DeconstructedPat<common::Cx>) -> Vec<String> {
+    pat.iter_subpatterns().map(|p| format!("{p:?}")).collect()
+}
+
+#[test]
+fn or_pattern_alternatives() {
+    let pat = pat::some(OPTION_U8, pat::or(Ty::U8, vec![pat::u8(1), pat::u8(2)]));
+    assert_eq!(subpatterns(&pat), ["Some(1 | 2)", "1 | 2", "1", "2"]);
+}
+
+#[test]
+fn alternatives_come_with_their_subpatterns() {
+    const TY: Ty = Ty::Tuple(&[OPTION_U8, Ty::Bool]);
+    let pat = pat::or(
+        TY,
+        vec![
+            pat::tuple(TY, vec![pat::some(OPTION_U8, pat::u8(0)), pat::bool(true)]),
+            pat::tuple(TY, vec![pat::none(OPTION_U8), pat::wild(Ty::Bool)]),
+        ],
+    );
+    assert_eq!(
+        subpatterns(&pat),
+        [
+            "(Some(0), true) | (None(), _ : Bool)",
+            "(Some(0), true)",
+            "Some(0)",
+            "0",
+            "true",
+            "(None(), _ : Bool)",
+            "None()",
+            "_ : Bool",
+        ]
+    );
+}
+
+#[test]
+fn bound_subpattern_records_its_binding() {
+    // Like `Some(x @ 1)`.
+    let pat = pat::some(OPTION_U8, pat::u8(1).with_binding(()));
+    let bindings: Vec<_> = pat.iter_subpatterns().map(|p| p.binding().is_some()).collect();
+    assert_eq!(bindings, [false, true]);
+}
+
+fn expanded(
+    pat: &rustc_pattern_analysis::pat::DeconstructedPat<common::Cx>,
+    limit: usize,
+) -> Option<Vec<String>> {
+    let alternatives = pat.expand_nested_or_pats(limit)?;
+    Some(alternatives.iter().map(|p| format!("{p:?}")).collect())
+}
+
+#[test]
+fn expand_or_pattern_in_field() {
+    let pat = pat::some(OPTION_U8, pat::or(Ty::U8, vec![pat::u8(1), pat::u8(2)]));
+    assert_eq!(expanded(&pat, 8).unwrap(), ["Some(1)", "Some(2)"]);
+}
+
+#[test]
+fn expand_or_patterns_in_several_fields() {
+    const TY: Ty = Ty::Tuple(&[Ty::Bool, Ty::U8]);
+    let pat = pat::tuple(
+        TY,
+        vec![
+            pat::or(Ty::Bool, vec![pat::bool(true), pat::bool(false)]),
+            pat::or(Ty::U8, vec![pat::u8(1), pat::u8(2)]),
+        ],
+    );
+    assert_eq!(expanded(&pat, 4).unwrap(), ["(true, 1)", "(true, 2)", "(false, 1)", "(false, 2)"]);
+    assert_eq!(expanded(&pat, 3), None);
+}
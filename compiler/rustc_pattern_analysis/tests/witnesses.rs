@@ -0,0 +1,57 @@
+mod common;
+
+use common::*;
+use rustc_apfloat::ieee::Double;
+use rustc_pattern_analysis::constructor::{Constructor, OpaqueId, RangeEnd};
+use rustc_pattern_analysis::pat::WitnessPat;
+use rustc_pattern_analysis::usefulness::{compute_match_usefulness, ValidityConstraint};
+
+#[test]
+fn display_float_range_witness() {
+    let cx = Cx::default();
+    let lo = "1.5".parse::<Double>().unwrap();
+    let hi = "2.5".parse::<Double>().unwrap();
+    let ctor = Constructor::F64Range(lo, hi, RangeEnd::Included);
+    let range = WitnessPat::<Cx>::new(ctor, vec![], Ty::F64);
+    assert_eq!(range.display(&cx).to_string(), "1.5..=2.5");
+
+    let some = WitnessPat::new(Constructor::Variant(1), vec![range], Ty::Option(&Ty::F64));
+    assert_eq!(some.display(&cx).to_string(), "Some(1.5..=2.5)");
+}
+
+#[test]
+fn display_opaque_witness() {
+    let cx = Cx::default();
+    let opaque = WitnessPat::<Cx>::new(Constructor::Opaque(OpaqueId::new()), vec![], Ty::U8);
+    let fields = vec![opaque, WitnessPat::new(Constructor::Bool(true), vec![], Ty::Bool)];
+    let tuple = WitnessPat::new(Constructor::Struct, fields, Ty::Tuple(&[Ty::U8, Ty::Bool]));
+    assert_eq!(tuple.display(&cx).to_string(), "(_, true)");
+}
+
+#[test]
+fn free_and_constrained_witness_fields() {
+    let cx = Cx::default();
+    // The analog of `Foo { a: _, b: Some(_) }`, with a tuple standing for the struct.
+    let a = WitnessPat::<Cx>::wildcard(Ty::Bool);
+    let some_wild = vec![WitnessPat::wildcard(Ty::U8)];
+    let b = WitnessPat::new(Constructor::Variant(1), some_wild, Ty::Option(&Ty::U8));
+    let ty = Ty::Tuple(&[Ty::Bool, Ty::Option(&Ty::U8)]);
+    let foo = WitnessPat::new(Constructor::Struct, vec![a, b], ty);
+    assert_eq!(foo.free_fields().collect::<Vec<_>>(), [true, false]);
+    assert_eq!(foo.display(&cx).to_string(), "(_, Some(_))");
+
+    let some = foo.iter_fields().nth(1).unwrap();
+    assert_eq!(some.free_fields().collect::<Vec<_>>(), [true]);
+}
+
+#[test]
+fn empty_match_on_unit_reports_unit() {
+    let cx = Cx::default();
+    let unit = Ty::Tuple(&[]);
+    let report = compute_match_usefulness(&cx, &[], unit, ValidityConstraint::ValidOnly, None);
+    let witnesses = report.unwrap().non_exhaustiveness_witnesses;
+    assert_eq!(witnesses.len(), 1);
+    assert!(matches!(witnesses[0].ctor(), Constructor::Struct));
+    assert_eq!(witnesses[0].display(&cx).to_string(), "()");
+    assert_eq!(format!("{:?}", pat::tuple(unit, vec![])), "()");
+}
@@ -1614,11 +1614,17 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
         "exclude the pass number when dumping MIR (used in tests) (default: no)"),
     dump_mir_graphviz: bool = (false, parse_bool, [UNTRACKED],
         "in addition to `.mir` files, create graphviz `.dot` files (default: no)"),
+    dump_mir_nll_regions_json: bool = (false, parse_bool, [UNTRACKED],
+        "with `-Zdump-mir=nll`, also write the regions live at each MIR location to a \
+        `.regions.json` file (default: no)"),
     dump_mono_stats: SwitchWithOptPath = (SwitchWithOptPath::Disabled,
         parse_switch_with_opt_path, [UNTRACKED],
         "output statistics about monomorphization collection"),
     dump_mono_stats_format: DumpMonoStatsFormat = (DumpMonoStatsFormat::Markdown, parse_dump_mono_stats, [UNTRACKED],
         "the format to use for -Z dump-mono-stats (`markdown` (default) or `json`)"),
+    dump_region_requirements_json: bool = (false, parse_bool, [UNTRACKED],
+        "with `#[rustc_regions]`, also write the external requirements of closures to a \
+        `.requirements.json` file in the `-Zdump-mir-dir` directory (default: no)"),
     dwarf_version: Option<u32> = (None, parse_opt_number, [TRACKED],
         "version of DWARF debug information to emit (default: 2 or 4, depending on platform)"),
     dylib_lto: bool = (false, parse_bool, [UNTRACKED],
@@ -1758,6 +1764,15 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
         "dump facts from NLL analysis into side files (default: no)"),
     nll_facts_dir: String = ("nll-facts".to_string(), parse_string, [UNTRACKED],
         "the directory the NLL facts are dumped into (default: `nll-facts`)"),
+    nll_facts_filter: Option<String> = (None, parse_opt_string, [UNTRACKED],
+        "only dump NLL facts for functions whose def-path contains this string (default: dump all)"),
+    nll_facts_loan_activations: bool = (false, parse_bool, [UNTRACKED],
+        "also dump a `loan_activated_at` relation recording where each two-phase borrow is \
+        activated (default: no)"),
+    nll_facts_verify: bool = (false, parse_bool, [UNTRACKED],
+        "check that the dumped NLL facts are well-formed, e.g. that every block is reachable \
+        through `cfg_edge` facts, and that every loan is killed or lasts until the function \
+        returns (default: no)"),
     no_analysis: bool = (false, parse_no_flag, [UNTRACKED],
         "parse and expand the source, but run no analysis"),
     no_codegen: bool = (false, parse_no_flag, [TRACKED_NO_CRATE_HASH],
@@ -1794,6 +1809,9 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
         "panic strategy for panics in drops"),
     parse_only: bool = (false, parse_bool, [UNTRACKED],
         "parse only; do not compile, assemble, or link (default: no)"),
+    pattern_witness_display_limit: usize = (3, parse_number, [UNTRACKED],
+        "how many missing patterns a non-exhaustive match error lists before it only counts the \
+        rest; at least one is always listed, so 0 acts like 1 (default: 3)"),
     plt: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "whether to use the PLT when calling into shared libraries;
         only has effect for PIC code on systems with ELF binaries
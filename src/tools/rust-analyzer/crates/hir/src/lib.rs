@@ -1800,6 +1800,22 @@ pub fn diagnostics(self, db: &dyn HirDatabase, acc: &mut Vec<AnyDiagnostic>) {
             acc.push(diag.into())
         }
     }
+
+    /// The `let` statements without an `else` in this body whose pattern doesn't match every value
+    /// of the initializer, as found by the exhaustiveness checker.
+    pub fn non_exhaustive_lets(self, db: &dyn HirDatabase) -> Vec<NonExhaustiveLet> {
+        let (_, source_map) = db.body_with_source_map(self.into());
+        BodyValidationDiagnostic::collect(db, self.into())
+            .into_iter()
+            .filter(|it| matches!(it, BodyValidationDiagnostic::NonExhaustiveLet { .. }))
+            .filter_map(|it| {
+                match AnyDiagnostic::body_validation_diagnostic(db, it, &source_map)? {
+                    AnyDiagnostic::NonExhaustiveLet(it) => Some(*it),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
 }
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Function {
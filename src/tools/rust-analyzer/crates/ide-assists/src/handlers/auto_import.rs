@@ -1324,6 +1324,35 @@ fn main() {
         );
     }
 
+    #[test]
+    fn dep_import_merges_with_existing_use() {
+        check_assist(
+            auto_import,
+            r"
+//- /lib.rs crate:dep
+pub struct Struct;
+pub mod fmt {
+    pub struct Formatter;
+}
+
+//- /main.rs crate:main deps:dep
+use dep::Struct;
+
+fn main() {
+    let _ = Struct;
+    Formatter$0
+}
+",
+            r"use dep::{fmt::Formatter, Struct};
+
+fn main() {
+    let _ = Struct;
+    Formatter
+}
+",
+        );
+    }
+
     #[test]
     fn whole_segment() {
         // Tests that only imports whose last segment matches the identifier get suggested.
@@ -272,6 +272,10 @@ fn replace_usages(
                 } else if let Some((ty_annotation, initializer)) = find_assoc_const_usage(&name) {
                     edit.replace(ty_annotation.syntax().text_range(), "Bool");
                     replace_bool_expr(edit, initializer);
+                } else if let Some(literal) = find_literal_comparison_usage(&name) {
+                    cov_mark::hit!(replaces_literal_comparison);
+
+                    replace_bool_expr(edit, literal);
                 } else if let Some(receiver) = find_method_call_expr_usage(&name) {
                     edit.replace(
                         receiver.syntax().text_range(),
@@ -427,6 +431,29 @@ fn find_assoc_const_usage(name: &ast::NameLike) -> Option<(ast::Type, ast::Expr)
     Some((const_.ty()?, const_.body()?))
 }
 
+/// Finds a direct comparison of the usage against a `bool` literal, e.g. `flag == true`, returning
+/// the literal so that it can be replaced by the corresponding variant.
+fn find_literal_comparison_usage(name: &ast::NameLike) -> Option<ast::Expr> {
+    let usage = name.syntax().ancestors().find_map(ast::Expr::cast)?;
+    let bin_expr = usage.syntax().parent().and_then(ast::BinExpr::cast)?;
+
+    if !matches!(bin_expr.op_kind()?, ast::BinaryOp::CmpOp(ast::CmpOp::Eq { .. })) {
+        return None;
+    }
+
+    let other = if bin_expr.lhs()?.syntax() == usage.syntax() {
+        bin_expr.rhs()?
+    } else {
+        bin_expr.lhs()?
+    };
+    match &other {
+        ast::Expr::Literal(literal) if matches!(literal.kind(), ast::LiteralKind::Bool(_)) => {
+            Some(other)
+        }
+        _ => None,
+    }
+}
+
 fn find_method_call_expr_usage(name: &ast::NameLike) -> Option<ast::Expr> {
     let method_call = name.syntax().ancestors().find_map(ast::MethodCallExpr::cast)?;
     let receiver = method_call.receiver()?;
@@ -1271,6 +1298,76 @@ fn main() {
         )
     }
 
+    #[test]
+    fn field_assigned_later() {
+        cov_mark::check!(replaces_assignment);
+        check_assist(
+            bool_to_enum,
+            r#"
+struct Foo {
+    $0active: bool,
+}
+
+fn main() {
+    let mut foo = Foo { active: false };
+
+    foo.active = true;
+}
+"#,
+            r#"
+#[derive(PartialEq, Eq)]
+enum Bool { True, False }
+
+struct Foo {
+    active: Bool,
+}
+
+fn main() {
+    let mut foo = Foo { active: Bool::False };
+
+    foo.active = Bool::True;
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn field_compared_to_literal() {
+        cov_mark::check_count!(replaces_literal_comparison, 2);
+        check_assist(
+            bool_to_enum,
+            r#"
+struct Foo {
+    $0active: bool,
+}
+
+fn main() {
+    let foo = Foo { active: true };
+
+    if foo.active == false || true != foo.active {
+        println!("foo");
+    }
+}
+"#,
+            r#"
+#[derive(PartialEq, Eq)]
+enum Bool { True, False }
+
+struct Foo {
+    active: Bool,
+}
+
+fn main() {
+    let foo = Foo { active: Bool::True };
+
+    if foo.active == Bool::False || Bool::True != foo.active {
+        println!("foo");
+    }
+}
+"#,
+        )
+    }
+
     #[test]
     fn field_assigned_to_another() {
         cov_mark::check!(dont_assign_incorrect_ref);
@@ -0,0 +1,191 @@
+use itertools::Itertools;
+use syntax::{
+    ast::{self, AstNode},
+    T,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: collapse_nested_or_patterns
+//
+// Factors the constructor shared by all the alternatives of an or-pattern out of them.
+//
+// ```
+// # //- minicore: option
+// fn classify(x: Option<u8>) -> bool {
+//     match x {
+//         $0Some(1) | Some(2) | Some(3) => true,
+//         _ => false,
+//     }
+// }
+// ```
+// ->
+// ```
+// fn classify(x: Option<u8>) -> bool {
+//     match x {
+//         Some(1 | 2 | 3) => true,
+//         _ => false,
+//     }
+// }
+// ```
+pub(crate) fn collapse_nested_or_patterns(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let or_pat = ctx.find_node_at_offset::<ast::OrPat>()?;
+    let alternatives: Vec<_> = or_pat.pats().map(|pat| constructor_fields(&pat)).collect();
+    let (head, fields) = alternatives.first()?.clone()?;
+    let mut field_alternatives = vec![Vec::new(); fields.len()];
+    for alternative in &alternatives {
+        let Some((alt_head, alt_fields)) = alternative else {
+            cov_mark::hit!(collapse_nested_or_patterns_different_constructors);
+            return None;
+        };
+        if *alt_head != head || alt_fields.len() != fields.len() {
+            cov_mark::hit!(collapse_nested_or_patterns_different_constructors);
+            return None;
+        }
+        for (alts, field) in field_alternatives.iter_mut().zip(alt_fields) {
+            alts.push(field.syntax().text().to_string());
+        }
+    }
+
+    // The alternatives may only differ in one field: `A(1, 2) | A(3, 4)` isn't `A(1 | 3, 2 | 4)`.
+    let mut differing =
+        field_alternatives.iter().positions(|alts| alts.iter().any(|alt| *alt != alts[0]));
+    let index = differing.next()?;
+    if differing.next().is_some() || field_alternatives[index].iter().any(|alt| alt == "..") {
+        return None;
+    }
+
+    let target = or_pat.syntax().text_range();
+    acc.add(
+        AssistId("collapse_nested_or_patterns", AssistKind::RefactorRewrite),
+        "Collapse into a nested or-pattern",
+        target,
+        |edit| {
+            let fields = field_alternatives
+                .iter()
+                .enumerate()
+                .map(|(i, alts)| if i == index { alts.join(" | ") } else { alts[0].clone() })
+                .join(", ");
+            let trailing_comma =
+                if head.is_empty() && field_alternatives.len() == 1 { "," } else { "" };
+            edit.replace(target, format!("{head}({fields}{trailing_comma})"));
+        },
+    )
+}
+
+/// Splits a tuple or tuple struct pattern into the text of its path, empty for tuples, and its
+/// fields.
+fn constructor_fields(pat: &ast::Pat) -> Option<(String, Vec<ast::Pat>)> {
+    match pat {
+        ast::Pat::TupleStructPat(it) => Some((it.path()?.to_string(), it.fields().collect())),
+        ast::Pat::TuplePat(it) => {
+            // `(a,)` is a one-element tuple, while `(a)` is just parenthesized.
+            let trailing_comma = it.syntax().children_with_tokens().any(|it| it.kind() == T![,]);
+            let fields: Vec<_> = it.fields().collect();
+            (fields.len() > 1 || trailing_comma).then(|| (String::new(), fields))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn collapse_some() {
+        check_assist(
+            collapse_nested_or_patterns,
+            r#"
+//- minicore: option
+fn f(x: Option<u8>) {
+    match x {
+        Some(1) | Some(2 | 4)$0 | Some(3) => {}
+        _ => {}
+    }
+}
+"#,
+            r#"
+fn f(x: Option<u8>) {
+    match x {
+        Some(1 | 2 | 4 | 3) => {}
+        _ => {}
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn collapse_tuple() {
+        check_assist(
+            collapse_nested_or_patterns,
+            r#"
+fn f(x: (u8, bool)) {
+    if let $0(0, flag) | (1, flag) = x {}
+}
+"#,
+            r#"
+fn f(x: (u8, bool)) {
+    if let (0 | 1, flag) = x {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn collapse_tuple_struct_with_several_fields() {
+        check_assist(
+            collapse_nested_or_patterns,
+            r#"
+struct Point(u8, u8);
+fn f(p: Point) {
+    let Point(x, 0) | Point(x, 1)$0 = p;
+}
+"#,
+            r#"
+struct Point(u8, u8);
+fn f(p: Point) {
+    let Point(x, 0 | 1) = p;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_different_constructors() {
+        cov_mark::check!(collapse_nested_or_patterns_different_constructors);
+        check_assist_not_applicable(
+            collapse_nested_or_patterns,
+            r#"
+//- minicore: option, result
+fn f(x: Result<u8, u8>) {
+    match x {
+        Ok(1) | Err(1)$0 => {}
+        _ => {}
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_several_fields_differ() {
+        check_assist_not_applicable(
+            collapse_nested_or_patterns,
+            r#"
+fn f(x: (u8, u8)) {
+    match x {
+        (1, 2) | (3, 4)$0 => {}
+        _ => {}
+    }
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use syntax::{
+    ast::{self, AstNode},
+    TextRange,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_array_to_vec
+//
+// Converts an array expression into a `vec!` macro call.
+//
+// ```
+// fn main() {
+//     let v = [1, 2$0, 3];
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let v = vec![1, 2, 3];
+// }
+// ```
+pub(crate) fn convert_array_to_vec(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let array = ctx.find_node_at_offset::<ast::ArrayExpr>()?;
+
+    acc.add(
+        AssistId("convert_array_to_vec", AssistKind::RefactorRewrite),
+        "Convert array to `Vec`",
+        array.syntax().text_range(),
+        |builder| builder.insert(array.syntax().text_range().start(), "vec!"),
+    )
+}
+
+// Assist: convert_vec_to_array
+//
+// Converts a `vec!` macro call into an array expression.
+//
+// ```
+// fn main() {
+//     let v = vec!$0[0; 4];
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let v = [0; 4];
+// }
+// ```
+pub(crate) fn convert_vec_to_array(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let macro_call = ctx.find_node_at_offset::<ast::MacroCall>()?;
+    if !ast::MacroExpr::can_cast(macro_call.syntax().parent()?.kind())
+        || macro_call.path()?.segment()?.name_ref()?.text() != "vec"
+        || macro_call.excl_token().is_none()
+    {
+        return None;
+    }
+
+    let tt = macro_call.token_tree()?;
+    let contents = TextRange::new(
+        tt.left_delimiter_token()?.text_range().end(),
+        tt.right_delimiter_token()?.text_range().start(),
+    ) - tt.syntax().text_range().start();
+    let tt_text = tt.syntax().text().to_string();
+    let contents = &tt_text[contents];
+
+    // Both the comma list and the `x; n` repeat form are valid array expressions.
+    let array = format!("[{contents}]");
+    let parse = ast::SourceFile::parse(&format!("const _: () = {array};"));
+    if !parse.errors().is_empty() {
+        cov_mark::hit!(convert_vec_to_array_not_array_like);
+        return None;
+    }
+
+    let target = macro_call.syntax().text_range();
+    acc.add(
+        AssistId("convert_vec_to_array", AssistKind::RefactorRewrite),
+        "Convert `vec!` to array",
+        target,
+        |builder| builder.replace(target, array),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn array_to_vec() {
+        check_assist(
+            convert_array_to_vec,
+            r#"
+fn main() {
+    let v = [$01, 2, 3];
+}
+"#,
+            r#"
+fn main() {
+    let v = vec![1, 2, 3];
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn repeat_array_to_vec() {
+        check_assist(
+            convert_array_to_vec,
+            r#"
+fn main() {
+    let v = $0[0; 4];
+}
+"#,
+            r#"
+fn main() {
+    let v = vec![0; 4];
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn vec_to_array() {
+        check_assist(
+            convert_vec_to_array,
+            r#"
+fn main() {
+    let v = vec$0![1, 2, 3];
+}
+"#,
+            r#"
+fn main() {
+    let v = [1, 2, 3];
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn repeat_vec_to_array() {
+        check_assist(
+            convert_vec_to_array,
+            r#"
+fn main() {
+    let v = vec$0![0; 4];
+}
+"#,
+            r#"
+fn main() {
+    let v = [0; 4];
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn vec_with_parentheses_to_array() {
+        check_assist(
+            convert_vec_to_array,
+            r#"
+fn main() {
+    let v = $0vec!("a", "b");
+}
+"#,
+            r#"
+fn main() {
+    let v = ["a", "b"];
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_macros() {
+        check_assist_not_applicable(
+            convert_vec_to_array,
+            r#"
+fn main() {
+    let v = format$0!("{}", 1);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_vec_of_non_expressions() {
+        cov_mark::check!(convert_vec_to_array_not_array_like);
+        check_assist_not_applicable(
+            convert_vec_to_array,
+            r#"
+fn main() {
+    let v = vec$0![1, 2; 3];
+}
+"#,
+        );
+    }
+}
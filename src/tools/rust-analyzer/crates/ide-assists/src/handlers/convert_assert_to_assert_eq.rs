@@ -0,0 +1,195 @@
+use hir::DescendPreference;
+use ide_db::famous_defs::FamousDefs;
+use itertools::Itertools;
+use syntax::{
+    ast::{self, AstNode},
+    NodeOrToken, TextRange, T,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_assert_to_assert_eq
+//
+// Converts an `assert!` of an equality or inequality into `assert_eq!` or `assert_ne!`, which
+// print both sides when they fail.
+//
+// ```
+// # //- minicore: assert, fmt
+// fn check(answer: u32) {
+//     $0assert!(answer == 42, "wrong answer");
+// }
+// ```
+// ->
+// ```
+// fn check(answer: u32) {
+//     assert_eq!(answer, 42, "wrong answer");
+// }
+// ```
+pub(crate) fn convert_assert_to_assert_eq(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let macro_call = ctx.find_node_at_offset::<ast::MacroCall>()?;
+    let macro_name = macro_call.path()?.segment()?.name_ref()?;
+    if macro_name.text() != "assert" || macro_call.excl_token().is_none() {
+        return None;
+    }
+    let tt = macro_call.token_tree()?;
+    let l_delim = tt.left_delimiter_token()?;
+    let r_delim = tt.right_delimiter_token()?;
+    let input: Vec<_> = tt
+        .syntax()
+        .children_with_tokens()
+        .skip(1)
+        .take_while(|it| it.as_token() != Some(&r_delim))
+        .collect();
+    let (cond, message) = match input.iter().position(|it| it.kind() == T![,]) {
+        Some(comma) => (&input[..comma], input[comma..].iter().join("")),
+        None => (&input[..], String::new()),
+    };
+
+    let cond_token = cond.iter().find_map(|it| match it {
+        NodeOrToken::Token(token) if !token.kind().is_trivia() => Some(token.clone()),
+        NodeOrToken::Token(_) => None,
+        NodeOrToken::Node(node) => node.first_token(),
+    })?;
+    let cond = syntax::hacks::parse_expr_from_str(&cond.iter().join(""))?;
+    let ast::Expr::BinExpr(cond) = cond else { return None };
+    let (name, label) = match cond.op_kind()? {
+        ast::BinaryOp::CmpOp(ast::CmpOp::Eq { negated: false }) => {
+            ("assert_eq", "Convert to `assert_eq!`")
+        }
+        ast::BinaryOp::CmpOp(ast::CmpOp::Eq { negated: true }) => {
+            ("assert_ne", "Convert to `assert_ne!`")
+        }
+        _ => {
+            cov_mark::hit!(convert_assert_to_assert_eq_not_comparison);
+            return None;
+        }
+    };
+    let (lhs, rhs) = (cond.lhs()?, cond.rhs()?);
+    if !operands_are_debug(ctx, &macro_call, cond_token)? {
+        cov_mark::hit!(convert_assert_to_assert_eq_not_debug);
+        return None;
+    }
+
+    acc.add(
+        AssistId("convert_assert_to_assert_eq", AssistKind::RefactorRewrite),
+        label,
+        macro_call.syntax().text_range(),
+        |edit| {
+            edit.replace(macro_name.syntax().text_range(), name);
+            let args = TextRange::new(l_delim.text_range().end(), r_delim.text_range().start());
+            edit.replace(args, format!("{lhs}, {rhs}{message}"));
+        },
+    )
+}
+
+/// Whether both sides of the comparison in `assert!` implement `Debug`, as `assert_eq!` requires.
+/// The types are looked up on the comparison in the expansion, found from `cond_token`, the first
+/// token of the condition.
+fn operands_are_debug(
+    ctx: &AssistContext<'_>,
+    macro_call: &ast::MacroCall,
+    cond_token: syntax::SyntaxToken,
+) -> Option<bool> {
+    let krate = ctx.sema.scope(macro_call.syntax())?.krate();
+    let debug = FamousDefs(&ctx.sema, krate).core_fmt_Debug()?;
+    let token = ctx.sema.descend_into_macros_single(DescendPreference::SameText, cond_token);
+    // The condition is the outermost comparison of the expansion, `if !(cond) { .. }`.
+    let cond = token
+        .parent_ancestors()
+        .filter_map(ast::BinExpr::cast)
+        .filter(|it| matches!(it.op_kind(), Some(ast::BinaryOp::CmpOp(ast::CmpOp::Eq { .. }))))
+        .last()?;
+    let is_debug = |expr: Option<ast::Expr>| -> Option<bool> {
+        let ty = ctx.sema.type_of_expr(&expr?)?.original;
+        Some(ty.impls_trait(ctx.db(), debug, &[]))
+    };
+    Some(is_debug(cond.lhs())? && is_debug(cond.rhs())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn convert_eq() {
+        check_assist(
+            convert_assert_to_assert_eq,
+            r#"
+//- minicore: assert, fmt
+struct V;
+impl V {
+    fn len(&self) -> usize { 0 }
+}
+
+fn f(a: V, b: V) {
+    assert!(a.len()$0 == b.len() + 1);
+}
+"#,
+            r#"
+struct V;
+impl V {
+    fn len(&self) -> usize { 0 }
+}
+
+fn f(a: V, b: V) {
+    assert_eq!(a.len(), b.len() + 1);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_ne_with_message() {
+        check_assist(
+            convert_assert_to_assert_eq,
+            r#"
+//- minicore: assert, fmt
+fn f(x: char, y: char) {
+    $0core::assert!(x != y, "{} and {} should differ", x, y);
+}
+"#,
+            r#"
+fn f(x: char, y: char) {
+    core::assert_ne!(x, y, "{} and {} should differ", x, y);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_conditions() {
+        cov_mark::check!(convert_assert_to_assert_eq_not_comparison);
+        check_assist_not_applicable(
+            convert_assert_to_assert_eq,
+            r#"
+fn main() {
+    $0assert!(a < b);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_operands_without_debug() {
+        cov_mark::check!(convert_assert_to_assert_eq_not_debug);
+        check_assist_not_applicable(
+            convert_assert_to_assert_eq,
+            r#"
+//- minicore: assert, fmt, eq
+struct Meters(u32);
+impl PartialEq for Meters {
+    fn eq(&self, other: &Meters) -> bool { self.0 == other.0 }
+}
+
+fn f(a: Meters, b: Meters) {
+    $0assert!(a == b);
+}
+"#,
+        );
+    }
+}
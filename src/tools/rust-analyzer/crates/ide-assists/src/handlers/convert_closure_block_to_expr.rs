@@ -0,0 +1,103 @@
+use syntax::{
+    ast::{self, AstNode},
+    SyntaxKind,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_closure_block_to_expr
+//
+// Replaces the block body of a closure with the only expression in it.
+//
+// ```
+// fn main() {
+//     let inc = |x$0| { x + 1 };
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let inc = |x| x + 1;
+// }
+// ```
+pub(crate) fn convert_closure_block_to_expr(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let closure = ctx.find_node_at_offset::<ast::ClosureExpr>()?;
+    let ast::Expr::BlockExpr(block) = closure.body()? else { return None };
+    // `|x| -> T { .. }` needs its block, as do `unsafe`, `async` and labeled blocks.
+    if closure.ret_type().is_some() || block.modifier().is_some() {
+        return None;
+    }
+    let stmt_list = block.stmt_list()?;
+    let has_comments =
+        stmt_list.syntax().children_with_tokens().any(|it| it.kind() == SyntaxKind::COMMENT);
+    if stmt_list.statements().next().is_some() || has_comments {
+        cov_mark::hit!(convert_closure_block_to_expr_not_only_tail);
+        return None;
+    }
+    let tail = stmt_list.tail_expr()?;
+
+    let target = block.syntax().text_range();
+    acc.add(
+        AssistId("convert_closure_block_to_expr", AssistKind::RefactorRewrite),
+        "Unwrap closure body block",
+        target,
+        |edit| edit.replace(target, tail.syntax().text().to_string()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn unwrap_block_body() {
+        check_assist(
+            convert_closure_block_to_expr,
+            r#"
+fn main() {
+    let f = move |a, b| {
+        $0a.max(b)
+    };
+}
+"#,
+            r#"
+fn main() {
+    let f = move |a, b| a.max(b);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_statements() {
+        cov_mark::check!(convert_closure_block_to_expr_not_only_tail);
+        check_assist_not_applicable(
+            convert_closure_block_to_expr,
+            r#"
+fn main() {
+    let f = |x$0| {
+        let y = x + 1;
+        y * 2
+    };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_return_type() {
+        check_assist_not_applicable(
+            convert_closure_block_to_expr,
+            r#"
+fn main() {
+    let f = |x: u32$0| -> u32 { x + 1 };
+}
+"#,
+        );
+    }
+}
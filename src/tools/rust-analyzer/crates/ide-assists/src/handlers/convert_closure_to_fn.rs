@@ -0,0 +1,192 @@
+use ide_db::assists::{AssistId, AssistKind};
+use itertools::Itertools;
+use syntax::{ast, AstNode};
+
+use crate::assist_context::{AssistContext, Assists};
+
+// Assist: convert_closure_to_fn
+//
+// Converts a closure bound to a local variable into a nested function, if the closure doesn't
+// capture anything.
+//
+// ```
+// fn main() {
+//     let $0add_one = |x: i32| -> i32 { x + 1 };
+//
+//     add_one(2);
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     fn add_one(x: i32) -> i32 { x + 1 }
+//
+//     add_one(2);
+// }
+// ```
+pub(crate) fn convert_closure_to_fn(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let name = ctx.find_node_at_offset::<ast::Name>()?;
+    let ident_pat = name.syntax().parent().and_then(ast::IdentPat::cast)?;
+    let let_stmt = ident_pat.syntax().parent().and_then(ast::LetStmt::cast)?;
+    if ident_pat.pat().is_some() || let_stmt.let_else().is_some() {
+        return None;
+    }
+    let ast::Expr::ClosureExpr(closure) = let_stmt.initializer()? else { return None };
+    if closure.async_token().is_some() || closure.generic_param_list().is_some() {
+        return None;
+    }
+
+    let params = closure.param_list()?.params().collect::<Vec<_>>();
+    if params.iter().any(|param| param.ty().is_none()) {
+        cov_mark::hit!(convert_closure_to_fn_untyped_param);
+        return None;
+    }
+
+    let closure_ty = ctx.sema.type_of_expr(&closure.clone().into())?.original;
+    if !closure_ty.as_closure()?.captured_items(ctx.db()).is_empty() {
+        cov_mark::hit!(convert_closure_to_fn_captures);
+        return None;
+    }
+
+    let body = closure.body()?;
+    let ret_type = match closure.ret_type() {
+        Some(ret_type) => format!(" {ret_type}"),
+        None => {
+            let ty = ctx.sema.type_of_expr(&body)?.original;
+            if ty.is_unit() {
+                String::new()
+            } else {
+                let module = ctx.sema.scope(closure.syntax())?.module();
+                format!(" -> {}", ty.display_source_code(ctx.db(), module.into(), true).ok()?)
+            }
+        }
+    };
+
+    let target = let_stmt.syntax().text_range();
+    acc.add(
+        AssistId("convert_closure_to_fn", AssistKind::RefactorRewrite),
+        "Convert closure to function",
+        target,
+        |edit| {
+            let params = params.iter().join(", ");
+            let body = match &body {
+                ast::Expr::BlockExpr(block) if block.modifier().is_none() => body.to_string(),
+                _ => format!("{{ {body} }}"),
+            };
+            edit.replace(target, format!("fn {name}({params}){ret_type} {body}"));
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::convert_closure_to_fn;
+
+    #[test]
+    fn convert_typed_closure() {
+        check_assist(
+            convert_closure_to_fn,
+            r#"
+fn main() {
+    let $0add_one = |x: i32| -> i32 { x + 1 };
+
+    add_one(2);
+}
+"#,
+            r#"
+fn main() {
+    fn add_one(x: i32) -> i32 { x + 1 }
+
+    add_one(2);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_closure_with_expression_body() {
+        check_assist(
+            convert_closure_to_fn,
+            r#"
+fn main() {
+    let $0double = |x: u32, y: u32| x * y;
+
+    let _ = double(2, 3);
+}
+"#,
+            r#"
+fn main() {
+    fn double(x: u32, y: u32) -> u32 { x * y }
+
+    let _ = double(2, 3);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_closure_returning_unit() {
+        check_assist(
+            convert_closure_to_fn,
+            r#"
+fn main() {
+    let $0nothing = |_x: i32| {};
+
+    nothing(1);
+}
+"#,
+            r#"
+fn main() {
+    fn nothing(_x: i32) {}
+
+    nothing(1);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_capturing() {
+        cov_mark::check!(convert_closure_to_fn_captures);
+        check_assist_not_applicable(
+            convert_closure_to_fn,
+            r#"
+fn main() {
+    let y = 1;
+    let $0add_y = |x: i32| -> i32 { x + y };
+
+    add_y(2);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_untyped_param() {
+        cov_mark::check!(convert_closure_to_fn_untyped_param);
+        check_assist_not_applicable(
+            convert_closure_to_fn,
+            r#"
+fn main() {
+    let $0add_one = |x| x + 1;
+
+    add_one(2);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_non_closure() {
+        check_assist_not_applicable(
+            convert_closure_to_fn,
+            r#"
+fn main() {
+    let $0x = 1;
+}
+"#,
+        );
+    }
+}
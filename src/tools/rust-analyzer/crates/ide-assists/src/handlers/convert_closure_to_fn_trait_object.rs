@@ -0,0 +1,138 @@
+use stdx::format_to;
+use syntax::{ast, AstNode};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_closure_to_fn_trait_object
+//
+// Boxes a closure stored in a local variable as a `dyn Fn`, `dyn FnMut` or `dyn FnOnce` trait
+// object, depending on how the closure uses its captures.
+//
+// ```
+// # //- minicore: fn
+// fn main() {
+//     let f: _ = $0|x: u32| x + 1;
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let f: _ = Box::new(|x: u32| x + 1) as Box<dyn Fn(u32) -> u32>;
+// }
+// ```
+pub(crate) fn convert_closure_to_fn_trait_object(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let closure = ctx.find_node_at_offset::<ast::ClosureExpr>()?;
+    let let_stmt = closure.syntax().parent().and_then(ast::LetStmt::cast)?;
+    if let_stmt.initializer()?.syntax() != closure.syntax() {
+        return None;
+    }
+    // Only offer this on the closure's parameter list, not anywhere in its body.
+    if !closure.param_list()?.syntax().text_range().contains_range(ctx.selection_trimmed()) {
+        return None;
+    }
+
+    let closure_ty = ctx.sema.type_of_expr(&closure.clone().into())?.original;
+    let callable = closure_ty.as_callable(ctx.db())?;
+    let params: Vec<_> = callable.params(ctx.db()).into_iter().map(|(_, ty)| ty).collect();
+    let ret_ty = callable.return_type();
+    if params.iter().chain(Some(&ret_ty)).any(|ty| ty.contains_unknown()) {
+        cov_mark::hit!(convert_closure_to_fn_trait_object_unknown_signature);
+        return None;
+    }
+
+    let module = ctx.sema.scope(closure.syntax())?.module();
+    let fn_trait = closure_ty.as_closure()?.fn_trait(ctx.db());
+    let fn_trait = hir::Trait::from(fn_trait.get_id(ctx.db(), module.krate().into())?);
+    let fn_trait = fn_trait.name(ctx.db());
+    let display = |ty: &hir::Type| ty.display_source_code(ctx.db(), module.into(), true).ok();
+    let params = params.iter().map(display).collect::<Option<Vec<_>>>()?;
+    let mut dyn_ty = format!("dyn {}({})", fn_trait.display(ctx.db()), params.join(", "));
+    if !ret_ty.is_unit() {
+        format_to!(dyn_ty, " -> {}", display(&ret_ty)?);
+    }
+
+    let target = closure.syntax().text_range();
+    acc.add(
+        AssistId("convert_closure_to_fn_trait_object", AssistKind::RefactorRewrite),
+        format!("Convert closure to `Box<{dyn_ty}>`"),
+        target,
+        |builder| builder.replace(target, format!("Box::new({closure}) as Box<{dyn_ty}>")),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::convert_closure_to_fn_trait_object;
+
+    #[test]
+    fn non_capturing_closure() {
+        check_assist(
+            convert_closure_to_fn_trait_object,
+            r#"
+//- minicore: fn
+fn main() {
+    let f: _ = $0|| ();
+    f();
+}
+"#,
+            r#"
+fn main() {
+    let f: _ = Box::new(|| ()) as Box<dyn Fn()>;
+    f();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn mutating_closure_is_fn_mut() {
+        check_assist(
+            convert_closure_to_fn_trait_object,
+            r#"
+//- minicore: fn
+fn main() {
+    let mut n = 0u8;
+    let f = |$0step: u8| { n += step; n };
+}
+"#,
+            r#"
+fn main() {
+    let mut n = 0u8;
+    let f = Box::new(|step: u8| { n += step; n }) as Box<dyn FnMut(u8) -> u8>;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_unknown_signature() {
+        cov_mark::check!(convert_closure_to_fn_trait_object_unknown_signature);
+        check_assist_not_applicable(
+            convert_closure_to_fn_trait_object,
+            r#"
+//- minicore: fn
+fn main() {
+    let f = $0|x| x;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_in_body() {
+        check_assist_not_applicable(
+            convert_closure_to_fn_trait_object,
+            r#"
+//- minicore: fn
+fn main() {
+    let f = || $0();
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,293 @@
+use hir::PathResolution;
+use ide_db::{famous_defs::FamousDefs, FxHashSet};
+use syntax::{
+    ast::{self, edit::IndentLevel, HasLoopBody},
+    AstNode,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_for_to_while_let
+//
+// Converts a for loop over an iterator into a while let loop calling `next`.
+//
+// ```
+// fn main() {
+//     for$0 i in 0..10 {
+//         foo(i);
+//     }
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let mut it = 0..10;
+//     while let Some(i) = it.next() {
+//         foo(i);
+//     }
+// }
+// ```
+pub(crate) fn convert_for_to_while_let(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let for_loop = ctx.find_node_at_offset::<ast::ForExpr>()?;
+    let iterable = for_loop.iterable()?;
+    let pat = for_loop.pat()?;
+    let body = for_loop.loop_body()?;
+    if body.syntax().text_range().start() < ctx.offset() {
+        cov_mark::hit!(convert_for_to_while_let_in_body);
+        return None;
+    }
+
+    // The `let` binding for the iterator has to go in the enclosing block.
+    let parent = for_loop.syntax().parent()?;
+    if !ast::ExprStmt::can_cast(parent.kind()) && !ast::StmtList::can_cast(parent.kind()) {
+        return None;
+    }
+
+    if !matches!(iterable, ast::Expr::RangeExpr(_)) && !impls_core_iter(ctx, &iterable) {
+        cov_mark::hit!(convert_for_to_while_let_not_iterator);
+        return None;
+    }
+
+    acc.add(
+        AssistId("convert_for_to_while_let", AssistKind::RefactorRewrite),
+        "Convert for loop to while let",
+        for_loop.syntax().text_range(),
+        |builder| {
+            let indent = IndentLevel::from_node(for_loop.syntax());
+            let label = for_loop.label().map(|label| format!("{label} ")).unwrap_or_default();
+
+            let replacement = if is_mut_local(ctx, &iterable) {
+                // A mutable iterator can be advanced in place.
+                format!("{label}while let Some({pat}) = {iterable}.next() {body}")
+            } else {
+                let it = fresh_iter_name(ctx, &body);
+                format!(
+                    "let mut {it} = {iterable};\n\
+                     {indent}{label}while let Some({pat}) = {it}.next() {body}"
+                )
+            };
+
+            builder.replace(for_loop.syntax().text_range(), replacement)
+        },
+    )
+}
+
+/// A name for the iterator that doesn't shadow anything the loop body can see: `it`, or `it1`,
+/// `it2`, ... if that is taken.
+fn fresh_iter_name(ctx: &AssistContext<'_>, body: &ast::BlockExpr) -> String {
+    let mut names_in_scope = FxHashSet::default();
+    if let Some(scope) = ctx.sema.scope(body.syntax()) {
+        scope.process_all_names(&mut |name, _| {
+            names_in_scope.insert(name.display(ctx.db()).to_string());
+        });
+    }
+
+    let mut name = "it".to_owned();
+    let mut counter = 0;
+    while names_in_scope.contains(&name) {
+        counter += 1;
+        name = format!("it{counter}");
+    }
+    name
+}
+
+/// Whether `expr` is a path to a local declared as `mut`.
+fn is_mut_local(ctx: &AssistContext<'_>, expr: &ast::Expr) -> bool {
+    let ast::Expr::PathExpr(path_expr) = expr else { return false };
+    match path_expr.path().and_then(|path| ctx.sema.resolve_path(&path)) {
+        Some(PathResolution::Local(local)) => local.is_mut(ctx.db()),
+        _ => false,
+    }
+}
+
+fn impls_core_iter(ctx: &AssistContext<'_>, iterable: &ast::Expr) -> bool {
+    (|| {
+        let ty = ctx.sema.type_of_expr(iterable)?.adjusted();
+        let krate = ctx.sema.scope(iterable.syntax())?.krate();
+        let iter_trait = FamousDefs(&ctx.sema, krate).core_iter_Iterator()?;
+        Some(ty.impls_trait(ctx.db(), iter_trait, &[]))
+    })()
+    .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn convert_range_loop() {
+        check_assist(
+            convert_for_to_while_let,
+            r#"
+fn main() {
+    for$0 i in 0..10 {
+        foo(i);
+    }
+}
+"#,
+            r#"
+fn main() {
+    let mut it = 0..10;
+    while let Some(i) = it.next() {
+        foo(i);
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_collection_iter_loop() {
+        check_assist(
+            convert_for_to_while_let,
+            r#"
+//- minicore: iterators
+struct Bag;
+struct BagIter;
+impl Iterator for BagIter {
+    type Item = u32;
+    fn next(&mut self) -> Option<u32> { None }
+}
+impl Bag {
+    fn iter(&self) -> BagIter { BagIter }
+}
+
+fn main() {
+    let bag = Bag;
+    if true {
+        $0for x in bag.iter() {
+            foo(x);
+        }
+    }
+}
+"#,
+            r#"
+struct Bag;
+struct BagIter;
+impl Iterator for BagIter {
+    type Item = u32;
+    fn next(&mut self) -> Option<u32> { None }
+}
+impl Bag {
+    fn iter(&self) -> BagIter { BagIter }
+}
+
+fn main() {
+    let bag = Bag;
+    if true {
+        let mut it = bag.iter();
+        while let Some(x) = it.next() {
+            foo(x);
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_mutable_iterator_in_place() {
+        check_assist(
+            convert_for_to_while_let,
+            r#"
+//- minicore: iterators
+fn main() {
+    let mut iter = core::iter::repeat(92);
+    'outer: for$0 x in iter {
+        foo(x);
+    }
+}
+"#,
+            r#"
+fn main() {
+    let mut iter = core::iter::repeat(92);
+    'outer: while let Some(x) = iter.next() {
+        foo(x);
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_immutable_iterator_with_binding() {
+        check_assist(
+            convert_for_to_while_let,
+            r#"
+//- minicore: iterators
+fn main() {
+    let iter = core::iter::repeat(92);
+    for$0 x in iter {
+        foo(x);
+    }
+}
+"#,
+            r#"
+fn main() {
+    let iter = core::iter::repeat(92);
+    let mut it = iter;
+    while let Some(x) = it.next() {
+        foo(x);
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn iterator_name_avoids_names_used_in_body() {
+        check_assist(
+            convert_for_to_while_let,
+            r#"
+fn main() {
+    let it = 92;
+    for$0 i in 0..10 {
+        foo(i, it);
+    }
+}
+"#,
+            r#"
+fn main() {
+    let it = 92;
+    let mut it1 = 0..10;
+    while let Some(i) = it1.next() {
+        foo(i, it);
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_non_iterator() {
+        cov_mark::check!(convert_for_to_while_let_not_iterator);
+        check_assist_not_applicable(
+            convert_for_to_while_let,
+            r#"
+//- minicore: iterators
+fn main() {
+    for$0 x in [1, 2, 3] {
+        foo(x);
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_in_body() {
+        cov_mark::check!(convert_for_to_while_let_in_body);
+        check_assist_not_applicable(
+            convert_for_to_while_let,
+            r#"
+fn main() {
+    for i in 0..10 {
+        $0foo(i);
+    }
+}
+"#,
+        );
+    }
+}
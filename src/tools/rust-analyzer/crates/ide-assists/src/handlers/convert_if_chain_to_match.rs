@@ -0,0 +1,402 @@
+use std::iter::{self, successors};
+
+use hir::{db::HirDatabase, PathResolution, StructKind};
+use ide_db::famous_defs::FamousDefs;
+use syntax::{
+    ast::{
+        self,
+        edit::{AstNodeEdit, IndentLevel},
+        make, BinaryOp, CmpOp,
+    },
+    AstNode, TextRange,
+};
+
+use crate::{utils::unwrap_trivial_block, AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_if_chain_to_match
+//
+// Converts a chain of `if`s comparing the same expression against constants into a `match`.
+//
+// ```
+// fn f(x: i32) -> &'static str {
+//     $0if x == 1 {
+//         "one"
+//     } else if x == 2 {
+//         "two"
+//     } else {
+//         "many"
+//     }
+// }
+// ```
+// ->
+// ```
+// fn f(x: i32) -> &'static str {
+//     match x {
+//         1 => "one",
+//         2 => "two",
+//         _ => "many",
+//     }
+// }
+// ```
+pub(crate) fn convert_if_chain_to_match(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let if_expr: ast::IfExpr = ctx.find_node_at_offset()?;
+    let available_range = TextRange::new(
+        if_expr.syntax().text_range().start(),
+        if_expr.then_branch()?.syntax().text_range().start(),
+    );
+    if !available_range.contains_range(ctx.selection_trimmed()) {
+        return None;
+    }
+
+    let mut else_block = None;
+    let if_exprs = successors(Some(if_expr.clone()), |expr| match expr.else_branch()? {
+        ast::ElseBranch::IfExpr(expr) => Some(expr),
+        ast::ElseBranch::Block(block) => {
+            else_block = Some(block);
+            None
+        }
+    });
+
+    let mut scrutinee: Option<ast::Expr> = None;
+    let mut arms = Vec::new();
+    for if_expr in if_exprs {
+        let (expr, pat) = equality_with_constant(ctx, &if_expr.condition()?)?;
+        match &scrutinee {
+            // Like `replace_if_let_with_match`, compare the scrutinees syntactically.
+            Some(scrutinee) if scrutinee.syntax().text() != expr.syntax().text() => {
+                cov_mark::hit!(convert_if_chain_to_match_different_scrutinees);
+                return None;
+            }
+            Some(_) => (),
+            None => scrutinee = Some(expr),
+        }
+        arms.push((pat, if_expr.then_branch()?));
+    }
+    if arms.len() < 2 {
+        return None;
+    }
+    let scrutinee = scrutinee?;
+
+    acc.add(
+        AssistId("convert_if_chain_to_match", AssistKind::RefactorRewrite),
+        "Convert if chain to match",
+        available_range,
+        move |edit| {
+            let make_arm = |pat, body: ast::BlockExpr| {
+                let body = body.reset_indent().indent(IndentLevel(1));
+                make::match_arm(iter::once(pat), None, unwrap_trivial_block(body))
+            };
+            let else_arm = make::match_arm(
+                iter::once(make::wildcard_pat().into()),
+                None,
+                match else_block {
+                    Some(block) => unwrap_trivial_block(block),
+                    None => make::expr_unit(),
+                },
+            );
+            let arms =
+                arms.into_iter().map(|(pat, body)| make_arm(pat, body)).chain(iter::once(else_arm));
+            let match_expr = make::expr_match(scrutinee, make::match_arm_list(arms))
+                .indent(IndentLevel::from_node(if_expr.syntax()));
+
+            let is_else_if =
+                if_expr.syntax().parent().map_or(false, |it| ast::IfExpr::can_cast(it.kind()));
+            let expr = if is_else_if {
+                // Keep `else { match .. }`, `else match ..` is not valid.
+                make::block_expr(None, Some(match_expr)).into()
+            } else {
+                match_expr
+            };
+            edit.replace_ast::<ast::Expr>(if_expr.into(), expr);
+        },
+    )
+}
+
+/// Splits a `scrutinee == constant` (or `constant == scrutinee`) condition into the scrutinee and
+/// the pattern matching the constant.
+fn equality_with_constant(
+    ctx: &AssistContext<'_>,
+    cond: &ast::Expr,
+) -> Option<(ast::Expr, ast::Pat)> {
+    let ast::Expr::BinExpr(bin_expr) = cond else { return None };
+    if bin_expr.op_kind()? != BinaryOp::CmpOp(CmpOp::Eq { negated: false }) {
+        cov_mark::hit!(convert_if_chain_to_match_not_equality);
+        return None;
+    }
+    let (lhs, rhs) = (bin_expr.lhs()?, bin_expr.rhs()?);
+    let (scrutinee, constant, pat) = match constant_pat(ctx, &rhs) {
+        Some(pat) => (lhs, rhs, pat),
+        None => {
+            let pat = constant_pat(ctx, &lhs)?;
+            (rhs, lhs, pat)
+        }
+    };
+    if !is_place(&scrutinee) {
+        cov_mark::hit!(convert_if_chain_to_match_not_a_place);
+        return None;
+    }
+    // `==` may compare different types, e.g. through `impl PartialEq<u32> for Id`, but a pattern
+    // only matches values of the scrutinee's own type.
+    let scrutinee_ty = ctx.sema.type_of_expr(&scrutinee)?.original;
+    let constant_ty = ctx.sema.type_of_expr(&constant)?.original;
+    if scrutinee_ty.contains_unknown() || !scrutinee_ty.could_unify_with(ctx.db(), &constant_ty) {
+        cov_mark::hit!(convert_if_chain_to_match_type_mismatch);
+        return None;
+    }
+    Some((scrutinee, pat))
+}
+
+/// Whether `expr` is a path or a field access on one, which can be matched on in place of being
+/// evaluated once per comparison.
+fn is_place(expr: &ast::Expr) -> bool {
+    match expr {
+        ast::Expr::PathExpr(_) => true,
+        ast::Expr::FieldExpr(field) => field.expr().map_or(false, |it| is_place(&it)),
+        _ => false,
+    }
+}
+
+/// The pattern for `expr` if it is a literal, a constant or a unit enum variant.
+fn constant_pat(ctx: &AssistContext<'_>, expr: &ast::Expr) -> Option<ast::Pat> {
+    match expr {
+        ast::Expr::Literal(_) => Some(make::literal_pat(&expr.to_string()).into()),
+        ast::Expr::PrefixExpr(prefix)
+            if prefix.op_kind()? == ast::UnaryOp::Neg
+                && matches!(prefix.expr()?, ast::Expr::Literal(_)) =>
+        {
+            Some(make::literal_pat(&expr.to_string()).into())
+        }
+        ast::Expr::PathExpr(path_expr) => {
+            let path = path_expr.path()?;
+            match ctx.sema.resolve_path(&path)? {
+                PathResolution::Def(hir::ModuleDef::Const(konst)) => {
+                    let krate = ctx.sema.scope(path_expr.syntax())?.krate();
+                    let partial_eq = FamousDefs(&ctx.sema, krate).core_cmp_PartialEq()?;
+                    if !is_structural_match(ctx.db(), &konst.ty(ctx.db()), partial_eq) {
+                        cov_mark::hit!(convert_if_chain_to_match_non_structural_const);
+                        return None;
+                    }
+                    Some(make::path_pat(path))
+                }
+                PathResolution::Def(hir::ModuleDef::Variant(variant))
+                    if variant.kind(ctx.db()) == StructKind::Unit =>
+                {
+                    Some(make::path_pat(path))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Whether a constant of type `ty` can be used as a pattern. rustc only accepts constants whose
+/// `PartialEq` is derived, and so compares them structurally.
+fn is_structural_match(db: &dyn HirDatabase, ty: &hir::Type, partial_eq: hir::Trait) -> bool {
+    if ty.is_scalar() || ty.is_str() {
+        return true;
+    }
+    if let Some((inner, _)) = ty.as_reference() {
+        return is_structural_match(db, &inner, partial_eq);
+    }
+    if let Some((elem, _)) = ty.as_array(db) {
+        return is_structural_match(db, &elem, partial_eq);
+    }
+    if ty.is_tuple() {
+        return ty.tuple_fields(db).iter().all(|it| is_structural_match(db, it, partial_eq));
+    }
+    ty.as_adt().is_some()
+        && hir::Impl::all_for_type(db, ty.clone())
+            .into_iter()
+            .any(|it| it.trait_(db) == Some(partial_eq) && it.as_builtin_derive_path(db).is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn integer_equality_chain() {
+        check_assist(
+            convert_if_chain_to_match,
+            r#"
+fn f(x: i32) {
+    $0if x == 1 {
+        foo();
+    } else if 2 == x {
+        bar();
+        baz();
+    } else if x == -3 {
+        qux();
+    }
+}
+"#,
+            r#"
+fn f(x: i32) {
+    match x {
+        1 => foo(),
+        2 => {
+            bar();
+            baz();
+        }
+        -3 => qux(),
+        _ => (),
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn constants_and_variants() {
+        check_assist(
+            convert_if_chain_to_match,
+            r#"
+//- minicore: eq, derive
+#[derive(PartialEq, Eq)]
+enum Dir { Up, Down }
+const LIMIT: u8 = 10;
+
+fn f(d: Dir, n: u8) -> u8 {
+    if$0 d == Dir::Up {
+        LIMIT
+    } else if d == Dir::Down {
+        0
+    } else {
+        n
+    }
+}
+"#,
+            r#"
+#[derive(PartialEq, Eq)]
+enum Dir { Up, Down }
+const LIMIT: u8 = 10;
+
+fn f(d: Dir, n: u8) -> u8 {
+    match d {
+        Dir::Up => LIMIT,
+        Dir::Down => 0,
+        _ => n,
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_different_scrutinees() {
+        cov_mark::check!(convert_if_chain_to_match_different_scrutinees);
+        check_assist_not_applicable(
+            convert_if_chain_to_match,
+            r#"
+fn f(x: i32, y: i32) {
+    $0if x == 1 {
+        foo();
+    } else if y == 2 {
+        bar();
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_comparisons() {
+        cov_mark::check!(convert_if_chain_to_match_not_equality);
+        check_assist_not_applicable(
+            convert_if_chain_to_match,
+            r#"
+fn f(x: i32) {
+    $0if x == 1 {
+        foo();
+    } else if x < 2 {
+        bar();
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_non_constant_comparisons() {
+        check_assist_not_applicable(
+            convert_if_chain_to_match,
+            r#"
+fn f(x: i32, y: i32) {
+    $0if x == y {
+        foo();
+    } else if x == 2 {
+        bar();
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_method_call_scrutinees() {
+        cov_mark::check!(convert_if_chain_to_match_not_a_place);
+        check_assist_not_applicable(
+            convert_if_chain_to_match,
+            r#"
+fn f(v: &[i32]) {
+    $0if v.len() == 1 {
+        foo();
+    } else if v.len() == 2 {
+        bar();
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_comparisons_across_types() {
+        cov_mark::check!(convert_if_chain_to_match_type_mismatch);
+        check_assist_not_applicable(
+            convert_if_chain_to_match,
+            r#"
+//- minicore: eq
+struct Id(u32);
+impl PartialEq<u32> for Id {
+    fn eq(&self, other: &u32) -> bool { self.0 == *other }
+}
+
+fn f(id: Id) {
+    $0if id == 1 {
+        foo();
+    } else if id == 2 {
+        bar();
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_consts_without_derived_partial_eq() {
+        cov_mark::check!(convert_if_chain_to_match_non_structural_const);
+        check_assist_not_applicable(
+            convert_if_chain_to_match,
+            r#"
+//- minicore: eq
+struct Meters(u32);
+impl PartialEq for Meters {
+    fn eq(&self, other: &Meters) -> bool { self.0 == other.0 }
+}
+const ZERO: Meters = Meters(0);
+const ONE: Meters = Meters(1);
+
+fn f(m: Meters) {
+    $0if m == ZERO {
+        foo();
+    } else if m == ONE {
+        bar();
+    }
+}
+"#,
+        );
+    }
+}
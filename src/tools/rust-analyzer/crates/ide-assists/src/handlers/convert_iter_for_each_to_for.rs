@@ -1,5 +1,5 @@
 use hir::known;
-use ide_db::famous_defs::FamousDefs;
+use ide_db::{famous_defs::FamousDefs, syntax_helpers::node_ext::walk_expr};
 use stdx::format_to;
 use syntax::{
     ast::{self, edit_in_place::Indent, make, HasArgList, HasLoopBody},
@@ -48,6 +48,14 @@ pub(crate) fn convert_iter_for_each_to_for(
     let param_list = closure.param_list()?;
     let param = param_list.params().next()?.pat()?;
     let body = closure.body()?;
+    // A `return` from the closure only skips to the next item, which has no direct equivalent
+    // once the body is inlined into a loop that may contain loops of its own.
+    let mut has_return = false;
+    walk_expr(&body, &mut |expr| has_return |= matches!(expr, ast::Expr::ReturnExpr(_)));
+    if has_return {
+        cov_mark::hit!(for_each_to_for_closure_returns);
+        return None;
+    }
 
     let stmt = method.syntax().parent().and_then(ast::ExprStmt::cast);
     let range = stmt.as_ref().map_or(method.syntax(), AstNode::syntax).text_range();
@@ -288,6 +296,47 @@ fn main() {
         )
     }
 
+    #[test]
+    fn test_for_each_on_adapter_chain() {
+        check_assist(
+            convert_iter_for_each_to_for,
+            r#"
+//- minicore: iterators, option
+fn main() {
+    core::iter::repeat(92).filter_map(|x| Some(x)).take(3).$0for_each(|x| {
+        let y = x * 2;
+    });
+}
+"#,
+            r#"
+fn main() {
+    for x in core::iter::repeat(92).filter_map(|x| Some(x)).take(3) {
+        let y = x * 2;
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn test_for_each_not_applicable_with_return() {
+        cov_mark::check!(for_each_to_for_closure_returns);
+        check_assist_not_applicable(
+            convert_iter_for_each_to_for,
+            r#"
+//- minicore: iterators
+fn main() {
+    core::iter::repeat(92).$0for_each(|x| {
+        if x == 0 {
+            return;
+        }
+        let y = x * 2;
+    });
+}
+"#,
+        )
+    }
+
     #[test]
     fn test_for_each_not_applicable() {
         check_assist_not_applicable(
@@ -251,6 +251,24 @@ fn main() {
         );
     }
 
+    #[test]
+    fn convert_let_else_to_match_statement_else() {
+        check_assist(
+            convert_let_else_to_match,
+            r"
+fn main() {
+    let Some(x) = f() else$0 { return; };
+}",
+            r"
+fn main() {
+    let x = match f() {
+        Some(x) => x,
+        _ => { return; }
+    };
+}",
+        );
+    }
+
     #[test]
     fn convert_let_else_to_match_const_ref() {
         check_assist(
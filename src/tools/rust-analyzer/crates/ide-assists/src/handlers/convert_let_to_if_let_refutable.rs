@@ -0,0 +1,337 @@
+use hir::HirFileId;
+use syntax::{
+    ast::{
+        self,
+        edit::{AstNodeEdit, IndentLevel},
+        make,
+    },
+    match_ast, AstNode, AstPtr, SyntaxKind, TextRange,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+use super::convert_to_guarded_return::let_stmt_early_expression;
+
+// Assist: convert_let_to_if_let_refutable
+//
+// Fixes a `let` whose pattern is refutable, either by moving the statements following it into an
+// `if let`, or by turning it into a `let else` that returns.
+//
+// ```
+// enum Shape { Circle(f32), Square(f32) }
+// fn area(shape: Shape) {
+//     $0let Shape::Circle(r) = shape;
+//     let area = r * r * 3.14;
+// }
+// ```
+// ->
+// ```
+// enum Shape { Circle(f32), Square(f32) }
+// fn area(shape: Shape) {
+//     if let Shape::Circle(r) = shape {
+//         let area = r * r * 3.14;
+//     }
+// }
+// ```
+pub(crate) fn convert_let_to_if_let_refutable(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let let_stmt = ctx.find_node_at_offset::<ast::LetStmt>()?;
+    if let_stmt.let_else().is_some() || let_stmt.ty().is_some() {
+        return None;
+    }
+    let pat = let_stmt.pat()?;
+    let init = let_stmt.initializer()?;
+    if !is_refutable(ctx, &let_stmt, &pat)? {
+        cov_mark::hit!(convert_let_to_if_let_refutable_irrefutable);
+        return None;
+    }
+    let stmt_list = let_stmt.syntax().parent().and_then(ast::StmtList::cast)?;
+
+    let indent = let_stmt.indent_level();
+    let following: Vec<ast::Stmt> =
+        stmt_list.statements().skip_while(|it| it.syntax() != let_stmt.syntax()).skip(1).collect();
+    let last = match (stmt_list.tail_expr(), following.last()) {
+        (Some(tail), _) => tail.syntax().clone(),
+        (None, Some(stmt)) => stmt.syntax().clone(),
+        (None, None) => let_stmt.syntax().clone(),
+    };
+    let target = let_stmt.syntax().text_range();
+    let range = TextRange::new(target.start(), last.text_range().end());
+    let moved = TextRange::new(target.end(), range.end());
+
+    // An `if let` without an `else` is `()`, so it can only end a block whose value is `()` too.
+    let block = stmt_list.syntax().parent().and_then(ast::BlockExpr::cast)?;
+    let block_is_unit =
+        ctx.sema.type_of_expr(&block.into()).is_some_and(|ty| ty.original.is_unit());
+    if block_is_unit {
+        acc.add(
+            AssistId("convert_let_to_if_let_refutable", AssistKind::QuickFix),
+            "Convert to `if let`",
+            target,
+            |edit| {
+                // The rest of the block moves into the `if let` as written, comments included,
+                // one level further in.
+                let body: String = stmt_list
+                    .syntax()
+                    .descendants_with_tokens()
+                    .filter_map(|it| it.into_token())
+                    .filter(|token| moved.contains_range(token.text_range()))
+                    .map(|token| match token.kind() {
+                        SyntaxKind::WHITESPACE if token.text().contains('\n') => {
+                            format!("{}{}", token.text(), IndentLevel::single())
+                        }
+                        _ => token.text().to_owned(),
+                    })
+                    .collect();
+                let cond = make::expr_let(pat.clone(), init.clone());
+                edit.replace(range, format!("if {cond} {{{body}\n{indent}}}"));
+            },
+        );
+    } else {
+        cov_mark::hit!(convert_let_to_if_let_refutable_valued_block);
+    }
+    // The `else` has to leave the function or the loop iteration, like a guarded return.
+    let early_expression = let_stmt_early_expression(&let_stmt)?;
+    acc.add(
+        AssistId("convert_let_to_if_let_refutable", AssistKind::QuickFix),
+        "Convert to `let else`",
+        target,
+        |edit| {
+            let diverging = make::block_expr([make::expr_stmt(early_expression).into()], None);
+            let let_else = make::let_else_stmt(pat, None, init, diverging);
+            edit.replace(target, let_else.indent(indent).to_string());
+        },
+    )
+}
+
+/// Whether pattern analysis finds values of the initializer that `pat` doesn't match.
+fn is_refutable(ctx: &AssistContext<'_>, let_stmt: &ast::LetStmt, pat: &ast::Pat) -> Option<bool> {
+    let body: hir::DefWithBody = let_stmt.syntax().ancestors().find_map(|node| {
+        match_ast! {
+            match node {
+                ast::Fn(it) => Some(ctx.sema.to_def(&it)?.into()),
+                ast::Const(it) => Some(ctx.sema.to_def(&it)?.into()),
+                ast::Static(it) => Some(ctx.sema.to_def(&it)?.into()),
+                _ => None,
+            }
+        }
+    })?;
+    let file_id = HirFileId::from(ctx.file_id());
+    let pat = AstPtr::new(pat);
+    Some(
+        body.non_exhaustive_lets(ctx.db())
+            .iter()
+            .any(|it| it.pat.file_id == file_id && it.pat.value == pat),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{
+        check_assist, check_assist_by_label, check_assist_not_applicable,
+        check_assist_not_applicable_by_label,
+    };
+
+    use super::*;
+
+    #[test]
+    fn wraps_multi_variant_enum_in_if_let() {
+        check_assist(
+            convert_let_to_if_let_refutable,
+            r#"
+enum Event { Key(char), Click { x: u32, y: u32 } }
+fn quit() {}
+fn log(c: char) {}
+fn handle(event: Event) {
+    $0let Event::Key(c) = event;
+    if c == 'q' {
+        quit();
+    }
+    log(c)
+}
+"#,
+            r#"
+enum Event { Key(char), Click { x: u32, y: u32 } }
+fn quit() {}
+fn log(c: char) {}
+fn handle(event: Event) {
+    if let Event::Key(c) = event {
+        if c == 'q' {
+            quit();
+        }
+        log(c)
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn converts_to_let_else() {
+        check_assist_by_label(
+            convert_let_to_if_let_refutable,
+            r#"
+enum Event { Key(char), Click { x: u32, y: u32 } }
+fn handle(event: Event) {
+    let Event::Click { x, .. }$0 = event;
+    log(x);
+}
+"#,
+            r#"
+enum Event { Key(char), Click { x: u32, y: u32 } }
+fn handle(event: Event) {
+    let Event::Click { x, .. } = event else {
+        return;
+    };
+    log(x);
+}
+"#,
+            "Convert to `let else`",
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_irrefutable_pattern() {
+        cov_mark::check!(convert_let_to_if_let_refutable_irrefutable);
+        check_assist_not_applicable(
+            convert_let_to_if_let_refutable,
+            r#"
+enum Wrapper { Only(u32) }
+fn unwrap(w: Wrapper) {
+    $0let (Wrapper::Only(x), y) = (w, 1);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_or_pattern_covering_all_variants() {
+        cov_mark::check!(convert_let_to_if_let_refutable_irrefutable);
+        check_assist_not_applicable(
+            convert_let_to_if_let_refutable,
+            r#"
+enum Event { Key(u32), Scroll(u32) }
+fn handle(event: Event) {
+    $0let Event::Key(n) | Event::Scroll(n) = event;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn wraps_slice_pattern_in_if_let() {
+        check_assist(
+            convert_let_to_if_let_refutable,
+            r#"
+fn head(xs: &[u32]) {
+    $0let [x, ..] = xs;
+    log(x);
+}
+"#,
+            r#"
+fn head(xs: &[u32]) {
+    if let [x, ..] = xs {
+        log(x);
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn keeps_comments_of_moved_statements() {
+        check_assist(
+            convert_let_to_if_let_refutable,
+            r#"
+enum Event { Key(char), Click { x: u32, y: u32 } }
+fn handle(event: Event) {
+    $0let Event::Key(c) = event;
+    // Quit on `q`.
+    if c == 'q' {
+        quit(); // Doesn't return.
+    }
+
+    log(c);
+}
+"#,
+            r#"
+enum Event { Key(char), Click { x: u32, y: u32 } }
+fn handle(event: Event) {
+    if let Event::Key(c) = event {
+        // Quit on `q`.
+        if c == 'q' {
+            quit(); // Doesn't return.
+        }
+
+        log(c);
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn let_else_continues_in_loop() {
+        check_assist_by_label(
+            convert_let_to_if_let_refutable,
+            r#"
+enum Event { Key(char), Click { x: u32, y: u32 } }
+fn next() -> Event { Event::Key('a') }
+fn handle() {
+    loop {
+        let Event::Key(c)$0 = next();
+        log(c);
+    }
+}
+"#,
+            r#"
+enum Event { Key(char), Click { x: u32, y: u32 } }
+fn next() -> Event { Event::Key('a') }
+fn handle() {
+    loop {
+        let Event::Key(c) = next() else {
+            continue;
+        };
+        log(c);
+    }
+}
+"#,
+            "Convert to `let else`",
+        );
+    }
+
+    #[test]
+    fn no_let_else_outside_fn_or_loop_body() {
+        check_assist_not_applicable_by_label(
+            convert_let_to_if_let_refutable,
+            r#"
+enum Event { Key(char), Click { x: u32, y: u32 } }
+fn handle(event: Event) {
+    let c = {
+        let Event::Key(c)$0 = event;
+        c
+    };
+}
+"#,
+            "Convert to `let else`",
+        );
+    }
+
+    #[test]
+    fn no_if_let_when_block_has_a_value() {
+        cov_mark::check!(convert_let_to_if_let_refutable_valued_block);
+        check_assist_not_applicable_by_label(
+            convert_let_to_if_let_refutable,
+            r#"
+enum E { A(char), B }
+fn f(e: E) -> char {
+    $0let E::A(c) = e;
+    c
+}
+"#,
+            "Convert to `if let`",
+        );
+    }
+}
@@ -51,15 +51,14 @@ pub(crate) fn convert_match_to_let_else(acc: &mut Assists, ctx: &AssistContext<'
         other => format!("{{ {other} }}"),
     };
     let extracting_arm_pat = extracting_arm.pat()?;
-    let extracted_variable_positions = find_extracted_variable(ctx, &extracting_arm)?;
+    let extracted_variables = find_extracted_variables(ctx, &extracting_arm, &pat)?;
 
     acc.add(
         AssistId("convert_match_to_let_else", AssistKind::RefactorRewrite),
         "Convert match to let-else",
         let_stmt.syntax().text_range(),
         |builder| {
-            let extracting_arm_pat =
-                rename_variable(&extracting_arm_pat, &extracted_variable_positions, pat);
+            let extracting_arm_pat = rename_variables(&extracting_arm_pat, &extracted_variables);
             builder.replace(
                 let_stmt.syntax().text_range(),
                 format!("let {extracting_arm_pat} = {initializer_expr} else {diverging_arm_expr};"),
@@ -97,9 +96,39 @@ fn find_arms(
     }
 }
 
-// Given an extracting arm, find the extracted variable.
-fn find_extracted_variable(ctx: &AssistContext<'_>, arm: &ast::MatchArm) -> Option<Vec<Name>> {
-    match arm.expr()? {
+// Given an extracting arm and the pattern of the `let`, find the extracted variables along with
+// the binding each of them becomes. An arm returning a tuple of variables is matched against a
+// tuple pattern of the same length.
+fn find_extracted_variables(
+    ctx: &AssistContext<'_>,
+    arm: &ast::MatchArm,
+    binding: &ast::Pat,
+) -> Option<Vec<(Name, ast::Pat)>> {
+    let with_binding = |names: Vec<Name>, binding: &ast::Pat| {
+        names.into_iter().map(|name| (name, binding.clone())).collect::<Vec<_>>()
+    };
+    match (arm.expr()?, binding) {
+        (ast::Expr::TupleExpr(tuple), ast::Pat::TuplePat(tuple_pat)) => {
+            let fields = tuple.fields().collect::<Vec<_>>();
+            let bindings = tuple_pat.fields().collect::<Vec<_>>();
+            if fields.len() != bindings.len()
+                || bindings.iter().any(|it| matches!(it, ast::Pat::RestPat(_)))
+            {
+                return None;
+            }
+            let mut extracted = Vec::new();
+            for (field, binding) in fields.iter().zip(&bindings) {
+                extracted.extend(with_binding(find_extracted_variable(ctx, field)?, binding));
+            }
+            Some(extracted)
+        }
+        (expr, _) => Some(with_binding(find_extracted_variable(ctx, &expr)?, binding)),
+    }
+}
+
+// Given the expression of an extracting arm, find the extracted variable.
+fn find_extracted_variable(ctx: &AssistContext<'_>, expr: &ast::Expr) -> Option<Vec<Name>> {
+    match expr {
         ast::Expr::PathExpr(path) => {
             let name_ref = path.syntax().descendants().find_map(ast::NameRef::cast)?;
             match NameRefClass::classify(&ctx.sema, &name_ref)? {
@@ -118,14 +147,14 @@ fn find_extracted_variable(ctx: &AssistContext<'_>, arm: &ast::MatchArm) -> Opti
     }
 }
 
-// Rename `extracted` with `binding` in `pat`.
-fn rename_variable(pat: &ast::Pat, extracted: &[Name], binding: ast::Pat) -> SyntaxNode {
+// Rename each `extracted` variable with its `binding` in `pat`.
+fn rename_variables(pat: &ast::Pat, extracted: &[(Name, ast::Pat)]) -> SyntaxNode {
     let syntax = pat.syntax().clone_for_update();
     let extracted = extracted
         .iter()
-        .map(|e| syntax.covering_element(e.syntax().text_range()))
+        .map(|(e, binding)| (syntax.covering_element(e.syntax().text_range()), binding))
         .collect::<Vec<_>>();
-    for extracted_syntax in extracted {
+    for (extracted_syntax, binding) in extracted {
         // If `extracted` variable is a record field, we should rename it to `binding`,
         // otherwise we just need to replace `extracted` with `binding`.
 
@@ -239,6 +268,56 @@ fn foo(opt: Option<()>) {
         );
     }
 
+    #[test]
+    fn tuple_of_bindings() {
+        check_assist(
+            convert_match_to_let_else,
+            r#"
+enum Pair {
+    Both(i32, i32),
+    Neither,
+}
+
+fn foo(pair: Pair) {
+    let (a$0, mut b) = match pair {
+        Pair::Both(x, y) => (x, y),
+        Pair::Neither => return,
+    };
+}
+"#,
+            r#"
+enum Pair {
+    Both(i32, i32),
+    Neither,
+}
+
+fn foo(pair: Pair) {
+    let Pair::Both(a, mut b) = pair else { return };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn should_not_be_applicable_if_tuple_lengths_differ() {
+        check_assist_not_applicable(
+            convert_match_to_let_else,
+            r#"
+enum Pair {
+    Both(i32, i32),
+    Neither,
+}
+
+fn foo(pair: Pair) {
+    let (a$0, ..) = match pair {
+        Pair::Both(x, y) => (x, y),
+        Pair::Neither => return,
+    };
+}
+"#,
+        );
+    }
+
     #[test]
     fn should_not_be_applicable_if_extracting_arm_has_guard() {
         cov_mark::check!(extracting_arm_has_guard);
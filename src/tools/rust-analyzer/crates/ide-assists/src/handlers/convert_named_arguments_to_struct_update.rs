@@ -0,0 +1,215 @@
+use itertools::Itertools;
+use syntax::{
+    ast::{self, edit::AstNodeEdit},
+    AstNode,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_named_arguments_to_struct_update
+//
+// Replaces the fields of a struct literal that are copied from another value of the same struct
+// with the struct update syntax.
+//
+// ```
+// struct Foo { a: u32, b: u32, c: u32 }
+// fn with_c(base: Foo) -> Foo {
+//     Foo { a: base.a, b: base.b, $0c: 3 }
+// }
+// ```
+// ->
+// ```
+// struct Foo { a: u32, b: u32, c: u32 }
+// fn with_c(base: Foo) -> Foo {
+//     Foo { c: 3, ..base }
+// }
+// ```
+pub(crate) fn convert_named_arguments_to_struct_update(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let record = ctx.find_node_at_offset::<ast::RecordExpr>()?;
+    let field_list = record.record_expr_field_list()?;
+    if field_list.dotdot_token().is_some() {
+        return None;
+    }
+
+    let mut base: Option<ast::Expr> = None;
+    let mut remaining = Vec::new();
+    for field in field_list.fields() {
+        let Some(copied_from) = copied_from(&field) else {
+            remaining.push(field);
+            continue;
+        };
+        match &base {
+            Some(base) if base.syntax().text() != copied_from.syntax().text() => {
+                cov_mark::hit!(convert_named_arguments_to_struct_update_different_bases);
+                return None;
+            }
+            Some(_) => {}
+            None => base = Some(copied_from),
+        }
+    }
+    let base = base?;
+    // `..base` is evaluated after all the remaining fields instead of in field order, so a
+    // remaining field that reads or changes the base could see a different value.
+    let root = root_name(&base)?;
+    let mentions_root = |expr: ast::Expr| {
+        expr.syntax()
+            .descendants_with_tokens()
+            .any(|it| it.as_token().is_some_and(|token| token.text() == root.text()))
+    };
+    if remaining.iter().filter_map(|field| field.expr()).any(mentions_root) {
+        cov_mark::hit!(convert_named_arguments_to_struct_update_base_in_remaining_field);
+        return None;
+    }
+    // Having the same fields isn't enough, `..base` needs a value of the struct itself.
+    let ty = ctx.sema.type_of_expr(&ast::Expr::RecordExpr(record.clone()))?.original;
+    if ctx.sema.type_of_expr(&base)?.original != ty {
+        return None;
+    }
+
+    let target = field_list.syntax().text_range();
+    acc.add(
+        AssistId("convert_named_arguments_to_struct_update", AssistKind::RefactorRewrite),
+        "Convert to struct update syntax",
+        target,
+        |edit| {
+            let replacement = if field_list.syntax().text().contains_char('\n') {
+                let indent = field_list.indent_level();
+                let fields = remaining
+                    .iter()
+                    .map(|field| format!("{}{field},\n", indent + 1))
+                    .collect::<String>();
+                format!("{{\n{fields}{}..{base}\n{indent}}}", indent + 1)
+            } else {
+                let fields = remaining.iter().map(|field| format!("{field}, ")).join("");
+                format!("{{ {fields}..{base} }}")
+            };
+            edit.replace(target, replacement);
+        },
+    )
+}
+
+/// The value `field` is copied from, when it is initialized with the field of the same name of
+/// that value, as in `a: base.a`.
+fn copied_from(field: &ast::RecordExprField) -> Option<ast::Expr> {
+    let name = field.name_ref()?;
+    let ast::Expr::FieldExpr(expr) = field.expr()? else { return None };
+    if expr.name_ref()?.text() != name.text() {
+        return None;
+    }
+    // The spread evaluates the base once instead of once per field, so only take places.
+    match expr.expr()? {
+        base @ (ast::Expr::PathExpr(_) | ast::Expr::FieldExpr(_)) => Some(base),
+        _ => None,
+    }
+}
+
+/// The variable the place `base` is read from, like `app` in `app.config`.
+fn root_name(base: &ast::Expr) -> Option<ast::NameRef> {
+    match base {
+        ast::Expr::PathExpr(expr) => expr.path()?.as_single_name_ref(),
+        ast::Expr::FieldExpr(expr) => root_name(&expr.expr()?),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn collapse_two_copied_fields() {
+        check_assist(
+            convert_named_arguments_to_struct_update,
+            r#"
+struct Foo { a: u32, b: u32, c: u32 }
+fn with_c(base: Foo) -> Foo {
+    Foo$0 { a: base.a, c: 3, b: base.b }
+}
+"#,
+            r#"
+struct Foo { a: u32, b: u32, c: u32 }
+fn with_c(base: Foo) -> Foo {
+    Foo { c: 3, ..base }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn collapse_multiline_literal() {
+        check_assist(
+            convert_named_arguments_to_struct_update,
+            r#"
+struct Config { name: String, verbose: bool, depth: u32 }
+struct App { config: Config }
+fn quiet(app: &App) -> Config {
+    let name = String::new();
+    Config {
+        name,
+        verbose: app.config.verbose,$0
+        depth: app.config.depth,
+    }
+}
+"#,
+            r#"
+struct Config { name: String, verbose: bool, depth: u32 }
+struct App { config: Config }
+fn quiet(app: &App) -> Config {
+    let name = String::new();
+    Config {
+        name,
+        ..app.config
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_different_bases() {
+        cov_mark::check!(convert_named_arguments_to_struct_update_different_bases);
+        check_assist_not_applicable(
+            convert_named_arguments_to_struct_update,
+            r#"
+struct Foo { a: u32, b: u32 }
+fn mix(x: Foo, y: Foo) -> Foo {
+    Foo { a: x.a, $0b: y.b }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_base_of_other_type() {
+        check_assist_not_applicable(
+            convert_named_arguments_to_struct_update,
+            r#"
+struct Foo { a: u32, b: u32 }
+struct Bar { a: u32, b: u32 }
+fn convert(bar: Bar) -> Foo {
+    Foo { a: bar.a, $0b: bar.b }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_remaining_field_reads_base() {
+        cov_mark::check!(convert_named_arguments_to_struct_update_base_in_remaining_field);
+        check_assist_not_applicable(
+            convert_named_arguments_to_struct_update,
+            r#"
+struct Foo { a: u32, b: u32, c: u32 }
+fn bump(base: &mut Foo) -> u32 { base.a += 1; base.a }
+fn with_c(mut base: Foo) -> Foo {
+    Foo { a: base.a, $0c: bump(&mut base), b: base.b }
+}
+"#,
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use ide_db::assists::{AssistId, AssistKind};
-use syntax::ast::{self, HasGenericParams, HasName};
-use syntax::{AstNode, SyntaxKind};
+use ide_db::defs::Definition;
+use syntax::ast::{self, edit::IndentLevel, HasGenericParams, HasName};
+use syntax::{AstNode, SyntaxKind, SyntaxNode, TextRange};
 
 use crate::assist_context::{AssistContext, Assists};
 
@@ -43,6 +44,26 @@ pub(crate) fn convert_nested_function_to_closure(
     let name = function.name()?;
     let param_list = function.param_list()?;
 
+    let def = ctx.sema.to_def(&function)?;
+    let usages = Definition::Function(def).usages(&ctx.sema).all();
+    let usage_ranges =
+        usages.iter().flat_map(|(_, refs)| refs).map(|usage| usage.range).collect::<Vec<_>>();
+
+    // A closure can't refer to itself by name.
+    if usage_ranges.iter().any(|range| body.syntax().text_range().contains_range(*range)) {
+        cov_mark::hit!(convert_nested_function_to_closure_recursive);
+        return None;
+    }
+
+    // A closure is only in scope after its `let`, so it has to move up to its first use.
+    let first_use = usage_ranges.iter().min_by_key(|range| range.start()).copied();
+    let move_before = match first_use {
+        Some(first_use) if first_use.start() < target.start() => {
+            Some(stmt_containing(&function, first_use)?)
+        }
+        _ => None,
+    };
+
     acc.add(
         AssistId("convert_nested_function_to_closure", AssistKind::RefactorRewrite),
         "Convert nested function to closure",
@@ -52,15 +73,51 @@ pub(crate) fn convert_nested_function_to_closure(
             let params = params.strip_prefix('(').unwrap_or(params);
             let params = params.strip_suffix(')').unwrap_or(params);
 
+            // When moving, the semicolon after the function is deleted along with it.
             let mut body = body.to_string();
-            if !has_semicolon(&function) {
+            if move_before.is_some() || !has_semicolon(&function) {
                 body.push(';');
             }
-            edit.replace(target, format!("let {name} = |{params}| {body}"));
+            let closure = format!("let {name} = |{params}| {body}");
+
+            match move_before {
+                Some(stmt) => {
+                    let indent = IndentLevel::from_node(&stmt);
+                    edit.insert(stmt.text_range().start(), format!("{closure}\n\n{indent}"));
+                    edit.delete(range_with_leading_whitespace(&function));
+                }
+                None => edit.replace(target, closure),
+            }
         },
     )
 }
 
+/// Returns the statement of the block defining `function` that contains `range`.
+fn stmt_containing(function: &ast::Fn, range: TextRange) -> Option<SyntaxNode> {
+    let stmt_list = function.syntax().parent().and_then(ast::StmtList::cast)?;
+    stmt_list
+        .syntax()
+        .children()
+        .find(|stmt| stmt.text_range().contains_range(range))
+        .filter(|stmt| ast::Stmt::can_cast(stmt.kind()) || ast::Expr::can_cast(stmt.kind()))
+}
+
+/// Returns the range of `function`, including its trailing semicolon and the whitespace before it.
+fn range_with_leading_whitespace(function: &ast::Fn) -> TextRange {
+    let mut range = function.syntax().text_range();
+    if let Some(prev) = function.syntax().prev_sibling_or_token() {
+        if prev.kind() == SyntaxKind::WHITESPACE {
+            range = range.cover(prev.text_range());
+        }
+    }
+    if let Some(next) = function.syntax().next_sibling_or_token() {
+        if next.kind() == SyntaxKind::SEMICOLON {
+            range = range.cover(next.text_range());
+        }
+    }
+    range
+}
+
 /// Returns whether the given function is nested within the body of another function.
 fn is_nested_function(function: &ast::Fn) -> bool {
     function.syntax().ancestors().skip(1).find_map(ast::Item::cast).map_or(false, |it| {
@@ -202,6 +259,50 @@ const fn fo$0o(s: String) -> String {
     }
 
     _ = foo("hello");
+}
+            "#,
+        );
+    }
+
+    #[test]
+    fn convert_nested_function_to_closure_moves_before_first_use() {
+        check_assist(
+            convert_nested_function_to_closure,
+            r#"
+fn main() {
+    let x = 1;
+    _ = foo(x);
+
+    fn $0foo(a: u64) -> u64 {
+        a + 1
+    }
+}
+            "#,
+            r#"
+fn main() {
+    let x = 1;
+    let foo = |a: u64| {
+        a + 1
+    };
+
+    _ = foo(x);
+}
+            "#,
+        );
+    }
+
+    #[test]
+    fn convert_nested_function_to_closure_is_not_suggested_if_function_is_recursive() {
+        cov_mark::check!(convert_nested_function_to_closure_recursive);
+        check_assist_not_applicable(
+            convert_nested_function_to_closure,
+            r#"
+fn main() {
+    fn $0fact(n: u64) -> u64 {
+        if n == 0 { 1 } else { n * fact(n - 1) }
+    }
+
+    _ = fact(5);
 }
             "#,
         );
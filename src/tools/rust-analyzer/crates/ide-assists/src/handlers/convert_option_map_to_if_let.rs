@@ -0,0 +1,179 @@
+use ide_db::{famous_defs::FamousDefs, syntax_helpers::node_ext::walk_expr};
+use syntax::{
+    ast::{self, edit_in_place::Indent, make, HasArgList},
+    ted, AstNode, T,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_option_map_to_if_let
+//
+// Converts an `Option::map` call whose result is unused into an `if let`.
+//
+// ```
+// # //- minicore: option
+// fn main() {
+//     let name = Some("world");
+//     name.map$0(|n| {
+//         greet(n);
+//     });
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let name = Some("world");
+//     if let Some(n) = name {
+//         greet(n);
+//     }
+// }
+// ```
+pub(crate) fn convert_option_map_to_if_let(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let method = ctx.find_node_at_offset::<ast::MethodCallExpr>()?;
+    let name_ref = method.name_ref()?;
+    if name_ref.text() != "map"
+        || !name_ref.syntax().text_range().contains_range(ctx.selection_trimmed())
+    {
+        return None;
+    }
+    let mut args = method.arg_list()?.args();
+    let ast::Expr::ClosureExpr(closure) = args.next()? else { return None };
+    if args.next().is_some() {
+        return None;
+    }
+    let mut params = closure.param_list()?.params();
+    let param = params.next()?.pat()?;
+    if params.next().is_some() {
+        return None;
+    }
+    let body = closure.body()?;
+
+    let receiver = method.receiver()?;
+    let receiver_ty = ctx.sema.type_of_expr(&receiver)?.adjusted();
+    let famous_defs = FamousDefs(&ctx.sema, ctx.sema.scope(method.syntax())?.krate());
+    if receiver_ty.as_adt() != Some(hir::Adt::Enum(famous_defs.core_option_Option()?)) {
+        return None;
+    }
+
+    let Some(stmt) = method.syntax().parent().and_then(ast::ExprStmt::cast) else {
+        cov_mark::hit!(option_map_result_used);
+        return None;
+    };
+    // A `return` from the closure would return from the enclosing function instead.
+    let mut has_return = false;
+    walk_expr(&body, &mut |expr| has_return |= matches!(expr, ast::Expr::ReturnExpr(_)));
+    if has_return {
+        return None;
+    }
+    // An `if let` without `else` is `()`, so a value the closure produces has to be discarded.
+    let tail = match &body {
+        ast::Expr::BlockExpr(block) if block.modifier().is_none() => block.tail_expr(),
+        _ => Some(body.clone()),
+    };
+    let discard_tail = tail.map_or(false, |tail| {
+        !ctx.sema.type_of_expr(&tail).map_or(false, |ty| ty.original().is_unit())
+    });
+
+    let range = stmt.syntax().text_range();
+    acc.add(
+        AssistId("convert_option_map_to_if_let", AssistKind::RefactorRewrite),
+        "Replace `Option::map` with `if let`",
+        range,
+        |builder| {
+            let block = match body {
+                ast::Expr::BlockExpr(block) if block.modifier().is_none() => block,
+                _ => make::block_expr(Vec::new(), Some(body)),
+            }
+            .clone_for_update();
+            if let Some(tail) = block.tail_expr().filter(|_| discard_tail) {
+                ted::insert(ted::Position::after(tail.syntax()), make::token(T![;]));
+            }
+            block.reindent_to(stmt.indent_level());
+
+            builder.replace(range, format!("if let Some({param}) = {receiver} {block}"))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn map_in_statement_position() {
+        check_assist(
+            convert_option_map_to_if_let,
+            r#"
+//- minicore: option
+fn consume(_: i32) {}
+fn main() {
+    let x = Some(2);
+    x.$0map(|n| consume(n));
+}
+"#,
+            r#"
+fn consume(_: i32) {}
+fn main() {
+    let x = Some(2);
+    if let Some(n) = x {
+        consume(n)
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn discards_value_of_closure_body() {
+        check_assist(
+            convert_option_map_to_if_let,
+            r#"
+//- minicore: option
+fn main() {
+    let x = Some(2);
+    x.$0map(|n| n + 1);
+}
+"#,
+            r#"
+fn main() {
+    let x = Some(2);
+    if let Some(n) = x {
+        n + 1;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_result_is_bound() {
+        cov_mark::check!(option_map_result_used);
+        check_assist_not_applicable(
+            convert_option_map_to_if_let,
+            r#"
+//- minicore: option
+fn main() {
+    let y = Some(2).$0map(|x| x + 1);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_types() {
+        check_assist_not_applicable(
+            convert_option_map_to_if_let,
+            r#"
+//- minicore: option, result
+fn main() {
+    Ok::<u8, ()>(2).$0map(|x| { x + 1; });
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,296 @@
+use syntax::{
+    ast::{self, edit::AstNodeEdit, HasArgList, RangeItem, RangeOp, UnaryOp},
+    AstNode,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_range_to_match_guard
+//
+// Moves a `(a..=b).contains(&x)` check out of a match arm body. When `x` is the value matched by
+// the arm, the range becomes the arm's pattern, otherwise the check becomes a match guard.
+//
+// ```
+// fn handle(n: u32) {
+//     match n {
+//         x => $0if (1..=9).contains(&x) { digit(x) },
+//     }
+// }
+// ```
+// ->
+// ```
+// fn handle(n: u32) {
+//     match n {
+//         x @ 1..=9 => digit(x),
+//         x => {}
+//     }
+// }
+// ```
+pub(crate) fn convert_range_to_match_guard(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let match_arm = ctx.find_node_at_offset::<ast::MatchArm>()?;
+    if match_arm.guard().is_some() {
+        return None;
+    }
+    let pat = match_arm.pat()?;
+    let arm_body = match_arm.expr()?;
+    let (if_expr, in_block) = match &arm_body {
+        ast::Expr::IfExpr(if_expr) => (if_expr.clone(), false),
+        ast::Expr::BlockExpr(block) if block.statements().next().is_none() => {
+            match block.tail_expr()? {
+                ast::Expr::IfExpr(if_expr) => (if_expr, true),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    let then_branch = if_expr.then_branch()?;
+    if ctx.offset() > then_branch.syntax().text_range().start() {
+        return None;
+    }
+    let else_branch = match if_expr.else_branch() {
+        Some(ast::ElseBranch::Block(block)) => Some(block),
+        Some(ast::ElseBranch::IfExpr(_)) => return None,
+        None => None,
+    };
+
+    let cond = if_expr.condition()?;
+    let (range, checked) = range_contains(&cond)?;
+    let is_inclusive = range.op_kind() == Some(RangeOp::Inclusive);
+    if !is_inclusive {
+        cov_mark::hit!(convert_range_exclusive_stays_guard);
+    }
+    let foldable = is_inclusive && is_literal(range.start()) && is_literal(range.end());
+    let range_pat = match &pat {
+        ast::Pat::WildcardPat(_) if foldable => {
+            let match_expr = match_arm.syntax().ancestors().find_map(ast::MatchExpr::cast)?;
+            (match_expr.expr()?.syntax().text() == checked.text().as_str())
+                .then(|| range.to_string())
+        }
+        ast::Pat::IdentPat(ident) if foldable => {
+            let name = ident.name()?;
+            let is_plain_binding =
+                ident.pat().is_none() && ident.ref_token().is_none() && ident.mut_token().is_none();
+            let is_used = then_branch
+                .syntax()
+                .descendants()
+                .filter_map(ast::NameRef::cast)
+                .any(|name_ref| name_ref.text() == name.text());
+            if !is_plain_binding || name.text() != checked.text() {
+                None
+            } else if is_used {
+                Some(format!("{name} @ {range}"))
+            } else {
+                Some(range.to_string())
+            }
+        }
+        _ => None,
+    };
+
+    let label = match range_pat {
+        Some(_) => "Move range into match arm pattern",
+        None => "Move range to match guard",
+    };
+    let target = match_arm.syntax().text_range();
+    acc.add(
+        AssistId("convert_range_to_match_guard", AssistKind::RefactorRewrite),
+        label,
+        target,
+        |edit| {
+            let dedent = if in_block { 1 } else { 0 };
+            let indent = match_arm.indent_level();
+            let arm_text = |pat: &str, block: &ast::BlockExpr| match block.tail_expr() {
+                Some(expr) if block.statements().next().is_none() => format!("{pat} => {expr},"),
+                _ => format!("{pat} => {}", block.dedent(dedent.into())),
+            };
+
+            let mut arms = vec![match &range_pat {
+                Some(range_pat) => arm_text(range_pat, &then_branch),
+                None => arm_text(&format!("{pat} if {cond}"), &then_branch),
+            }];
+            // Values outside of the range used to be matched by this arm too.
+            match &else_branch {
+                Some(else_branch) => arms.push(arm_text(&pat.to_string(), else_branch)),
+                None => {
+                    // A following `_ => {}` already does nothing for them.
+                    let next_arm = match_arm.syntax().next_sibling().and_then(ast::MatchArm::cast);
+                    let next_does_nothing = next_arm.map_or(false, |arm| {
+                        matches!(arm.pat(), Some(ast::Pat::WildcardPat(_)))
+                            && arm.guard().is_none()
+                            && arm.expr().map_or(false, |expr| is_empty_body(&expr))
+                    });
+                    if !next_does_nothing {
+                        arms.push(format!("{pat} => {{}}"));
+                    }
+                }
+            }
+            edit.replace(target, arms.join(&format!("\n{indent}")));
+        },
+    )
+}
+
+/// Splits `(range).contains(&x)` into the range and `x`.
+fn range_contains(cond: &ast::Expr) -> Option<(ast::RangeExpr, ast::NameRef)> {
+    let ast::Expr::MethodCallExpr(call) = cond else { return None };
+    if call.name_ref()?.text() != "contains" {
+        return None;
+    }
+    let ast::Expr::ParenExpr(receiver) = call.receiver()? else { return None };
+    let ast::Expr::RangeExpr(range) = receiver.expr()? else { return None };
+
+    let mut args = call.arg_list()?.args();
+    let ast::Expr::RefExpr(arg) = args.next()? else { return None };
+    if args.next().is_some() || arg.mut_token().is_some() {
+        return None;
+    }
+    let ast::Expr::PathExpr(checked) = arg.expr()? else { return None };
+    Some((range, checked.path()?.as_single_name_ref()?))
+}
+
+/// Whether `expr` is `{}` or `()`.
+fn is_empty_body(expr: &ast::Expr) -> bool {
+    match expr {
+        ast::Expr::BlockExpr(block) => {
+            block.statements().next().is_none() && block.tail_expr().is_none()
+        }
+        ast::Expr::TupleExpr(tuple) => tuple.fields().next().is_none(),
+        _ => false,
+    }
+}
+
+/// Whether `bound` is a, possibly negated, literal that can be used in a range pattern.
+fn is_literal(bound: Option<ast::Expr>) -> bool {
+    match bound {
+        Some(ast::Expr::Literal(_)) => true,
+        Some(ast::Expr::PrefixExpr(prefix)) => {
+            prefix.op_kind() == Some(UnaryOp::Neg)
+                && matches!(prefix.expr(), Some(ast::Expr::Literal(_)))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn fold_range_on_scrutinee() {
+        check_assist(
+            convert_range_to_match_guard,
+            r#"
+fn handle(x: i32) {
+    match x {
+        _ => $0if (-5..=5).contains(&x) { small() }
+    }
+}
+"#,
+            r#"
+fn handle(x: i32) {
+    match x {
+        -5..=5 => small(),
+        _ => {}
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn fold_range_on_binding_keeps_else() {
+        check_assist(
+            convert_range_to_match_guard,
+            r#"
+fn handle(x: u8) -> u8 {
+    match x {
+        n $0=> {
+            if (0..=9).contains(&n) {
+                n
+            } else {
+                let clamped = 9;
+                clamped
+            }
+        }
+    }
+}
+"#,
+            r#"
+fn handle(x: u8) -> u8 {
+    match x {
+        n @ 0..=9 => n,
+        n => {
+            let clamped = 9;
+            clamped
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn exclusive_range_becomes_guard() {
+        cov_mark::check!(convert_range_exclusive_stays_guard);
+        check_assist(
+            convert_range_to_match_guard,
+            r#"
+fn handle(x: Option<u8>) {
+    match x {
+        Some(y) => $0if (0..10).contains(&y) { digit(y) },
+        _ => (),
+    }
+}
+"#,
+            r#"
+fn handle(x: Option<u8>) {
+    match x {
+        Some(y) if (0..10).contains(&y) => digit(y),
+        _ => (),
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn keeps_arm_before_non_empty_wildcard() {
+        check_assist(
+            convert_range_to_match_guard,
+            r#"
+fn handle(x: u8) {
+    match x {
+        n => $0if (0..=9).contains(&n) { digit(n) },
+        _ => other(),
+    }
+}
+"#,
+            r#"
+fn handle(x: u8) {
+    match x {
+        n @ 0..=9 => digit(n),
+        n => {}
+        _ => other(),
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_conditions() {
+        check_assist_not_applicable(
+            convert_range_to_match_guard,
+            r#"
+fn handle(x: u8) {
+    match x {
+        n => $0if n < 10 { digit(n) },
+    }
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use std::ops::Range;
+
+use hir::DescendPreference;
+use ide_db::syntax_helpers::format_string::is_format_string;
+use syntax::{
+    ast::{self, IsString},
+    AstToken, NodeOrToken, SyntaxKind, SyntaxToken, TextRange, T,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_string_format_to_inline_args
+//
+// Moves the arguments of a format macro that are plain identifiers into the format string.
+//
+// ```
+// # //- minicore: fmt
+// fn main() {
+//     print!("{} {}$0", x, y + 1);
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     print!("{x} {}", y + 1);
+// }
+// ```
+pub(crate) fn convert_string_format_to_inline_args(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let fmt_string = ctx.find_token_at_offset::<ast::String>()?;
+    let tt = fmt_string.syntax().parent().and_then(ast::TokenTree::cast)?;
+    let expanded = ast::String::cast(
+        ctx.sema
+            .descend_into_macros_single(DescendPreference::SameKind, fmt_string.syntax().clone()),
+    )?;
+    if !is_format_string(&expanded) {
+        return None;
+    }
+
+    let (args, args_range) = format_args(&tt, fmt_string.syntax())?;
+    let placeholders = placeholders(fmt_string.text(), fmt_string.is_raw())?;
+
+    // Resolve the argument each placeholder refers to, `None` for already inlined ones.
+    let mut next = 0;
+    let mut indices = Vec::with_capacity(placeholders.len());
+    for placeholder in &placeholders {
+        let index = match placeholder.arg {
+            PlaceholderArg::Next => {
+                next += 1;
+                Some(next - 1)
+            }
+            PlaceholderArg::Index(index) => Some(index),
+            PlaceholderArg::Name => None,
+        };
+        if index.map_or(false, |index| index >= args.len()) {
+            return None;
+        }
+        indices.push(index);
+    }
+    // All the uses of an argument are replaced, so an argument is inlined if it is used at all.
+    let inlined: Vec<Option<&str>> = args
+        .iter()
+        .enumerate()
+        .map(|(i, arg)| arg.ident.as_deref().filter(|_| indices.contains(&Some(i))))
+        .collect();
+    if inlined.iter().all(Option::is_none) {
+        cov_mark::hit!(convert_string_format_no_identifier_args);
+        return None;
+    }
+
+    let mut new_fmt = fmt_string.text().to_owned();
+    for (placeholder, index) in placeholders.iter().zip(&indices).rev() {
+        let Some(index) = *index else { continue };
+        let replacement = match (inlined[index], &placeholder.arg) {
+            (Some(ident), _) => ident.to_owned(),
+            (None, PlaceholderArg::Index(_)) => {
+                // Inlined arguments no longer take a position.
+                let removed_before = inlined[..index].iter().filter(|it| it.is_some()).count();
+                (index - removed_before).to_string()
+            }
+            // Implicit positions stay correct: every argument taken by an implicit placeholder
+            // before this one is either inlined everywhere or still an argument.
+            (None, _) => continue,
+        };
+        new_fmt.replace_range(placeholder.range.clone(), &replacement);
+    }
+    let new_args: String = args
+        .iter()
+        .zip(&inlined)
+        .filter(|(_, inlined)| inlined.is_none())
+        .map(|(arg, _)| format!(", {}", arg.text))
+        .collect();
+
+    acc.add(
+        AssistId("convert_string_format_to_inline_args", AssistKind::RefactorRewrite),
+        "Inline format arguments",
+        tt.syntax().text_range(),
+        |edit| {
+            edit.replace(fmt_string.syntax().text_range(), new_fmt);
+            edit.replace(args_range, new_args);
+        },
+    )
+}
+
+struct FormatArg {
+    text: String,
+    /// The identifier this argument consists of, if it can be captured by the format string.
+    ident: Option<String>,
+}
+
+/// Splits the arguments following `fmt_string` in `tt`, also returning the range they span.
+fn format_args(
+    tt: &ast::TokenTree,
+    fmt_string: &SyntaxToken,
+) -> Option<(Vec<FormatArg>, TextRange)> {
+    let text_range = |it: &NodeOrToken<ast::TokenTree, SyntaxToken>| match it {
+        NodeOrToken::Node(node) => node.syntax().text_range(),
+        NodeOrToken::Token(token) => token.text_range(),
+    };
+    let elements: Vec<_> = tt
+        .token_trees_and_tokens()
+        .skip_while(|it| it.as_token() != Some(fmt_string))
+        .skip(1)
+        .filter(|it| it.as_token().map_or(true, |t| t.kind() != SyntaxKind::WHITESPACE))
+        .collect();
+    let (close, elements) = elements.split_last()?;
+    let range = TextRange::new(fmt_string.text_range().end(), text_range(close).start());
+    let elements = match elements {
+        [] => elements,
+        [comma, rest @ ..] if comma.as_token().map(|t| t.kind()) == Some(T![,]) => rest,
+        _ => return None,
+    };
+
+    let mut args = Vec::new();
+    for arg in elements.split(|it| it.as_token().map(|t| t.kind()) == Some(T![,])) {
+        match arg {
+            // A trailing comma
+            [] => continue,
+            // Named arguments would need to be renamed in the format string too.
+            [_, eq, ..] if eq.as_token().map(|t| t.kind()) == Some(T![=]) => return None,
+            _ => {}
+        }
+        let ident = match arg {
+            // Raw identifiers can't be captured.
+            [NodeOrToken::Token(t)]
+                if t.kind() == SyntaxKind::IDENT && !t.text().starts_with("r#") =>
+            {
+                Some(t.text().to_owned())
+            }
+            _ => None,
+        };
+        let arg_range = text_range(&arg[0]).cover(text_range(arg.last()?));
+        let text = tt.syntax().text().slice(arg_range - tt.syntax().text_range().start());
+        args.push(FormatArg { text: text.to_string(), ident });
+    }
+    Some((args, range))
+}
+
+struct Placeholder {
+    /// The range of the argument part of the placeholder, e.g. `0` in `{0:?}`.
+    range: Range<usize>,
+    arg: PlaceholderArg,
+}
+
+enum PlaceholderArg {
+    /// `{}`
+    Next,
+    /// `{0}`
+    Index(usize),
+    /// `{name}`
+    Name,
+}
+
+/// The placeholders of the format string `text`. Returns `None` if widths or precisions are taken
+/// from the arguments, as those take positions that can't be inlined.
+fn placeholders(text: &str, is_raw: bool) -> Option<Vec<Placeholder>> {
+    let mut res = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' if !is_raw => {
+                // Skip escapes, `\u{..}` contains braces.
+                if let Some((_, 'u')) = chars.next() {
+                    chars.by_ref().find(|&(_, c)| c == '}');
+                }
+            }
+            '{' if chars.next_if(|&(_, c)| c == '{').is_some() => {}
+            '}' => {
+                chars.next_if(|&(_, c)| c == '}');
+            }
+            '{' => {
+                let end = chars.by_ref().find(|&(_, c)| c == '}')?.0;
+                let placeholder = &text[i + 1..end];
+                let (arg, spec) = placeholder.split_once(':').unwrap_or((placeholder, ""));
+                if spec.contains('*') || takes_positional_count(spec) {
+                    cov_mark::hit!(convert_string_format_positional_count);
+                    return None;
+                }
+                let arg_kind = if arg.is_empty() {
+                    PlaceholderArg::Next
+                } else if let Ok(index) = arg.parse() {
+                    PlaceholderArg::Index(index)
+                } else {
+                    PlaceholderArg::Name
+                };
+                res.push(Placeholder { range: i + 1..i + 1 + arg.len(), arg: arg_kind });
+            }
+            _ => {}
+        }
+    }
+    Some(res)
+}
+
+/// Whether a format spec has a width or precision like `1$`, taken from a positional argument.
+fn takes_positional_count(spec: &str) -> bool {
+    spec.match_indices('$').any(|(i, _)| {
+        let count = spec[..i].rsplit(|c: char| !c.is_ascii_alphanumeric() && c != '_').next();
+        count.map_or(false, |count| !count.is_empty() && count.bytes().all(|b| b.is_ascii_digit()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn inline_all_identifiers() {
+        check_assist(
+            convert_string_format_to_inline_args,
+            r#"
+//- minicore: fmt
+fn main() {
+    print!("{} and {:?}$0", first, second);
+}
+"#,
+            r#"
+fn main() {
+    print!("{first} and {second:?}");
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn keep_expressions_positional() {
+        check_assist(
+            convert_string_format_to_inline_args,
+            r#"
+//- minicore: fmt
+fn main() {
+    print!("{}, {} and {1}$0", a + 1, b);
+}
+"#,
+            r#"
+fn main() {
+    print!("{}, {b} and {b}", a + 1);
+}
+"#,
+        );
+        check_assist(
+            convert_string_format_to_inline_args,
+            r#"
+//- minicore: fmt
+fn main() {
+    print!("{1} {0}$0", x, y + 1);
+}
+"#,
+            r#"
+fn main() {
+    print!("{0} {x}", y + 1);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_without_identifiers() {
+        cov_mark::check!(convert_string_format_no_identifier_args);
+        check_assist_not_applicable(
+            convert_string_format_to_inline_args,
+            r#"
+//- minicore: fmt
+fn main() {
+    print!("{} {{}}$0", x + 1);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_positional_precision() {
+        cov_mark::check!(convert_string_format_positional_count);
+        check_assist_not_applicable(
+            convert_string_format_to_inline_args,
+            r#"
+//- minicore: fmt
+fn main() {
+    print!("{:.1$}$0", x, precision);
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,285 @@
+use hir::{ModuleDef, PathResolution, Semantics};
+use ide_db::{syntax_helpers::node_ext::preorder_expr, RootDatabase};
+use syntax::{ast, AstNode, SyntaxElement, TextRange, WalkEvent};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_to_const_fn
+//
+// Adds the `const` qualifier to a function whose body only does things a `const fn` can do.
+//
+// ```
+// fn $0double(x: u32) -> u32 {
+//     x * 2
+// }
+// ```
+// ->
+// ```
+// const fn double(x: u32) -> u32 {
+//     x * 2
+// }
+// ```
+pub(crate) fn convert_to_const_fn(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let fn_ = ctx.find_node_at_offset::<ast::Fn>()?;
+    let body = fn_.body()?;
+    let available_range =
+        TextRange::new(fn_.syntax().text_range().start(), body.syntax().text_range().start());
+    if !available_range.contains_range(ctx.selection_trimmed()) {
+        return None;
+    }
+    if fn_.const_token().is_some() || fn_.async_token().is_some() || is_in_trait_or_trait_impl(&fn_)
+    {
+        return None;
+    }
+    if has_mut_ref_param(&fn_) {
+        cov_mark::hit!(convert_to_const_fn_mut_ref_param);
+        return None;
+    }
+    if !is_body_const(&ctx.sema, &body.into()) {
+        cov_mark::hit!(convert_to_const_fn_non_const_body);
+        return None;
+    }
+
+    // `const` comes before `unsafe`, the ABI and `fn`.
+    let insert_before: SyntaxElement = match fn_.unsafe_token() {
+        Some(unsafe_token) => unsafe_token.into(),
+        None => match fn_.abi() {
+            Some(abi) => abi.syntax().clone().into(),
+            None => fn_.fn_token()?.into(),
+        },
+    };
+    acc.add(
+        AssistId("convert_to_const_fn", AssistKind::RefactorRewrite),
+        "Convert to `const fn`",
+        available_range,
+        |builder| builder.insert(insert_before.text_range().start(), "const "),
+    )
+}
+
+/// Functions in traits and trait impls can't be `const`.
+fn is_in_trait_or_trait_impl(fn_: &ast::Fn) -> bool {
+    let Some(item_list) = fn_.syntax().parent().and_then(ast::AssocItemList::cast) else {
+        return false;
+    };
+    match item_list.syntax().parent() {
+        Some(parent) => {
+            ast::Trait::can_cast(parent.kind())
+                || ast::Impl::cast(parent).map_or(false, |impl_| impl_.trait_().is_some())
+        }
+        None => false,
+    }
+}
+
+/// `&mut` in `const fn` parameters is unstable (`const_mut_refs`).
+fn has_mut_ref_param(fn_: &ast::Fn) -> bool {
+    let Some(param_list) = fn_.param_list() else { return false };
+    let mut_self = param_list
+        .self_param()
+        .map_or(false, |it| it.amp_token().is_some() && it.mut_token().is_some());
+    mut_self
+        || param_list
+            .syntax()
+            .descendants()
+            .filter_map(ast::RefType::cast)
+            .any(|it| it.mut_token().is_some())
+}
+
+/// Whether `body` looks like it could be evaluated at compile time. Function and method calls must
+/// resolve to `const fn`s or to tuple struct and variant constructors, and macros, closures, `for`
+/// loops, `?`, `yield`, `.await`, `&mut` borrows and operations on floats (unstable as
+/// `const_mut_refs` and `const_fn_floating_point_arithmetic`) are rejected. Overloaded operators
+/// and other trait calls are not checked.
+fn is_body_const(sema: &Semantics<'_, RootDatabase>, body: &ast::Expr) -> bool {
+    let is_const_fn = |func: Option<hir::Function>| func.map_or(false, |it| it.is_const(sema.db));
+    let mut is_const = true;
+    preorder_expr(body, &mut |ev| {
+        let expr = match ev {
+            WalkEvent::Enter(_) if !is_const => return true,
+            WalkEvent::Enter(expr) => expr,
+            WalkEvent::Leave(_) => return false,
+        };
+        match expr {
+            ast::Expr::CallExpr(call) => {
+                let func = match call.expr() {
+                    Some(ast::Expr::PathExpr(path_expr)) => {
+                        match path_expr.path().and_then(|path| sema.resolve_path(&path)) {
+                            Some(PathResolution::Def(ModuleDef::Function(func))) => Some(func),
+                            // Tuple struct and variant constructors are fine.
+                            Some(PathResolution::Def(
+                                ModuleDef::Adt(_) | ModuleDef::Variant(_),
+                            )) => return false,
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+                is_const &= is_const_fn(func);
+            }
+            ast::Expr::MethodCallExpr(call) => {
+                is_const &= is_const_fn(sema.resolve_method_call(&call));
+            }
+            ast::Expr::BinExpr(bin_expr) => {
+                is_const &= !is_float(sema, bin_expr.lhs()) && !is_float(sema, bin_expr.rhs());
+            }
+            ast::Expr::PrefixExpr(prefix) => is_const &= !is_float(sema, prefix.expr()),
+            ast::Expr::RefExpr(ref_expr) => is_const &= ref_expr.mut_token().is_none(),
+            ast::Expr::MacroExpr(_)
+            | ast::Expr::ClosureExpr(_)
+            | ast::Expr::ForExpr(_)
+            | ast::Expr::TryExpr(_)
+            | ast::Expr::YieldExpr(_)
+            | ast::Expr::AwaitExpr(_) => is_const = false,
+            _ => (),
+        }
+        !is_const
+    });
+    is_const
+}
+
+fn is_float(sema: &Semantics<'_, RootDatabase>, expr: Option<ast::Expr>) -> bool {
+    expr.and_then(|it| sema.type_of_expr(&it)).map_or(false, |it| it.original.is_float())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn trivially_const_fn() {
+        check_assist(
+            convert_to_const_fn,
+            r#"
+pub fn $0clamp(x: i32, max: i32) -> i32 {
+    if x > max {
+        return max;
+    }
+    x
+}
+"#,
+            r#"
+pub const fn clamp(x: i32, max: i32) -> i32 {
+    if x > max {
+        return max;
+    }
+    x
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn calls_to_const_fns() {
+        check_assist(
+            convert_to_const_fn,
+            r#"
+struct Wrapper(u8);
+const fn one() -> u8 { 1 }
+
+pub(crate) unsafe fn$0 wrap() -> Wrapper {
+    Wrapper(one())
+}
+"#,
+            r#"
+struct Wrapper(u8);
+const fn one() -> u8 { 1 }
+
+pub(crate) const unsafe fn wrap() -> Wrapper {
+    Wrapper(one())
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_heap_allocation() {
+        cov_mark::check!(convert_to_const_fn_non_const_body);
+        check_assist_not_applicable(
+            convert_to_const_fn,
+            r#"
+struct Vec<T>(T);
+impl<T> Vec<T> {
+    fn new() -> Self { loop {} }
+}
+
+fn $0empty() -> Vec<u8> {
+    Vec::new()
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_macros() {
+        cov_mark::check!(convert_to_const_fn_non_const_body);
+        check_assist_not_applicable(
+            convert_to_const_fn,
+            r#"
+fn $0names() {
+    let v = vec![1, 2, 3];
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_float_arithmetic() {
+        cov_mark::check!(convert_to_const_fn_non_const_body);
+        check_assist_not_applicable(
+            convert_to_const_fn,
+            r#"
+fn $0f(x: f64) -> f64 { x * 2.0 }
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_mut_ref_param() {
+        cov_mark::check!(convert_to_const_fn_mut_ref_param);
+        check_assist_not_applicable(
+            convert_to_const_fn,
+            r#"
+fn $0inc(x: &mut u32) { *x += 1 }
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_mut_borrow() {
+        cov_mark::check!(convert_to_const_fn_non_const_body);
+        check_assist_not_applicable(
+            convert_to_const_fn,
+            r#"
+fn $0first() -> u8 {
+    let mut a = [1, 2];
+    let r = &mut a;
+    r[0]
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_in_trait_impl() {
+        check_assist_not_applicable(
+            convert_to_const_fn,
+            r#"
+trait Tr { fn f() -> u8; }
+impl Tr for () {
+    fn $0f() -> u8 { 0 }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_const_fn() {
+        check_assist_not_applicable(
+            convert_to_const_fn,
+            r#"
+const fn $0f() -> u8 { 0 }
+"#,
+        );
+    }
+}
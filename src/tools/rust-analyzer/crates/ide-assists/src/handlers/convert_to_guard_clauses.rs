@@ -0,0 +1,222 @@
+use ide_db::syntax_helpers::node_ext::{is_pattern_cond, single_let};
+use itertools::Itertools;
+use syntax::{
+    ast::{
+        self,
+        edit::{AstNodeEdit, IndentLevel},
+        make,
+    },
+    AstNode, SyntaxKind, T,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+use super::convert_to_guarded_return::tail_early_expression;
+
+// Assist: convert_to_guard_clauses
+//
+// Flattens `if let`s nested in each other into a sequence of `let else` guard clauses.
+//
+// ```
+// # //- minicore: option
+// fn main() {
+//     $0if let Some(a) = foo() {
+//         if let Some(b) = bar(a) {
+//             baz(b);
+//         }
+//     }
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let Some(a) = foo() else { return };
+//     let Some(b) = bar(a) else { return };
+//     baz(b);
+// }
+// ```
+pub(crate) fn convert_to_guard_clauses(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let if_expr = ctx.find_node_at_offset::<ast::IfExpr>()?;
+    let if_token_range = if_expr.if_token()?.text_range();
+    let cond_range = if_expr.condition()?.syntax().text_range();
+    if !if_token_range.cover(cond_range).contains_range(ctx.selection_trimmed()) {
+        return None;
+    }
+
+    let mut guards = Vec::new();
+    let mut current = if_expr.clone();
+    let body = loop {
+        guards.push(if_let_parts(&current)?);
+        let body = current.then_branch()?.stmt_list()?;
+        match sole_if_let(&body) {
+            Some(inner) => current = inner,
+            None => break body,
+        }
+    };
+    if guards.len() < 2 {
+        // A single `if let` is handled by `convert_to_guarded_return`.
+        cov_mark::hit!(convert_to_guard_clauses_single_if_let);
+        return None;
+    }
+    // The guards can only exit where the `if let`s would have fallen through to.
+    let early_expression = tail_early_expression(&if_expr)?;
+
+    let target = if_expr.syntax().text_range();
+    acc.add(
+        AssistId("convert_to_guard_clauses", AssistKind::RefactorRewrite),
+        "Convert to guard clauses",
+        target,
+        |edit| {
+            let indent = if_expr.indent_level();
+            // The innermost body was indented once for each `if let` around it.
+            let depth = IndentLevel(guards.len() as u8);
+            let mut buf = guards
+                .into_iter()
+                .map(|(pat, expr)| {
+                    let diverging = make::tail_only_block_expr(early_expression.clone());
+                    make::let_else_stmt(pat, None, expr, diverging).indent(indent).to_string()
+                })
+                .join(&format!("\n{indent}"));
+            let body = body.dedent(depth);
+            let items = body
+                .syntax()
+                .children_with_tokens()
+                .skip(1)
+                .take_while(|it| it.kind() != T!['}'])
+                .join("");
+            buf.push_str(items.trim_end());
+            edit.replace(target, buf);
+        },
+    )
+}
+
+/// The pattern and scrutinee of an `if let` without an `else` branch.
+fn if_let_parts(if_expr: &ast::IfExpr) -> Option<(ast::Pat, ast::Expr)> {
+    if if_expr.else_branch().is_some() {
+        return None;
+    }
+    let cond = if_expr.condition()?;
+    if !is_pattern_cond(cond.clone()) {
+        return None;
+    }
+    let let_expr = single_let(cond)?;
+    Some((let_expr.pat()?, let_expr.expr()?))
+}
+
+/// The `if let` making up all of `body`, if any. Comments next to it would be lost.
+fn sole_if_let(body: &ast::StmtList) -> Option<ast::IfExpr> {
+    if body.statements().next().is_some()
+        || body.syntax().children_with_tokens().any(|it| it.kind() == SyntaxKind::COMMENT)
+    {
+        return None;
+    }
+    match body.tail_expr()? {
+        ast::Expr::IfExpr(inner) => if_let_parts(&inner).map(|_| inner),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn flatten_two_levels() {
+        check_assist(
+            convert_to_guard_clauses,
+            r#"
+//- minicore: option
+fn main() {
+    let x = Some(1);
+    if$0 let Some(a) = x {
+        if let Some(b) = a.checked_add(1) {
+            let c = a + b;
+            // Both are there.
+            print(c);
+        }
+    }
+}
+"#,
+            r#"
+fn main() {
+    let x = Some(1);
+    let Some(a) = x else { return };
+    let Some(b) = a.checked_add(1) else { return };
+    let c = a + b;
+    // Both are there.
+    print(c);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn flatten_in_loop_body() {
+        check_assist(
+            convert_to_guard_clauses,
+            r#"
+//- minicore: option
+fn main() {
+    for x in xs {
+        $0if let Some(a) = x {
+            if let Ok(b) = parse(a) {
+                if let [first, ..] = b {
+                    use_it(first)
+                }
+            }
+        }
+    }
+}
+"#,
+            r#"
+fn main() {
+    for x in xs {
+        let Some(a) = x else { continue };
+        let Ok(b) = parse(a) else { continue };
+        let [first, ..] = b else { continue };
+        use_it(first)
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_single_if_let() {
+        cov_mark::check!(convert_to_guard_clauses_single_if_let);
+        check_assist_not_applicable(
+            convert_to_guard_clauses,
+            r#"
+//- minicore: option
+fn main() {
+    $0if let Some(a) = x {
+        foo(a);
+        if let Some(b) = a {
+            bar(b);
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_code_follows() {
+        check_assist_not_applicable(
+            convert_to_guard_clauses,
+            r#"
+//- minicore: option
+fn main() {
+    $0if let Some(a) = x {
+        if let Some(b) = a {
+            bar(b);
+        }
+    }
+    baz();
+}
+"#,
+        );
+    }
+}
@@ -84,12 +84,6 @@ fn if_expr_to_guarded_return(
     let then_block = if_expr.then_branch()?;
     let then_block = then_block.stmt_list()?;
 
-    let parent_block = if_expr.syntax().parent()?.ancestors().find_map(ast::BlockExpr::cast)?;
-
-    if parent_block.tail_expr()? != if_expr.clone().into() {
-        return None;
-    }
-
     // FIXME: This relies on untyped syntax tree and casts to much. It should be
     // rewritten to use strongly-typed APIs.
 
@@ -104,13 +98,7 @@ fn if_expr_to_guarded_return(
         return None;
     }
 
-    let parent_container = parent_block.syntax().parent()?;
-
-    let early_expression: ast::Expr = match parent_container.kind() {
-        WHILE_EXPR | LOOP_EXPR | FOR_EXPR => make::expr_continue(None),
-        FN => make::expr_return(None),
-        _ => return None,
-    };
+    let early_expression = tail_early_expression(&if_expr)?;
 
     then_block.syntax().first_child_or_token().map(|t| t.kind() == T!['{'])?;
 
@@ -173,6 +161,42 @@ fn if_expr_to_guarded_return(
     )
 }
 
+/// The expression that exits the enclosing function or loop iteration, when nothing runs there
+/// after `if_expr`. That is, when `if_expr` is the tail of the function or loop body, possibly
+/// through the tails of outer `if`s without an `else` branch.
+pub(super) fn tail_early_expression(if_expr: &ast::IfExpr) -> Option<ast::Expr> {
+    let mut if_expr = if_expr.clone();
+    loop {
+        let parent_block = if_expr.syntax().parent()?.ancestors().find_map(ast::BlockExpr::cast)?;
+        if parent_block.tail_expr()? != if_expr.clone().into() {
+            return None;
+        }
+
+        let parent_container = parent_block.syntax().parent()?;
+        match parent_container.kind() {
+            WHILE_EXPR | LOOP_EXPR | FOR_EXPR => return Some(make::expr_continue(None)),
+            FN => return Some(make::expr_return(None)),
+            _ => {}
+        }
+        if_expr = ast::IfExpr::cast(parent_container)?;
+        if if_expr.else_branch().is_some() {
+            return None;
+        }
+    }
+}
+
+/// The expression that exits the function or loop iteration whose body `let_stmt` is directly in.
+pub(super) fn let_stmt_early_expression(let_stmt: &ast::LetStmt) -> Option<ast::Expr> {
+    let parent_block = let_stmt.syntax().parent()?.ancestors().find_map(ast::BlockExpr::cast)?;
+    let parent_container = parent_block.syntax().parent()?;
+
+    match parent_container.kind() {
+        WHILE_EXPR | LOOP_EXPR | FOR_EXPR => Some(make::expr_continue(None)),
+        FN => Some(make::expr_return(None)),
+        _ => None,
+    }
+}
+
 fn let_stmt_to_guarded_return(
     let_stmt: ast::LetStmt,
     acc: &mut Assists,
@@ -196,17 +220,7 @@ fn let_stmt_to_guarded_return(
     let happy_pattern = try_enum.happy_pattern(pat);
     let target = let_stmt.syntax().text_range();
 
-    let early_expression: ast::Expr = {
-        let parent_block =
-            let_stmt.syntax().parent()?.ancestors().find_map(ast::BlockExpr::cast)?;
-        let parent_container = parent_block.syntax().parent()?;
-
-        match parent_container.kind() {
-            WHILE_EXPR | LOOP_EXPR | FOR_EXPR => make::expr_continue(None),
-            FN => make::expr_return(None),
-            _ => return None,
-        }
-    };
+    let early_expression = let_stmt_early_expression(&let_stmt)?;
 
     acc.add(
         AssistId("convert_to_guarded_return", AssistKind::RefactorRewrite),
@@ -720,7 +734,65 @@ fn main() {
     }
 
     #[test]
-    fn ignore_statements_inside_if() {
+    fn convert_nested_tail_if_inside_fn() {
+        check_assist(
+            convert_to_guarded_return,
+            r#"
+fn main() {
+    if false {
+        if$0 true {
+            foo();
+            bar();
+        }
+    }
+}
+"#,
+            r#"
+fn main() {
+    if false {
+        if false {
+            return;
+        }
+        foo();
+        bar();
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_nested_tail_if_inside_loop() {
+        check_assist(
+            convert_to_guarded_return,
+            r#"
+fn main() {
+    loop {
+        if cond() {
+            if$0 true {
+                foo();
+            }
+        }
+    }
+}
+"#,
+            r#"
+fn main() {
+    loop {
+        if cond() {
+            if false {
+                continue;
+            }
+            foo();
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn ignore_nested_if_with_else() {
         check_assist_not_applicable(
             convert_to_guarded_return,
             r#"
@@ -729,7 +801,26 @@ fn main() {
         if$0 true {
             foo();
         }
+    } else {
+        bar();
+    }
+}
+"#,
+        );
     }
+
+    #[test]
+    fn ignore_nested_if_followed_by_statements() {
+        check_assist_not_applicable(
+            convert_to_guarded_return,
+            r#"
+fn main() {
+    if false {
+        if$0 true {
+            foo();
+        }
+    }
+    bar();
 }
 "#,
         );
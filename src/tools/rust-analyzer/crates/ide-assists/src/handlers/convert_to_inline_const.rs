@@ -0,0 +1,158 @@
+use ide_db::{defs::Definition, search::FileReference};
+use itertools::Itertools;
+use syntax::{ast, AstNode, SyntaxKind};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_to_inline_const
+//
+// Inlines a constant that is only used once as an array length, and removes the constant.
+//
+// ```
+// const $0N: usize = 4;
+//
+// fn zeros() -> [u8; N] {
+//     [0; 4]
+// }
+// ```
+// ->
+// ```
+// fn zeros() -> [u8; 4] {
+//     [0; 4]
+// }
+// ```
+pub(crate) fn convert_to_inline_const(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let name = ctx.find_node_at_offset::<ast::Name>()?;
+    let konst = name.syntax().parent().and_then(ast::Const::cast)?;
+    let body = konst.body()?;
+    let def = ctx.sema.to_def(&konst)?;
+
+    let usages = Definition::Const(def).usages(&ctx.sema).all();
+    let Ok((file_id, references)) = usages.into_iter().exactly_one() else {
+        cov_mark::hit!(convert_to_inline_const_not_single_use);
+        return None;
+    };
+    let Ok(FileReference { name: usage, .. }) = references.into_iter().exactly_one() else {
+        cov_mark::hit!(convert_to_inline_const_not_single_use);
+        return None;
+    };
+    if file_id != ctx.file_id() {
+        return None;
+    }
+
+    let usage = usage.as_name_ref()?.syntax().ancestors().find_map(ast::PathExpr::cast)?;
+    if !is_array_len(&usage) {
+        return None;
+    }
+
+    acc.add(
+        AssistId("convert_to_inline_const", AssistKind::RefactorInline),
+        "Inline const into array length",
+        konst.syntax().text_range(),
+        |builder| {
+            let range_to_delete = match konst.syntax().next_sibling_or_token() {
+                Some(s) if matches!(s.kind(), SyntaxKind::WHITESPACE) => {
+                    // Remove following whitespaces too.
+                    konst.syntax().text_range().cover(s.text_range())
+                }
+                _ => konst.syntax().text_range(),
+            };
+            builder.delete(range_to_delete);
+
+            let value = match &body {
+                ast::Expr::Literal(_) | ast::Expr::BlockExpr(_) => body.to_string(),
+                _ => format!("{{ {body} }}"),
+            };
+            builder.replace(usage.syntax().text_range(), value);
+        },
+    )
+}
+
+/// Whether `expr` is the length of an array type (`[T; N]`) or of an array repeat expression
+/// (`[x; N]`).
+fn is_array_len(expr: &ast::PathExpr) -> bool {
+    let Some(parent) = expr.syntax().parent() else { return false };
+    if let Some(const_arg) = ast::ConstArg::cast(parent.clone()) {
+        return const_arg.syntax().parent().and_then(ast::ArrayType::cast).is_some();
+    }
+    match ast::ArrayExpr::cast(parent) {
+        Some(array) => {
+            array.semicolon_token().is_some()
+                && array.exprs().nth(1).is_some_and(|len| len.syntax() == expr.syntax())
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn inline_array_type_len() {
+        check_assist(
+            convert_to_inline_const,
+            r#"
+const $0N: usize = 4;
+
+fn zeros() -> [u8; N] {
+    [0; 4]
+}
+"#,
+            r#"
+fn zeros() -> [u8; 4] {
+    [0; 4]
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn inline_repeat_len_non_literal() {
+        check_assist(
+            convert_to_inline_const,
+            r#"
+fn zeros() {
+    const $0N: usize = 2 * 3;
+    let _ = [0u8; N];
+}
+"#,
+            r#"
+fn zeros() {
+    let _ = [0u8; { 2 * 3 }];
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_used_twice() {
+        cov_mark::check!(convert_to_inline_const_not_single_use);
+        check_assist_not_applicable(
+            convert_to_inline_const,
+            r#"
+const $0N: usize = 4;
+
+fn zeros() -> [u8; N] {
+    [0; N]
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_outside_array_len() {
+        check_assist_not_applicable(
+            convert_to_inline_const,
+            r#"
+const $0N: usize = 4;
+
+fn four() -> usize {
+    N
+}
+"#,
+        );
+    }
+}
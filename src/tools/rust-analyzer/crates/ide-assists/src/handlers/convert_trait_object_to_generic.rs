@@ -0,0 +1,303 @@
+use ide_db::{defs::Definition, search::FileReference, source_change::SourceChangeBuilder};
+use syntax::{
+    ast::{self, edit_in_place::GenericParamsOwnerEdit, make, AstNode},
+    ted, SyntaxNode,
+};
+
+use crate::{utils::suggest_name, AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_trait_object_to_generic
+//
+// Replaces a `Box<dyn Trait>` function parameter with a generic parameter bounded by the trait,
+// taken either by value or in a `Box`.
+//
+// ```
+// # //- minicore: deref
+// # #[lang = "owned_box"]
+// # struct Box<T: ?Sized>(*mut T);
+// # impl<T: ?Sized> core::ops::Deref for Box<T> {
+// #     type Target = T;
+// #     fn deref(&self) -> &T { loop {} }
+// # }
+// trait Shape { fn area(&self) -> f64; }
+// fn report(shape: Box<dyn $0Shape>) -> f64 {
+//     shape.area()
+// }
+// ```
+// ->
+// ```
+// # #[lang = "owned_box"]
+// # struct Box<T: ?Sized>(*mut T);
+// # impl<T: ?Sized> core::ops::Deref for Box<T> {
+// #     type Target = T;
+// #     fn deref(&self) -> &T { loop {} }
+// # }
+// trait Shape { fn area(&self) -> f64; }
+// fn report<S: Shape>(shape: S) -> f64 {
+//     shape.area()
+// }
+// ```
+pub(crate) fn convert_trait_object_to_generic(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let dyn_trait = ctx.find_node_at_offset::<ast::DynTraitType>()?;
+    let box_ty = boxed_dyn_trait(&dyn_trait)?;
+    let param = box_ty.syntax().parent().and_then(ast::Param::cast)?;
+    let fn_ = param.syntax().ancestors().find_map(ast::Fn::cast)?;
+    let bounds = dyn_trait.type_bound_list()?;
+
+    // Calling methods works the same on a generic, but e.g. pushing the box into a collection of
+    // trait objects needs it to stay one.
+    let ast::Pat::IdentPat(ident_pat) = param.pat()? else { return None };
+    let local = ctx.sema.to_def(&ident_pat)?;
+    let box_adt = ctx.sema.resolve_type(&ast::Type::PathType(box_ty.clone()))?.as_adt()?;
+    let usages = Definition::Local(local).usages(&ctx.sema).all();
+    let mut takes_box = false;
+    for reference in usages.iter().flat_map(|(_, references)| references) {
+        let Some(method) = method_called_on(ctx, reference) else {
+            cov_mark::hit!(convert_trait_object_to_generic_dynamic_use);
+            return None;
+        };
+        // A method taking `self: Box<Self>` can't be called on the unboxed value.
+        let self_ty = method.self_param(ctx.db()).map(|self_param| self_param.ty(ctx.db()));
+        takes_box |= self_ty.and_then(|ty| ty.as_adt()) == Some(box_adt);
+    }
+
+    let target = box_ty.syntax().text_range();
+    if !takes_box {
+        acc.add(
+            AssistId("convert_trait_object_to_generic", AssistKind::RefactorRewrite),
+            "Convert trait object to generic taken by value",
+            target,
+            |edit| replace_with_generic(edit, &fn_, box_ty.syntax(), &bounds),
+        );
+    }
+    acc.add(
+        AssistId("convert_trait_object_to_generic", AssistKind::RefactorRewrite),
+        "Convert trait object to boxed generic",
+        target,
+        |edit| replace_with_generic(edit, &fn_, dyn_trait.syntax(), &bounds),
+    )
+}
+
+/// The `Box<dyn Trait>` type `dyn_trait` is the only generic argument of.
+fn boxed_dyn_trait(dyn_trait: &ast::DynTraitType) -> Option<ast::PathType> {
+    let box_ty = dyn_trait.syntax().ancestors().find_map(ast::PathType::cast)?;
+    let segment = box_ty.path()?.segment()?;
+    if segment.name_ref()?.text() != "Box" {
+        return None;
+    }
+    let mut args = segment.generic_arg_list()?.generic_args();
+    match (args.next()?, args.next()) {
+        (ast::GenericArg::TypeArg(arg), None) if arg.ty()?.syntax() == dyn_trait.syntax() => {
+            Some(box_ty)
+        }
+        _ => None,
+    }
+}
+
+/// The method `reference` is the receiver of, if it is one.
+fn method_called_on(ctx: &AssistContext<'_>, reference: &FileReference) -> Option<hir::Function> {
+    let path_expr = reference
+        .name
+        .as_name_ref()
+        .and_then(|name_ref| name_ref.syntax().ancestors().find_map(ast::PathExpr::cast))?;
+    let call = path_expr.syntax().parent().and_then(ast::MethodCallExpr::cast)?;
+    if call.receiver()?.syntax() != path_expr.syntax() {
+        return None;
+    }
+    ctx.sema.resolve_method_call(&call)
+}
+
+/// Adds a type parameter with `bounds` to `fn_`, and replaces `replaced` with it.
+fn replace_with_generic(
+    edit: &mut SourceChangeBuilder,
+    fn_: &ast::Fn,
+    replaced: &SyntaxNode,
+    bounds: &ast::TypeBoundList,
+) {
+    let replaced = edit.make_syntax_mut(replaced.clone());
+    let fn_ = edit.make_mut(fn_.clone());
+    let generic_param_list = fn_.get_or_create_generic_param_list();
+
+    // Named after the trait, like the parameters `introduce_named_generic` adds.
+    let initial = bounds
+        .bounds()
+        .find_map(|bound| match bound.ty()? {
+            ast::Type::PathType(ty) => ty.path()?.segment()?.name_ref(),
+            _ => None,
+        })
+        .and_then(|name| name.text().chars().next())
+        .unwrap_or('T');
+    let name = suggest_name::for_unique_generic_name(
+        initial.encode_utf8(&mut [0; 4]),
+        &generic_param_list,
+    );
+
+    let type_param = make::type_param(make::name(&name), Some(bounds.clone())).clone_for_update();
+    ted::replace(replaced, make::ty(&name).clone_for_update().syntax());
+    generic_param_list.add_generic_param(type_param.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{
+        check_assist, check_assist_by_label, check_assist_not_applicable,
+        check_assist_not_applicable_by_label,
+    };
+
+    use super::*;
+
+    #[test]
+    fn convert_to_generic_by_value() {
+        check_assist(
+            convert_trait_object_to_generic,
+            r#"
+//- minicore: deref
+#[lang = "owned_box"]
+struct Box<T: ?Sized>(*mut T);
+impl<T: ?Sized> core::ops::Deref for Box<T> {
+    type Target = T;
+    fn deref(&self) -> &T { loop {} }
+}
+trait Draw { fn draw(&self); }
+fn render<C>(ctx: C, item: Box<$0dyn Draw + Send>) {
+    item.draw();
+}
+"#,
+            r#"
+#[lang = "owned_box"]
+struct Box<T: ?Sized>(*mut T);
+impl<T: ?Sized> core::ops::Deref for Box<T> {
+    type Target = T;
+    fn deref(&self) -> &T { loop {} }
+}
+trait Draw { fn draw(&self); }
+fn render<C, D: Draw + Send>(ctx: C, item: D) {
+    item.draw();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_to_boxed_generic() {
+        check_assist_by_label(
+            convert_trait_object_to_generic,
+            r#"
+//- minicore: deref
+#[lang = "owned_box"]
+struct Box<T: ?Sized>(*mut T);
+impl<T: ?Sized> core::ops::Deref for Box<T> {
+    type Target = T;
+    fn deref(&self) -> &T { loop {} }
+}
+trait Draw { fn draw(&self); }
+fn render<D>(first: D, item: Box<dyn $0Draw>) {
+    item.draw();
+}
+"#,
+            r#"
+#[lang = "owned_box"]
+struct Box<T: ?Sized>(*mut T);
+impl<T: ?Sized> core::ops::Deref for Box<T> {
+    type Target = T;
+    fn deref(&self) -> &T { loop {} }
+}
+trait Draw { fn draw(&self); }
+fn render<D, D0: Draw>(first: D, item: Box<D0>) {
+    item.draw();
+}
+"#,
+            "Convert trait object to boxed generic",
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_stored_as_trait_object() {
+        cov_mark::check!(convert_trait_object_to_generic_dynamic_use);
+        check_assist_not_applicable(
+            convert_trait_object_to_generic,
+            r#"
+//- minicore: deref
+#[lang = "owned_box"]
+struct Box<T: ?Sized>(*mut T);
+impl<T: ?Sized> core::ops::Deref for Box<T> {
+    type Target = T;
+    fn deref(&self) -> &T { loop {} }
+}
+struct Vec<T>(T);
+impl<T> Vec<T> { fn push(&mut self, value: T) {} }
+trait Draw { fn draw(&self); }
+fn add(items: &mut Vec<Box<dyn Draw>>, item: Box<dyn $0Draw>) {
+    item.draw();
+    items.push(item);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_trait_objects() {
+        check_assist_not_applicable(
+            convert_trait_object_to_generic,
+            r#"
+trait Draw { fn draw(&self); }
+fn render(item: &dyn $0Draw) {
+    item.draw();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn only_boxed_generic_for_box_self_method() {
+        check_assist_by_label(
+            convert_trait_object_to_generic,
+            r#"
+//- minicore: deref
+#[lang = "owned_box"]
+struct Box<T: ?Sized>(*mut T);
+impl<T: ?Sized> core::ops::Deref for Box<T> {
+    type Target = T;
+    fn deref(&self) -> &T { loop {} }
+}
+trait Task { fn run(self: Box<Self>); }
+fn spawn(task: Box<dyn $0Task>) {
+    task.run();
+}
+"#,
+            r#"
+#[lang = "owned_box"]
+struct Box<T: ?Sized>(*mut T);
+impl<T: ?Sized> core::ops::Deref for Box<T> {
+    type Target = T;
+    fn deref(&self) -> &T { loop {} }
+}
+trait Task { fn run(self: Box<Self>); }
+fn spawn<T: Task>(task: Box<T>) {
+    task.run();
+}
+"#,
+            "Convert trait object to boxed generic",
+        );
+        check_assist_not_applicable_by_label(
+            convert_trait_object_to_generic,
+            r#"
+//- minicore: deref
+#[lang = "owned_box"]
+struct Box<T: ?Sized>(*mut T);
+impl<T: ?Sized> core::ops::Deref for Box<T> {
+    type Target = T;
+    fn deref(&self) -> &T { loop {} }
+}
+trait Task { fn run(self: Box<Self>); }
+fn spawn(task: Box<dyn $0Task>) {
+    task.run();
+}
+"#,
+            "Convert trait object to generic taken by value",
+        );
+    }
+}
@@ -739,6 +739,33 @@ fn foo(mut i: isize) -> FooResult {
         )
     }
 
+    #[test]
+    fn body_doesnt_wrap_closure_returns() {
+        check_assist(
+            convert_tuple_return_type_to_struct,
+            r#"
+fn parse(n: i32) -> (i32, $0String) {
+    let fallback = || return (0, String::new());
+    if n < 0 {
+        return (n, String::new());
+    }
+    (n, String::new())
+}
+"#,
+            r#"
+struct ParseResult(i32, String);
+
+fn parse(n: i32) -> ParseResult {
+    let fallback = || return (0, String::new());
+    if n < 0 {
+        return ParseResult(n, String::new());
+    }
+    ParseResult(n, String::new())
+}
+"#,
+        )
+    }
+
     #[test]
     fn body_doesnt_wrap_identifier() {
         check_assist(
@@ -183,6 +183,37 @@ fn foo(a: Option<u32>) -> bool {
         );
     }
 
+    #[test]
+    fn not_applicable_non_bool_arms() {
+        check_assist_not_applicable(
+            convert_two_arm_bool_match_to_matches_macro,
+            r#"
+fn foo(a: Option<u32>) -> u32 {
+    match a$0 {
+        Some(_) => 1,
+        _ => 0
+    }
+}
+        "#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_inverted_non_literal_arm() {
+        cov_mark::check!(non_invert_bool_literal_arms);
+        check_assist_not_applicable(
+            convert_two_arm_bool_match_to_matches_macro,
+            r#"
+fn foo(a: Option<u32>, b: bool) -> bool {
+    match a$0 {
+        Some(_) => false,
+        _ => b
+    }
+}
+        "#,
+        );
+    }
+
     #[test]
     fn convert_simple_case() {
         check_assist(
@@ -0,0 +1,181 @@
+use syntax::{
+    ast::{self, HasLoopBody},
+    AstNode, TextRange, T,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_while_true_to_loop
+//
+// Replaces a `while true` with a `loop`.
+//
+// ```
+// fn main() {
+//     $0while true {
+//         foo();
+//     }
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     loop {
+//         foo();
+//     }
+// }
+// ```
+pub(crate) fn convert_while_true_to_loop(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let while_kw = ctx.find_token_syntax_at_offset(T![while])?;
+    let while_expr = while_kw.parent().and_then(ast::WhileExpr::cast)?;
+    let cond = while_expr.condition()?;
+    if !is_true_literal(&cond) {
+        return None;
+    }
+
+    acc.add(
+        AssistId("convert_while_true_to_loop", AssistKind::RefactorRewrite),
+        "Convert `while true` to `loop`",
+        while_expr.syntax().text_range(),
+        |edit| {
+            let range =
+                TextRange::new(while_kw.text_range().start(), cond.syntax().text_range().end());
+            edit.replace(range, "loop");
+        },
+    )
+}
+
+// Assist: convert_loop_to_while_true
+//
+// Replaces a `loop` with a `while true`.
+//
+// ```
+// fn main() {
+//     $0loop {
+//         foo();
+//     }
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     while true {
+//         foo();
+//     }
+// }
+// ```
+pub(crate) fn convert_loop_to_while_true(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let loop_kw = ctx.find_token_syntax_at_offset(T![loop])?;
+    let loop_expr = loop_kw.parent().and_then(ast::LoopExpr::cast)?;
+    // A `while` evaluates to `()`, so the loop can't be used for its value.
+    if !loop_expr.syntax().parent().map_or(false, |it| ast::ExprStmt::can_cast(it.kind())) {
+        return None;
+    }
+    let body = loop_expr.loop_body()?;
+    if body.syntax().descendants().filter_map(ast::BreakExpr::cast).any(|it| it.expr().is_some()) {
+        cov_mark::hit!(convert_loop_to_while_true_break_with_value);
+        return None;
+    }
+
+    acc.add(
+        AssistId("convert_loop_to_while_true", AssistKind::RefactorRewrite),
+        "Convert `loop` to `while true`",
+        loop_expr.syntax().text_range(),
+        |edit| edit.replace(loop_kw.text_range(), "while true"),
+    )
+}
+
+fn is_true_literal(expr: &ast::Expr) -> bool {
+    match expr {
+        ast::Expr::Literal(lit) => matches!(lit.kind(), ast::LiteralKind::Bool(true)),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn convert_while_true() {
+        check_assist(
+            convert_while_true_to_loop,
+            r#"
+fn main() {
+    'outer: while$0 true {
+        if done() {
+            break 'outer;
+        }
+    }
+}
+"#,
+            r#"
+fn main() {
+    'outer: loop {
+        if done() {
+            break 'outer;
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_conditions() {
+        check_assist_not_applicable(
+            convert_while_true_to_loop,
+            r#"
+const ALWAYS: bool = true;
+fn main() {
+    while$0 ALWAYS {}
+}
+"#,
+        );
+        check_assist_not_applicable(
+            convert_while_true_to_loop,
+            r#"
+fn main() {
+    while$0 !false {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_loop() {
+        check_assist(
+            convert_loop_to_while_true,
+            r#"
+fn main() {
+    loop$0 {
+        foo();
+    }
+}
+"#,
+            r#"
+fn main() {
+    while true {
+        foo();
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_loop_with_value() {
+        cov_mark::check!(convert_loop_to_while_true_break_with_value);
+        check_assist_not_applicable(
+            convert_loop_to_while_true,
+            r#"
+fn main() {
+    loop$0 {
+        break 92;
+    };
+}
+"#,
+        );
+    }
+}
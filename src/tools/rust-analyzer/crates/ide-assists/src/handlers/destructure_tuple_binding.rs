@@ -71,6 +71,12 @@ pub(crate) fn destructure_tuple_binding_impl(
         );
     }
 
+    // Destructuring in place would leave the tuple that is passed along undefined.
+    if data.passed_by_value {
+        cov_mark::hit!(destructure_tuple_passed_by_value);
+        return Some(());
+    }
+
     acc.add(
         AssistId("destructure_tuple_binding", AssistKind::RefactorRewrite),
         if with_sub_pattern { "Destructure tuple in place" } else { "Destructure tuple" },
@@ -135,7 +141,23 @@ fn collect_data(ident_pat: IdentPat, ctx: &AssistContext<'_>) -> Option<TupleDat
         .map(|i| generate_name(ctx, i, &name, &ident_pat, &usages))
         .collect::<Vec<_>>();
 
-    Some(TupleData { ident_pat, ref_type, field_names, usages })
+    let passed_by_value = usages.as_ref().map_or(false, |usages| {
+        usages.iter().flat_map(|(_, refs)| refs).any(is_passed_as_argument)
+    });
+
+    Some(TupleData { ident_pat, ref_type, field_names, usages, passed_by_value })
+}
+
+/// Whether `usage` is the whole tuple, given as an argument to a function or method call.
+fn is_passed_as_argument(usage: &FileReference) -> bool {
+    usage
+        .name
+        .syntax()
+        .ancestors()
+        .skip_while(|s| !ast::PathExpr::can_cast(s.kind()))
+        .skip(1) // PATH_EXPR
+        .find(|s| !ast::ParenExpr::can_cast(s.kind()))
+        .map_or(false, |node| ast::ArgList::can_cast(node.kind()))
 }
 
 fn generate_name(
@@ -158,6 +180,8 @@ struct TupleData {
     ref_type: Option<RefType>,
     field_names: Vec<String>,
     usages: Option<UsageSearchResult>,
+    /// Whether the whole tuple is passed by value to some call.
+    passed_by_value: bool,
 }
 fn edit_tuple_assignment(
     ctx: &AssistContext<'_>,
@@ -497,6 +521,45 @@ fn main() {
         )
     }
 
+    #[test]
+    fn dont_trigger_when_passed_by_value() {
+        cov_mark::check!(destructure_tuple_passed_by_value);
+        check_assist_not_applicable(
+            assist,
+            r#"
+fn sum(t: (usize, usize, usize)) -> usize { t.0 + t.1 + t.2 }
+fn main() {
+    let $0tup = (1,2,3);
+    let v = tup.0;
+    sum(tup);
+}
+            "#,
+        )
+    }
+
+    #[test]
+    fn replace_indices_passed_as_arguments() {
+        check_assist(
+            assist,
+            r#"
+fn double(x: usize) -> usize { x * 2 }
+fn main() {
+    let $0tup = (1,2,3);
+    let v = double(tup.0) + double((tup).2);
+    let w = tup.1;
+}
+            "#,
+            r#"
+fn double(x: usize) -> usize { x * 2 }
+fn main() {
+    let ($0_0, _1, _2) = (1,2,3);
+    let v = double(_0) + double(_2);
+    let w = _1;
+}
+            "#,
+        )
+    }
+
     #[test]
     fn handle_invalid_index() {
         check_assist(
@@ -2919,6 +2919,65 @@ fn $0fun_name(n: i32) -> i32 {
         );
     }
 
+    #[test]
+    fn one_arg_from_loop_no_output() {
+        check_assist(
+            extract_function,
+            r#"
+fn foo() {
+    let n = 3;
+    $0for i in 0..n {
+        let m = i * 2;
+    }$0
+}
+"#,
+            r#"
+fn foo() {
+    let n = 3;
+    fun_name(n);
+}
+
+fn $0fun_name(n: i32) {
+    for i in 0..n {
+        let m = i * 2;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn one_arg_from_loop_single_output() {
+        check_assist(
+            extract_function,
+            r#"
+fn foo() {
+    let n = 3;
+    $0let mut sum = 0;
+    for i in 0..n {
+        sum += i;
+    }$0
+    let k = sum;
+}
+"#,
+            r#"
+fn foo() {
+    let n = 3;
+    let sum = fun_name(n);
+    let k = sum;
+}
+
+fn $0fun_name(n: i32) -> i32 {
+    let mut sum = 0;
+    for i in 0..n {
+        sum += i;
+    }
+    sum
+}
+"#,
+        );
+    }
+
     #[test]
     fn variable_defined_inside_and_used_after_mutably_no_ret() {
         check_assist(
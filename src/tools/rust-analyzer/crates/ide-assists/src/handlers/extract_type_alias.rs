@@ -1,8 +1,8 @@
 use either::Either;
-use ide_db::syntax_helpers::node_ext::walk_ty;
+use ide_db::{source_change::SourceChangeBuilder, syntax_helpers::node_ext::walk_ty};
 use syntax::{
     ast::{self, edit::IndentLevel, make, AstNode, HasGenericParams, HasName},
-    ted,
+    ted, SyntaxNode,
 };
 
 use crate::{AssistContext, AssistId, AssistKind, Assists};
@@ -39,6 +39,36 @@ pub(crate) fn extract_type_alias(acc: &mut Assists, ctx: &AssistContext<'_>) ->
     );
     let target = ty.syntax().text_range();
 
+    let mut known_generics = match item.generic_param_list() {
+        Some(it) => it.generic_params().collect(),
+        None => Vec::new(),
+    };
+    if let Some(it) = assoc_owner.as_ref().and_then(|it| match it {
+        Either::Left(it) => it.generic_param_list(),
+        Either::Right(it) => it.generic_param_list(),
+    }) {
+        known_generics.extend(it.generic_params());
+    }
+    let generics = collect_used_generics(&ty, &known_generics);
+    let generic_params = generics.map(|it| make::generic_param_list(it.into_iter().cloned()));
+
+    // Other occurrences may be under different generic parameters, so only offer to replace them
+    // all when the type doesn't use any. The same text can name another type in a nested scope, so
+    // occurrences are looked for in the enclosing item and compared by their resolved types.
+    let resolved_ty = ctx.sema.resolve_type(&ty).filter(|it| !it.contains_unknown());
+    let occurrences: Vec<_> = match (&generic_params, resolved_ty) {
+        (None, Some(resolved_ty)) => node
+            .descendants()
+            .filter_map(ast::Type::cast)
+            .filter(|it| {
+                ctx.sema.resolve_type(it).map_or(false, |it| {
+                    !it.contains_unknown() && it.could_unify_with(ctx.db(), &resolved_ty)
+                })
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
     acc.add(
         AssistId("extract_type_alias", AssistKind::RefactorExtract),
         "Extract type as type alias",
@@ -46,48 +76,59 @@ pub(crate) fn extract_type_alias(acc: &mut Assists, ctx: &AssistContext<'_>) ->
         |edit| {
             let node = edit.make_syntax_mut(node.clone());
             let target_ty = edit.make_mut(ty.clone());
-
-            let mut known_generics = match item.generic_param_list() {
-                Some(it) => it.generic_params().collect(),
-                None => Vec::new(),
-            };
-            if let Some(it) = assoc_owner.as_ref().and_then(|it| match it {
-                Either::Left(it) => it.generic_param_list(),
-                Either::Right(it) => it.generic_param_list(),
-            }) {
-                known_generics.extend(it.generic_params());
-            }
-            let generics = collect_used_generics(&ty, &known_generics);
-            let generic_params =
-                generics.map(|it| make::generic_param_list(it.into_iter().cloned()));
-
-            // Replace original type with the alias
-            let ty_args = generic_params
-                .as_ref()
-                .map_or(String::new(), |it| it.to_generic_args().to_string());
-            // FIXME: replace with a `ast::make` constructor
-            let new_ty = make::ty(&format!("Type{ty_args}")).clone_for_update();
-            ted::replace(target_ty.syntax(), new_ty.syntax());
-
-            // Insert new alias
-            let indent = IndentLevel::from_node(&node);
-            let ty_alias = make::ty_alias("Type", generic_params, None, None, Some((ty, None)))
-                .clone_for_update();
-            ted::insert_all(
-                ted::Position::before(node),
-                vec![
-                    ty_alias.syntax().clone().into(),
-                    make::tokens::whitespace(&format!("\n\n{indent}")).into(),
-                ],
-            );
-
-            if let Some(cap) = ctx.config.snippet_cap {
-                if let Some(name) = ty_alias.name() {
-                    edit.add_tabstop_before(cap, name);
-                }
-            }
+            insert_alias(ctx, edit, node, &ty, vec![target_ty], generic_params.clone());
         },
-    )
+    );
+    if occurrences.len() > 1 {
+        acc.add(
+            AssistId("extract_type_alias", AssistKind::RefactorExtract),
+            "Extract type as type alias and replace all occurrences",
+            target,
+            |edit| {
+                let node = edit.make_syntax_mut(node.clone());
+                let occurrences = occurrences.into_iter().map(|it| edit.make_mut(it)).collect();
+                insert_alias(ctx, edit, node, &ty, occurrences, None);
+            },
+        );
+    }
+    Some(())
+}
+
+/// Inserts an alias for `ty` before `node`, and replaces each of `occurrences` with it.
+fn insert_alias(
+    ctx: &AssistContext<'_>,
+    edit: &mut SourceChangeBuilder,
+    node: SyntaxNode,
+    ty: &ast::Type,
+    occurrences: Vec<ast::Type>,
+    generic_params: Option<ast::GenericParamList>,
+) {
+    // Replace original type with the alias
+    let ty_args =
+        generic_params.as_ref().map_or(String::new(), |it| it.to_generic_args().to_string());
+    for occurrence in occurrences {
+        // FIXME: replace with a `ast::make` constructor
+        let new_ty = make::ty(&format!("Type{ty_args}")).clone_for_update();
+        ted::replace(occurrence.syntax(), new_ty.syntax());
+    }
+
+    // Insert new alias
+    let indent = IndentLevel::from_node(&node);
+    let ty_alias = make::ty_alias("Type", generic_params, None, None, Some((ty.clone(), None)))
+        .clone_for_update();
+    ted::insert_all(
+        ted::Position::before(node),
+        vec![
+            ty_alias.syntax().clone().into(),
+            make::tokens::whitespace(&format!("\n\n{indent}")).into(),
+        ],
+    );
+
+    if let Some(cap) = ctx.config.snippet_cap {
+        if let Some(name) = ty_alias.name() {
+            edit.add_tabstop_before(cap, name);
+        }
+    }
 }
 
 fn collect_used_generics<'gp>(
@@ -190,7 +231,7 @@ fn find_lifetime(text: &str) -> impl Fn(&&ast::GenericParam) -> bool + '_ {
 
 #[cfg(test)]
 mod tests {
-    use crate::tests::{check_assist, check_assist_not_applicable};
+    use crate::tests::{check_assist, check_assist_by_label, check_assist_not_applicable};
 
     use super::*;
 
@@ -388,4 +429,91 @@ struct Foo<T, const N: usize>
             "#,
         );
     }
+
+    #[test]
+    fn extract_map_type() {
+        check_assist_by_label(
+            extract_type_alias,
+            r#"
+struct HashMap<K, V>(K, V);
+fn index(entries: $0HashMap<String, Vec<(u32, String)>>$0) {}
+fn lookup(index: &HashMap<String, Vec<(u32, String)>>) {}
+"#,
+            r#"
+struct HashMap<K, V>(K, V);
+type $0Type = HashMap<String, Vec<(u32, String)>>;
+
+fn index(entries: Type) {}
+fn lookup(index: &HashMap<String, Vec<(u32, String)>>) {}
+"#,
+            "Extract type as type alias",
+        );
+    }
+
+    #[test]
+    fn replace_all_occurrences() {
+        check_assist_by_label(
+            extract_type_alias,
+            r#"
+struct HashMap<K, V>(K, V);
+struct Entry;
+fn merge(a: $0HashMap<u32, (u8, Entry)>$0, b: &HashMap<u32, (u8, Entry)>) -> HashMap<u32, (u8, Entry)> {
+    let merged: HashMap<u32, (u8, self::Entry)> = a;
+    merged
+}
+fn lookup(index: &HashMap<u32, (u8, Entry)>) {}
+"#,
+            r#"
+struct HashMap<K, V>(K, V);
+struct Entry;
+type $0Type = HashMap<u32, (u8, Entry)>;
+
+fn merge(a: Type, b: &Type) -> Type {
+    let merged: Type = a;
+    merged
+}
+fn lookup(index: &HashMap<u32, (u8, Entry)>) {}
+"#,
+            "Extract type as type alias and replace all occurrences",
+        );
+    }
+
+    #[test]
+    fn replace_all_skips_shadowed_types() {
+        check_assist_by_label(
+            extract_type_alias,
+            r#"
+struct Entry;
+fn f(a: $0Entry$0, b: Entry) {
+    struct Entry;
+    let c: Entry = Entry;
+}
+"#,
+            r#"
+struct Entry;
+type $0Type = Entry;
+
+fn f(a: Type, b: Type) {
+    struct Entry;
+    let c: Entry = Entry;
+}
+"#,
+            "Extract type as type alias and replace all occurrences",
+        );
+    }
+
+    #[test]
+    fn replace_all_not_offered_for_generic_types() {
+        check_assist(
+            extract_type_alias,
+            r#"
+fn f<T>(a: $0Vec<T>$0, b: Vec<T>) {}
+"#,
+            r#"
+type $0Type<T> = Vec<T>;
+
+fn f<T>(a: Type<T>, b: Vec<T>) {}
+"#,
+        );
+    }
 }
@@ -210,7 +210,7 @@ pub(crate) fn generate_delegate_methods(acc: &mut Assists, ctx: &AssistContext<'
 
 #[cfg(test)]
 mod tests {
-    use crate::tests::{check_assist, check_assist_not_applicable};
+    use crate::tests::{check_assist, check_assist_by_label, check_assist_not_applicable};
 
     use super::*;
 
@@ -673,6 +673,46 @@ impl Foo {
         );
     }
 
+    #[test]
+    fn test_generate_delegate_len_of_vec_field() {
+        check_assist_by_label(
+            generate_delegate_methods,
+            r#"
+struct Vec<T>(T);
+impl<T> Vec<T> {
+    pub fn len(&self) -> usize {
+        0
+    }
+    pub fn push(&mut self, value: T) {}
+}
+
+struct Stack<T> {
+    inner$0: Vec<T>,
+}
+"#,
+            r#"
+struct Vec<T>(T);
+impl<T> Vec<T> {
+    pub fn len(&self) -> usize {
+        0
+    }
+    pub fn push(&mut self, value: T) {}
+}
+
+struct Stack<T> {
+    inner: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    $0pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+"#,
+            "Generate delegate for `inner.len()`",
+        );
+    }
+
     #[test]
     fn test_generate_delegate_visibility() {
         check_assist_not_applicable(
@@ -39,47 +39,74 @@
 // ```
 pub(crate) fn generate_enum_is_method(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
     let variant = ctx.find_node_at_offset::<ast::Variant>()?;
-    let variant_name = variant.name()?;
-    let parent_enum = ast::Adt::Enum(variant.parent_enum());
-    let pattern_suffix = match variant.kind() {
-        ast::StructKind::Record(_) => " { .. }",
-        ast::StructKind::Tuple(_) => "(..)",
-        ast::StructKind::Unit => "",
-    };
-
+    let ast_enum = variant.parent_enum();
+    let parent_enum = ast::Adt::Enum(ast_enum.clone());
     let enum_name = parent_enum.name()?;
-    let enum_lowercase_name = to_lower_snake_case(&enum_name.to_string()).replace('_', " ");
-    let fn_name = format!("is_{}", &to_lower_snake_case(&variant_name.text()));
+    let vis = parent_enum.visibility().map_or(String::new(), |v| format!("{v} "));
+
+    let (fn_name, method) = is_method(&enum_name, &vis, &variant)?;
 
     // Return early if we've found an existing new fn
-    let impl_def = find_struct_impl(ctx, &parent_enum, &[fn_name.clone()])?;
+    let impl_def = find_struct_impl(ctx, &parent_enum, &[fn_name])?;
 
+    let group =
+        GroupLabel("Generate an `is_`,`as_`, or `try_into_` for this enum variant".to_owned());
     let target = variant.syntax().text_range();
     acc.add_group(
-        &GroupLabel("Generate an `is_`,`as_`, or `try_into_` for this enum variant".to_owned()),
+        &group,
         AssistId("generate_enum_is_method", AssistKind::Generate),
         "Generate an `is_` method for this enum variant",
         target,
-        |builder| {
-            let vis = parent_enum.visibility().map_or(String::new(), |v| format!("{v} "));
-            let method = format!(
-                "    /// Returns `true` if the {enum_lowercase_name} is [`{variant_name}`].
+        |builder| add_method_to_adt(builder, &parent_enum, impl_def.clone(), &method),
+    );
+
+    // Skip the predicates that already exist, and only offer this if there is more than the one
+    // under the cursor to generate.
+    let methods: Vec<_> = ast_enum
+        .variant_list()?
+        .variants()
+        .filter_map(|variant| is_method(&enum_name, &vis, &variant))
+        .filter(|(fn_name, _)| find_struct_impl(ctx, &parent_enum, &[fn_name.clone()]).is_some())
+        .map(|(_, method)| method)
+        .collect();
+    if methods.len() < 2 {
+        return Some(());
+    }
+    acc.add_group(
+        &group,
+        AssistId("generate_enum_is_method", AssistKind::Generate),
+        "Generate `is_` methods for all enum variants",
+        target,
+        |builder| add_method_to_adt(builder, &parent_enum, impl_def, &methods.join("\n\n")),
+    )
+}
+
+/// Returns the name and the source of the `is_` method for `variant`.
+fn is_method(enum_name: &ast::Name, vis: &str, variant: &ast::Variant) -> Option<(String, String)> {
+    let variant_name = variant.name()?;
+    let pattern_suffix = match variant.kind() {
+        ast::StructKind::Record(_) => " { .. }",
+        ast::StructKind::Tuple(_) => "(..)",
+        ast::StructKind::Unit => "",
+    };
+
+    let enum_lowercase_name = to_lower_snake_case(&enum_name.to_string()).replace('_', " ");
+    let fn_name = format!("is_{}", &to_lower_snake_case(&variant_name.text()));
+    let method = format!(
+        "    /// Returns `true` if the {enum_lowercase_name} is [`{variant_name}`].
     ///
     /// [`{variant_name}`]: {enum_name}::{variant_name}
     #[must_use]
     {vis}fn {fn_name}(&self) -> bool {{
         matches!(self, Self::{variant_name}{pattern_suffix})
     }}",
-            );
-
-            add_method_to_adt(builder, &parent_enum, impl_def, &method);
-        },
-    )
+    );
+    Some((fn_name, method))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::tests::{check_assist, check_assist_not_applicable};
+    use crate::tests::{check_assist, check_assist_by_label, check_assist_not_applicable};
 
     use super::*;
 
@@ -307,4 +334,51 @@ fn is_complete(&self) -> bool {
 }"#,
         );
     }
+
+    #[test]
+    fn test_generate_enum_is_for_all_variants() {
+        check_assist_by_label(
+            generate_enum_is_method,
+            r#"
+enum Variant {
+    Undefined,
+    Minor$0(u32),
+    Major { version: u32 },
+}
+
+impl Variant {
+    fn is_undefined(&self) -> bool {
+        matches!(self, Self::Undefined)
+    }
+}"#,
+            r#"enum Variant {
+    Undefined,
+    Minor(u32),
+    Major { version: u32 },
+}
+
+impl Variant {
+    fn is_undefined(&self) -> bool {
+        matches!(self, Self::Undefined)
+    }
+
+    /// Returns `true` if the variant is [`Minor`].
+    ///
+    /// [`Minor`]: Variant::Minor
+    #[must_use]
+    fn is_minor(&self) -> bool {
+        matches!(self, Self::Minor(..))
+    }
+
+    /// Returns `true` if the variant is [`Major`].
+    ///
+    /// [`Major`]: Variant::Major
+    #[must_use]
+    fn is_major(&self) -> bool {
+        matches!(self, Self::Major { .. })
+    }
+}"#,
+            "Generate `is_` methods for all enum variants",
+        );
+    }
 }
@@ -49,9 +49,11 @@ pub(crate) fn generate_enum_try_into_method(
             fn_name_prefix: "try_into",
             self_param: "self",
             return_prefix: "Result<",
+            field_prefix: "",
             return_suffix: ", Self>",
             happy_case: "Ok",
             sad_case: "Err(self)",
+            multiple_fields: false,
         },
     )
 }
@@ -92,10 +94,12 @@ pub(crate) fn generate_enum_as_method(acc: &mut Assists, ctx: &AssistContext<'_>
         ProjectionProps {
             fn_name_prefix: "as",
             self_param: "&self",
-            return_prefix: "Option<&",
+            return_prefix: "Option<",
+            field_prefix: "&",
             return_suffix: ">",
             happy_case: "Some",
             sad_case: "None",
+            multiple_fields: true,
         },
     )
 }
@@ -104,9 +108,13 @@ struct ProjectionProps {
     fn_name_prefix: &'static str,
     self_param: &'static str,
     return_prefix: &'static str,
+    /// Prepended to the type of each projected field.
+    field_prefix: &'static str,
     return_suffix: &'static str,
     happy_case: &'static str,
     sad_case: &'static str,
+    /// Whether variants with several fields are projected to a tuple of their fields.
+    multiple_fields: bool,
 }
 
 fn generate_enum_projection_method(
@@ -120,30 +128,51 @@ fn generate_enum_projection_method(
         fn_name_prefix,
         self_param,
         return_prefix,
+        field_prefix,
         return_suffix,
         happy_case,
         sad_case,
+        multiple_fields,
     } = props;
 
     let variant = ctx.find_node_at_offset::<ast::Variant>()?;
     let variant_name = variant.name()?;
     let parent_enum = ast::Adt::Enum(variant.parent_enum());
 
-    let (pattern_suffix, field_type, bound_name) = match variant.kind() {
-        ast::StructKind::Record(record) => {
-            let (field,) = record.fields().collect_tuple()?;
-            let name = field.name()?.to_string();
-            let ty = field.ty()?;
-            let pattern_suffix = format!(" {{ {name} }}");
-            (pattern_suffix, ty, name)
-        }
+    let (bound_names, field_types): (Vec<_>, Vec<_>) = match variant.kind() {
+        ast::StructKind::Record(record) => record
+            .fields()
+            .map(|field| Some((field.name()?.to_string(), field.ty()?)))
+            .collect::<Option<_>>()?,
         ast::StructKind::Tuple(tuple) => {
-            let (field,) = tuple.fields().collect_tuple()?;
-            let ty = field.ty()?;
-            ("(v)".to_owned(), ty, "v".to_owned())
+            let field_count = tuple.fields().count();
+            tuple
+                .fields()
+                .enumerate()
+                .map(|(i, field)| {
+                    let name = if field_count == 1 { "v".to_owned() } else { format!("v{i}") };
+                    Some((name, field.ty()?))
+                })
+                .collect::<Option<_>>()?
         }
         ast::StructKind::Unit => return None,
     };
+    if bound_names.is_empty() || (bound_names.len() > 1 && !multiple_fields) {
+        return None;
+    }
+
+    let bound_list = bound_names.iter().join(", ");
+    let pattern_suffix = match variant.kind() {
+        ast::StructKind::Record(_) => format!(" {{ {bound_list} }}"),
+        _ => format!("({bound_list})"),
+    };
+    let (field_type, bound_name) = match &*field_types {
+        [field_type] => (format!("{field_prefix}{}", field_type.syntax()), bound_list),
+        _ => {
+            let types = field_types.iter().map(|ty| format!("{field_prefix}{ty}")).join(", ");
+            (format!("({types})"), format!("({bound_list})"))
+        }
+    };
 
     let fn_name = format!("{fn_name_prefix}_{}", &to_lower_snake_case(&variant_name.text()));
 
@@ -159,8 +188,6 @@ fn generate_enum_projection_method(
         |builder| {
             let vis = parent_enum.visibility().map_or(String::new(), |v| format!("{v} "));
 
-            let field_type_syntax = field_type.syntax();
-
             let must_use = if ctx.config.assist_emit_must_use {
                 "#[must_use]\n    "
             } else {
@@ -168,7 +195,7 @@ fn generate_enum_projection_method(
             };
 
             let method = format!(
-                "    {must_use}{vis}fn {fn_name}({self_param}) -> {return_prefix}{field_type_syntax}{return_suffix} {{
+                "    {must_use}{vis}fn {fn_name}({self_param}) -> {return_prefix}{field_type}{return_suffix} {{
         if let Self::{variant_name}{pattern_suffix} = self {{
             {happy_case}({bound_name})
         }} else {{
@@ -342,6 +369,69 @@ fn as_text(&self) -> Option<&String> {
             None
         }
     }
+}"#,
+        );
+    }
+
+    #[test]
+    fn test_generate_enum_as_tuple_variant_with_multiple_fields() {
+        check_assist(
+            generate_enum_as_method,
+            r#"
+enum Shape {
+    Circle(f64),
+    Rect(f64, f64)$0,
+}"#,
+            r#"enum Shape {
+    Circle(f64),
+    Rect(f64, f64),
+}
+
+impl Shape {
+    fn as_rect(&self) -> Option<(&f64, &f64)> {
+        if let Self::Rect(v0, v1) = self {
+            Some((v0, v1))
+        } else {
+            None
+        }
+    }
+}"#,
+        );
+    }
+
+    #[test]
+    fn test_generate_enum_as_record_variant_with_multiple_fields() {
+        check_assist(
+            generate_enum_as_method,
+            r#"
+enum Shape {
+    Circle(f64),
+    Rect { width: f64, height: f64 }$0,
+}"#,
+            r#"enum Shape {
+    Circle(f64),
+    Rect { width: f64, height: f64 },
+}
+
+impl Shape {
+    fn as_rect(&self) -> Option<(&f64, &f64)> {
+        if let Self::Rect { width, height } = self {
+            Some((width, height))
+        } else {
+            None
+        }
+    }
+}"#,
+        );
+    }
+
+    #[test]
+    fn test_generate_enum_as_unit_variant() {
+        check_assist_not_applicable(
+            generate_enum_as_method,
+            r#"enum Shape {
+    Circle(f64),
+    Point$0,
 }"#,
         );
     }
@@ -48,12 +48,22 @@ pub(crate) fn generate_is_empty_from_len(acc: &mut Assists, ctx: &AssistContext<
         return None;
     }
 
-    if fn_node.param_list()?.params().next().is_some() {
+    let param_list = fn_node.param_list()?;
+    if param_list.params().next().is_some() {
         cov_mark::hit!(len_function_with_parameters);
         return None;
     }
+    // The generated `is_empty` takes `&self`, so it can only call a `len` that does too.
+    if param_list.self_param().map(|it| it.kind()) != Some(ast::SelfParamKind::Ref) {
+        cov_mark::hit!(len_function_not_by_ref);
+        return None;
+    }
 
     let impl_ = fn_node.syntax().ancestors().find_map(ast::Impl::cast)?;
+    if impl_.trait_().is_some() {
+        cov_mark::hit!(len_function_in_trait_impl);
+        return None;
+    }
     let len_fn = get_impl_method(ctx, &impl_, &known::len)?;
     if !len_fn.ret_type(ctx.sema.db).is_usize() {
         cov_mark::hit!(len_fn_different_return_type);
@@ -282,6 +292,41 @@ pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
 }
+"#,
+        );
+    }
+
+    #[test]
+    fn len_function_not_by_ref() {
+        cov_mark::check!(len_function_not_by_ref);
+        check_assist_not_applicable(
+            generate_is_empty_from_len,
+            r#"
+struct MyStruct { data: Vec<String> }
+
+impl MyStruct {
+    p$0ub fn len(&mut self) -> usize {
+        self.data.len()
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn len_function_in_trait_impl() {
+        cov_mark::check!(len_function_in_trait_impl);
+        check_assist_not_applicable(
+            generate_is_empty_from_len,
+            r#"
+struct MyStruct { data: Vec<String> }
+trait Len { fn len(&self) -> usize; }
+
+impl Len for MyStruct {
+    f$0n len(&self) -> usize {
+        self.data.len()
+    }
+}
 "#,
         );
     }
@@ -1,3 +1,4 @@
+use either::Either;
 use ide_db::{
     imports::import_assets::item_for_path_search, use_trivial_constructor::use_trivial_constructor,
 };
@@ -7,7 +8,7 @@
 };
 
 use crate::{
-    utils::{find_struct_impl, generate_impl},
+    utils::{find_struct_impl, generate_impl, suggest_name},
     AssistContext, AssistId, AssistKind, Assists,
 };
 
@@ -35,10 +36,11 @@
 pub(crate) fn generate_new(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
     let strukt = ctx.find_node_at_offset::<ast::Struct>()?;
 
-    // We want to only apply this to non-union structs with named fields
+    // We want to only apply this to non-union structs with fields
     let field_list = match strukt.kind() {
-        StructKind::Record(named) => named,
-        _ => return None,
+        StructKind::Record(named) => Either::Left(named),
+        StructKind::Tuple(tuple) => Either::Right(tuple),
+        StructKind::Unit => return None,
     };
 
     // Return early if we've found an existing new fn
@@ -49,56 +51,99 @@ pub(crate) fn generate_new(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option
 
     let target = strukt.syntax().text_range();
     acc.add(AssistId("generate_new", AssistKind::Generate), "Generate `new`", target, |builder| {
-        let trivial_constructors = field_list
-            .fields()
-            .map(|f| {
-                let name = f.name()?;
-
-                let ty = ctx.sema.resolve_type(&f.ty()?)?;
-
-                let item_in_ns = hir::ItemInNs::from(hir::ModuleDef::from(ty.as_adt()?));
-
-                let type_path = current_module.find_use_path(
-                    ctx.sema.db,
-                    item_for_path_search(ctx.sema.db, item_in_ns)?,
-                    ctx.config.prefer_no_std,
-                    ctx.config.prefer_prelude,
-                )?;
-
-                let expr = use_trivial_constructor(
-                    ctx.sema.db,
-                    ide_db::helpers::mod_path_to_ast(&type_path),
-                    &ty,
-                )?;
-
-                Some(make::record_expr_field(make::name_ref(&name.text()), Some(expr)))
-            })
-            .collect::<Vec<_>>();
-
-        let params = field_list.fields().enumerate().filter_map(|(i, f)| {
-            if trivial_constructors[i].is_none() {
-                let name = f.name()?;
-                let ty = f.ty()?;
-
-                Some(make::param(make::ident_pat(false, false, name).into(), ty))
-            } else {
-                None
+        let trivial_constructor = |ty: Option<ast::Type>| {
+            let ty = ctx.sema.resolve_type(&ty?)?;
+
+            let item_in_ns = hir::ItemInNs::from(hir::ModuleDef::from(ty.as_adt()?));
+
+            let type_path = current_module.find_use_path(
+                ctx.sema.db,
+                item_for_path_search(ctx.sema.db, item_in_ns)?,
+                ctx.config.prefer_no_std,
+                ctx.config.prefer_prelude,
+            )?;
+
+            use_trivial_constructor(ctx.sema.db, ide_db::helpers::mod_path_to_ast(&type_path), &ty)
+        };
+
+        let (params, body) = match &field_list {
+            Either::Left(field_list) => {
+                let trivial_constructors = field_list
+                    .fields()
+                    .map(|f| {
+                        let name = f.name()?;
+                        let expr = trivial_constructor(f.ty())?;
+                        Some(make::record_expr_field(make::name_ref(&name.text()), Some(expr)))
+                    })
+                    .collect::<Vec<_>>();
+
+                let params = field_list.fields().enumerate().filter_map(|(i, f)| {
+                    if trivial_constructors[i].is_none() {
+                        let name = f.name()?;
+                        let ty = f.ty()?;
+
+                        Some(make::param(make::ident_pat(false, false, name).into(), ty))
+                    } else {
+                        None
+                    }
+                });
+                let params = make::param_list(None, params);
+
+                let fields = field_list.fields().enumerate().filter_map(|(i, f)| {
+                    let constructor = trivial_constructors[i].clone();
+                    if constructor.is_some() {
+                        constructor
+                    } else {
+                        Some(make::record_expr_field(make::name_ref(&f.name()?.text()), None))
+                    }
+                });
+                let fields = make::record_expr_field_list(fields);
+
+                (params, ast::Expr::from(make::record_expr(make::ext::ident_path("Self"), fields)))
             }
-        });
-        let params = make::param_list(None, params);
-
-        let fields = field_list.fields().enumerate().filter_map(|(i, f)| {
-            let constructor = trivial_constructors[i].clone();
-            if constructor.is_some() {
-                constructor
-            } else {
-                Some(make::record_expr_field(make::name_ref(&f.name()?.text()), None))
+            Either::Right(field_list) => {
+                // Name the parameters after the types of the fields, falling back to their
+                // positions when the type doesn't give a name or two fields would share one.
+                let names = field_list
+                    .fields()
+                    .map(|f| {
+                        let ty = ctx.sema.resolve_type(&f.ty()?)?;
+                        suggest_name::for_type(&ty, ctx.db())
+                    })
+                    .collect::<Vec<_>>();
+                let names = names
+                    .iter()
+                    .enumerate()
+                    .map(|(i, name)| match name {
+                        Some(name)
+                            if names.iter().filter(|it| it.as_ref() == Some(name)).count() == 1 =>
+                        {
+                            name.clone()
+                        }
+                        _ => format!("field{i}"),
+                    })
+                    .collect::<Vec<_>>();
+
+                let mut params = Vec::new();
+                let mut args = Vec::new();
+                for (f, name) in field_list.fields().zip(&names) {
+                    match trivial_constructor(f.ty()) {
+                        Some(expr) => args.push(expr),
+                        None => {
+                            let Some(ty) = f.ty() else { continue };
+                            let pat = make::ident_pat(false, false, make::name(name));
+                            params.push(make::param(pat.into(), ty));
+                            args.push(make::expr_path(make::ext::ident_path(name)));
+                        }
+                    }
+                }
+                let params = make::param_list(None, params);
+
+                let callee = make::expr_path(make::ext::ident_path("Self"));
+                (params, make::expr_call(callee, make::arg_list(args)))
             }
-        });
-        let fields = make::record_expr_field_list(fields);
-
-        let record_expr = make::record_expr(make::ext::ident_path("Self"), fields);
-        let body = make::block_expr(None, Some(record_expr.into()));
+        };
+        let body = make::block_expr(None, Some(body));
 
         let ret_type = make::ret_type(make::ty_path(make::ext::ident_path("Self")));
 
@@ -553,6 +598,58 @@ pub fn map<F: FnOnce(T) -> U, U>(self, f: F) -> Source<U> {
         Source { file_id: self.file_id, ast: f(self.ast) }
     }
 }
+"#,
+        );
+    }
+
+    #[test]
+    fn test_generate_new_tuple_struct() {
+        check_assist(
+            generate_new,
+            r#"
+struct Meters(u32);
+struct Seconds(u32);
+
+struct Speed(Meters, Seconds$0);
+"#,
+            r#"
+struct Meters(u32);
+struct Seconds(u32);
+
+struct Speed(Meters, Seconds);
+
+impl Speed {
+    fn $0new(meters: Meters, seconds: Seconds) -> Self {
+        Self(meters, seconds)
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_generate_new_tuple_struct_positional_names() {
+        check_assist(
+            generate_new,
+            r#"
+struct Empty;
+
+struct Meters(u32);
+
+struct Foo<T>(Meters, u32, Empty, T, Meters$0);
+"#,
+            r#"
+struct Empty;
+
+struct Meters(u32);
+
+struct Foo<T>(Meters, u32, Empty, T, Meters);
+
+impl<T> Foo<T> {
+    fn $0new(field0: Meters, field1: u32, field3: T, field4: Meters) -> Self {
+        Self(field0, field1, Empty, field3, field4)
+    }
+}
 "#,
         );
     }
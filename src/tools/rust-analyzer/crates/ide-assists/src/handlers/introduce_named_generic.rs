@@ -1,3 +1,4 @@
+use ide_db::source_change::SourceChangeBuilder;
 use syntax::{
     ast::{self, edit_in_place::GenericParamsOwnerEdit, make, AstNode, HasGenericParams},
     ted,
@@ -21,9 +22,26 @@ pub(crate) fn introduce_named_generic(acc: &mut Assists, ctx: &AssistContext<'_>
     let param = impl_trait_type.syntax().ancestors().find_map(ast::Param::cast)?;
     let fn_ = param.syntax().ancestors().find_map(ast::Fn::cast)?;
 
-    let type_bound_list = impl_trait_type.type_bound_list()?;
+    impl_trait_type.type_bound_list()?;
 
     let target = fn_.syntax().text_range();
+
+    // With several `impl Trait` arguments, also offer to name all of them at once.
+    let all_impl_traits = outermost_impl_trait_args(&fn_.param_list()?);
+    if all_impl_traits.len() > 1 {
+        acc.add(
+            AssistId("introduce_named_generic", AssistKind::RefactorRewrite),
+            "Replace all impl traits with generics",
+            target,
+            |edit| {
+                let impl_trait_types =
+                    all_impl_traits.into_iter().map(|it| edit.make_mut(it)).collect::<Vec<_>>();
+                let fn_ = edit.make_mut(fn_.clone());
+                replace_with_generics(ctx, edit, &fn_, impl_trait_types);
+            },
+        );
+    }
+
     acc.add(
         AssistId("introduce_named_generic", AssistKind::RefactorRewrite),
         "Replace impl trait with generic",
@@ -31,33 +49,65 @@ pub(crate) fn introduce_named_generic(acc: &mut Assists, ctx: &AssistContext<'_>
         |edit| {
             let impl_trait_type = edit.make_mut(impl_trait_type);
             let fn_ = edit.make_mut(fn_);
-            let fn_generic_param_list = fn_.get_or_create_generic_param_list();
-            let type_param_name =
-                suggest_name::for_impl_trait_as_generic(&impl_trait_type, &fn_generic_param_list);
-
-            let type_param = make::type_param(make::name(&type_param_name), Some(type_bound_list))
-                .clone_for_update();
-            let new_ty = make::ty(&type_param_name).clone_for_update();
-
-            ted::replace(impl_trait_type.syntax(), new_ty.syntax());
-            fn_generic_param_list.add_generic_param(type_param.into());
-
-            if let Some(cap) = ctx.config.snippet_cap {
-                if let Some(generic_param) =
-                    fn_.generic_param_list().and_then(|it| it.generic_params().last())
-                {
-                    edit.add_tabstop_before(cap, generic_param);
-                }
-            }
+            replace_with_generics(ctx, edit, &fn_, vec![impl_trait_type]);
         },
     )
 }
 
+/// Replaces each of `impl_trait_types` with a new type parameter of `fn_`, giving each a distinct
+/// name.
+fn replace_with_generics(
+    ctx: &AssistContext<'_>,
+    edit: &mut SourceChangeBuilder,
+    fn_: &ast::Fn,
+    impl_trait_types: Vec<ast::ImplTraitType>,
+) {
+    let fn_generic_param_list = fn_.get_or_create_generic_param_list();
+    let first_new_param = fn_generic_param_list.generic_params().count();
+
+    for impl_trait_type in impl_trait_types {
+        let Some(type_bound_list) = impl_trait_type.type_bound_list() else { continue };
+        let type_param_name =
+            suggest_name::for_impl_trait_as_generic(&impl_trait_type, &fn_generic_param_list);
+
+        let type_param = make::type_param(make::name(&type_param_name), Some(type_bound_list))
+            .clone_for_update();
+        let new_ty = make::ty(&type_param_name).clone_for_update();
+
+        ted::replace(impl_trait_type.syntax(), new_ty.syntax());
+        fn_generic_param_list.add_generic_param(type_param.into());
+    }
+
+    if let Some(cap) = ctx.config.snippet_cap {
+        if let Some(generic_param) =
+            fn_.generic_param_list().and_then(|it| it.generic_params().nth(first_new_param))
+        {
+            edit.add_tabstop_before(cap, generic_param);
+        }
+    }
+}
+
+/// The `impl Trait` types in `param_list` that aren't nested in another one.
+fn outermost_impl_trait_args(param_list: &ast::ParamList) -> Vec<ast::ImplTraitType> {
+    param_list
+        .syntax()
+        .descendants()
+        .filter_map(ast::ImplTraitType::cast)
+        .filter(|it| {
+            it.syntax()
+                .ancestors()
+                .skip(1)
+                .take_while(|node| node != param_list.syntax())
+                .all(|node| !ast::ImplTraitType::can_cast(node.kind()))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use crate::tests::check_assist;
+    use crate::tests::{check_assist, check_assist_by_label};
 
     #[test]
     fn introduce_named_generic_params() {
@@ -175,4 +225,24 @@ fn replace_impl_inside() {
             r#"fn f<$0I: Iterator<Item = i32>>(x: &mut Vec<I>) {}"#,
         );
     }
+
+    #[test]
+    fn replace_all_impl_traits() {
+        check_assist_by_label(
+            introduce_named_generic,
+            r#"fn foo<T>(bar: $0impl Bar, baz: impl Baz, t: T) {}"#,
+            r#"fn foo<T, $0B: Bar, B0: Baz>(bar: B, baz: B0, t: T) {}"#,
+            "Replace all impl traits with generics",
+        );
+    }
+
+    #[test]
+    fn replace_all_impl_traits_inside_other_types() {
+        check_assist_by_label(
+            introduce_named_generic,
+            r#"fn foo(v: &Vec<impl Bar>, f: $0impl Fn()) {}"#,
+            r#"fn foo<$0B: Bar, F: Fn()>(v: &Vec<B>, f: F) {}"#,
+            "Replace all impl traits with generics",
+        );
+    }
 }
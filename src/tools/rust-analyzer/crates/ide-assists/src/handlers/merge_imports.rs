@@ -1,7 +1,12 @@
+use std::iter;
+
 use either::Either;
 use ide_db::imports::{
     insert_use::{ImportGranularity, InsertUseConfig},
-    merge_imports::{try_merge_imports, try_merge_trees, try_normalize_use_tree, MergeBehavior},
+    merge_imports::{
+        try_merge_import_group, try_merge_imports, try_merge_trees, try_normalize_use_tree,
+        MergeBehavior,
+    },
 };
 use itertools::Itertools;
 use syntax::{
@@ -143,12 +148,24 @@ fn try_merge_from(
 }
 
 impl Merge for ast::Use {
+    fn try_merge_from(
+        self,
+        items: &mut dyn Iterator<Item = Self>,
+        cfg: &InsertUseConfig,
+    ) -> Option<Vec<Edit>> {
+        // Merge the whole selection at once instead of copying the merged import for every item.
+        let group: Vec<_> = iter::once(self.clone()).chain(items).collect();
+        if group.len() < 2 {
+            return None;
+        }
+        let merged = try_merge_import_group(&group, merge_behavior(cfg))?;
+        let mut edits: Vec<_> =
+            group.into_iter().skip(1).map(|it| Edit::Remove(Either::Left(it))).collect();
+        edits.push(Edit::replace(self, merged));
+        Some(edits)
+    }
     fn try_merge(&self, other: &Self, cfg: &InsertUseConfig) -> Option<Self> {
-        let mb = match cfg.granularity {
-            ImportGranularity::One => MergeBehavior::One,
-            _ => MergeBehavior::Crate,
-        };
-        try_merge_imports(self, other, mb)
+        try_merge_imports(self, other, merge_behavior(cfg))
     }
     fn into_either(self) -> Either<ast::Use, ast::UseTree> {
         Either::Left(self)
@@ -164,6 +181,13 @@ fn into_either(self) -> Either<ast::Use, ast::UseTree> {
     }
 }
 
+fn merge_behavior(cfg: &InsertUseConfig) -> MergeBehavior {
+    match cfg.granularity {
+        ImportGranularity::One => MergeBehavior::One,
+        _ => MergeBehavior::Crate,
+    }
+}
+
 enum Edit {
     Remove(Either<ast::Use, ast::UseTree>),
     Replace(SyntaxNode, SyntaxNode),
@@ -678,6 +702,22 @@ fn merge_selection_uses() {
         );
     }
 
+    #[test]
+    fn merge_selection_of_uses_with_different_paths() {
+        check_assist(
+            merge_imports,
+            r"
+$0use std::fmt::Write;
+use std::fmt::Debug;
+use std::io;
+use std::fmt::Display;$0
+",
+            r"
+use std::{fmt::{Debug, Display, Write}, io};
+",
+        );
+    }
+
     #[test]
     fn merge_selection_use_trees() {
         cov_mark::check!(merge_with_selected_use_tree_neighbors);
@@ -1,7 +1,7 @@
 use ide_db::syntax_helpers::node_ext::is_pattern_cond;
 use syntax::{
     ast::{self, AstNode, BinaryOp},
-    T,
+    SyntaxKind, T,
 };
 
 use crate::{
@@ -52,6 +52,10 @@ pub(crate) fn merge_nested_if(acc: &mut Assists, ctx: &AssistContext<'_>) -> Opt
     if stmt.statements().count() != 0 {
         return None;
     }
+    //should not apply if merging would drop comments around the nested if.
+    if stmt.syntax().children_with_tokens().any(|it| it.kind() == SyntaxKind::COMMENT) {
+        return None;
+    }
 
     let nested_if_to_merge = then_branch.tail_expr().and_then(|e| match e {
         ast::Expr::IfExpr(e) => Some(e),
@@ -243,4 +247,12 @@ fn merge_nested_if_do_not_apply_with_multiply_nested_if() {
             "fn f() { i$0f x == 0 { if y == 3 { foo(); } if z == 3 { 2 } } }",
         )
     }
+
+    #[test]
+    fn merge_nested_if_do_not_apply_with_comment_in_outer_block() {
+        check_assist_not_applicable(
+            merge_nested_if,
+            "fn f() { i$0f x == 0 { // only when y is 3\n if y == 3 { foo(); } } }",
+        )
+    }
 }
@@ -0,0 +1,154 @@
+use syntax::{ast, AstNode};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: pull_closure_arg_into_named
+//
+// Adds explicit types, as inferred from the call the closure is passed to, to the parameters of
+// a closure argument.
+//
+// ```
+// # //- minicore: fn
+// fn apply(f: impl Fn(u32, bool) -> u32) {}
+// fn main() {
+//     apply(|$0x, y| x);
+// }
+// ```
+// ->
+// ```
+// fn apply(f: impl Fn(u32, bool) -> u32) {}
+// fn main() {
+//     apply(|x: u32, y: bool| x);
+// }
+// ```
+pub(crate) fn pull_closure_arg_into_named(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let closure = ctx.find_node_at_offset::<ast::ClosureExpr>()?;
+    let param_list = closure.param_list()?;
+    if !param_list.syntax().text_range().contains_range(ctx.selection_trimmed()) {
+        return None;
+    }
+    // The types come from the expected type of the closure, so it has to be an argument.
+    closure.syntax().parent().and_then(ast::ArgList::cast)?;
+
+    let params: Vec<_> = param_list.params().collect();
+    if params.iter().all(|param| param.ty().is_some()) {
+        return None;
+    }
+
+    let closure_ty = ctx.sema.type_of_expr(&closure.clone().into())?.original;
+    let callable = closure_ty.as_callable(ctx.db())?;
+    let param_tys: Vec<_> = callable.params(ctx.db()).into_iter().map(|(_, ty)| ty).collect();
+    if param_tys.len() != params.len() || param_tys.iter().any(|ty| ty.contains_unknown()) {
+        cov_mark::hit!(pull_closure_arg_into_named_unknown_types);
+        return None;
+    }
+
+    let module = ctx.sema.scope(closure.syntax())?.module();
+    let mut insertions = Vec::new();
+    for (param, ty) in params.iter().zip(&param_tys) {
+        if param.ty().is_some() {
+            continue;
+        }
+        let ty = ty.display_source_code(ctx.db(), module.into(), false).ok()?;
+        insertions.push((param.pat()?.syntax().text_range().end(), format!(": {ty}")));
+    }
+
+    acc.add(
+        AssistId("pull_closure_arg_into_named", AssistKind::RefactorRewrite),
+        "Add explicit types to closure parameters",
+        param_list.syntax().text_range(),
+        |builder| {
+            for (offset, text) in insertions {
+                builder.insert(offset, text);
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn sort_by_params_are_references() {
+        check_assist(
+            pull_closure_arg_into_named,
+            r#"
+//- minicore: fn, ord
+use core::cmp::Ordering;
+struct Vec<T>(T);
+impl<T> Vec<T> {
+    fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {}
+}
+fn main() {
+    let mut v = Vec(1u32);
+    v.sort_by(|$0a, b| Ordering::Equal);
+}
+"#,
+            r#"
+use core::cmp::Ordering;
+struct Vec<T>(T);
+impl<T> Vec<T> {
+    fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {}
+}
+fn main() {
+    let mut v = Vec(1u32);
+    v.sort_by(|a: &u32, b: &u32| Ordering::Equal);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn keeps_existing_types() {
+        check_assist(
+            pull_closure_arg_into_named,
+            r#"
+//- minicore: fn
+fn apply(f: impl Fn(u8, bool)) {}
+fn main() {
+    apply(|x: u8, $0flag| ());
+}
+"#,
+            r#"
+fn apply(f: impl Fn(u8, bool)) {}
+fn main() {
+    apply(|x: u8, flag: bool| ());
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_ambiguous() {
+        cov_mark::check!(pull_closure_arg_into_named_unknown_types);
+        check_assist_not_applicable(
+            pull_closure_arg_into_named,
+            r#"
+//- minicore: fn
+fn apply<F>(f: F) {}
+fn main() {
+    apply(|$0x| ());
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_outside_of_arguments() {
+        check_assist_not_applicable(
+            pull_closure_arg_into_named,
+            r#"
+//- minicore: fn
+fn main() {
+    let f = |$0x: u32| x;
+}
+"#,
+        );
+    }
+}
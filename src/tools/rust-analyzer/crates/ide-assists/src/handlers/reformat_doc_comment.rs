@@ -0,0 +1,241 @@
+use itertools::Itertools;
+use syntax::{
+    ast::{self, edit::IndentLevel, Whitespace},
+    AstToken, TextRange,
+};
+
+use crate::{
+    handlers::convert_comment_block::relevant_line_comments, AssistContext, AssistId, AssistKind,
+    Assists,
+};
+
+// Assist: reformat_doc_comment
+//
+// Reflows the prose of a line doc comment to the configured width (80 columns by default),
+// leaving fenced code blocks untouched.
+//
+// ```
+// /// A doc comment$0 that was written as a single line which runs well past the end of the line.
+// fn f() {}
+// ```
+// ->
+// ```
+// /// A doc comment that was written as a single line which runs well past the end
+// /// of the line.
+// fn f() {}
+// ```
+pub(crate) fn reformat_doc_comment(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let comment = ctx.find_token_at_offset::<ast::Comment>()?;
+    if comment.kind().doc.is_none() || comment.kind().shape != ast::CommentShape::Line {
+        return None;
+    }
+    // Only allow comments which are alone on their line
+    if let Some(prev) = comment.syntax().prev_token() {
+        Whitespace::cast(prev).filter(|w| w.text().contains('\n'))?;
+    }
+
+    let comments = relevant_line_comments(&comment);
+    let prefix = comment.prefix();
+    let lines: Vec<&str> = comments
+        .iter()
+        .map(|c| {
+            let text = c.text().strip_prefix(prefix).unwrap_or(c.text());
+            text.strip_prefix(' ').unwrap_or(text)
+        })
+        .collect();
+
+    let indentation = IndentLevel::from_token(comment.syntax());
+    let used = indentation.to_string().len() + prefix.len() + 1;
+    let reflowed = reflow(&lines, ctx.config.doc_comment_width.saturating_sub(used));
+    if reflowed == lines {
+        cov_mark::hit!(reformat_doc_comment_already_formatted);
+        return None;
+    }
+
+    let target = TextRange::new(
+        comments[0].syntax().text_range().start(),
+        comments.last()?.syntax().text_range().end(),
+    );
+    acc.add(
+        AssistId("reformat_doc_comment", AssistKind::RefactorRewrite),
+        "Reformat doc comment",
+        target,
+        |edit| {
+            let output = reflowed
+                .iter()
+                .map(|line| {
+                    // Don't introduce trailing whitespace
+                    if line.is_empty() {
+                        prefix.to_owned()
+                    } else {
+                        format!("{prefix} {line}")
+                    }
+                })
+                .join(&format!("\n{indentation}"));
+            edit.replace(target, output)
+        },
+    )
+}
+
+/// A paragraph being reflowed: the prefix of its first line, like a list marker, the prefix of
+/// the lines after it, and its words.
+struct Paragraph<'a> {
+    first_prefix: &'a str,
+    rest_prefix: String,
+    words: Vec<&'a str>,
+}
+
+/// Reflows the paragraphs of `lines` so that they fit in `width` columns where possible. Blank
+/// lines, fenced code blocks, headings and tables are kept as they are, and list items keep their
+/// markers, with their continuation lines indented to line up with the text of the item.
+fn reflow(lines: &[&str], width: usize) -> Vec<String> {
+    let mut res = Vec::new();
+    let mut paragraph: Option<Paragraph<'_>> = None;
+    let mut in_fence = false;
+
+    for &line in lines {
+        let trimmed = line.trim_start();
+        let is_fence = trimmed.starts_with("```");
+        if in_fence || is_fence || trimmed.is_empty() || trimmed.starts_with(['#', '|']) {
+            flush(&mut res, paragraph.take(), width);
+            res.push(line.to_owned());
+            in_fence ^= is_fence;
+            continue;
+        }
+
+        let indent = &line[..line.len() - trimmed.len()];
+        if let Some(marker_len) = list_marker_len(trimmed) {
+            flush(&mut res, paragraph.take(), width);
+            let (marker, text) = line.split_at(indent.len() + marker_len);
+            paragraph = Some(Paragraph {
+                first_prefix: marker,
+                rest_prefix: " ".repeat(marker.len()),
+                words: text.split_whitespace().collect(),
+            });
+            continue;
+        }
+        match &mut paragraph {
+            Some(paragraph) => paragraph.words.extend(trimmed.split_whitespace()),
+            None => {
+                paragraph = Some(Paragraph {
+                    first_prefix: indent,
+                    rest_prefix: indent.to_owned(),
+                    words: trimmed.split_whitespace().collect(),
+                })
+            }
+        }
+    }
+    flush(&mut res, paragraph, width);
+    res
+}
+
+/// The length of the list marker at the start of `text`, including the space after it: `- `,
+/// `* ` or `1. `.
+fn list_marker_len(text: &str) -> Option<usize> {
+    if text.starts_with("- ") || text.starts_with("* ") {
+        return Some(2);
+    }
+    let digits = text.bytes().take_while(u8::is_ascii_digit).count();
+    (digits > 0 && text[digits..].starts_with(". ")).then_some(digits + 2)
+}
+
+fn flush(res: &mut Vec<String>, paragraph: Option<Paragraph<'_>>, width: usize) {
+    let Some(Paragraph { first_prefix, rest_prefix, words }) = paragraph else { return };
+    let mut line = first_prefix.to_owned();
+    let mut is_empty = true;
+    for word in words {
+        if !is_empty && line.chars().count() + 1 + word.chars().count() > width {
+            res.push(line);
+            line = rest_prefix.clone();
+            is_empty = true;
+        }
+        if !is_empty {
+            line.push(' ');
+        }
+        line.push_str(word);
+        is_empty = false;
+    }
+    res.push(line);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn reflow_long_line() {
+        check_assist(
+            reformat_doc_comment,
+            r#"
+mod m {
+    /// Returns the number of$0 elements in the collection, not counting the ones that were removed.
+    /// Runs in
+    /// constant time.
+    fn len() {}
+}
+"#,
+            r#"
+mod m {
+    /// Returns the number of elements in the collection, not counting the ones
+    /// that were removed. Runs in constant time.
+    fn len() {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn keep_code_blocks_and_lists() {
+        check_assist(
+            reformat_doc_comment,
+            r#"
+//! Some$0 examples:
+//!
+//! ```
+//! let a_long_line_of_code = "that must not be reflowed even though it is much longer than 80";
+//! ```
+//!
+//! - a list item that is long enough that it needs to be wrapped onto a second line to fit
+//! - short
+"#,
+            r#"
+//! Some examples:
+//!
+//! ```
+//! let a_long_line_of_code = "that must not be reflowed even though it is much longer than 80";
+//! ```
+//!
+//! - a list item that is long enough that it needs to be wrapped onto a second
+//!   line to fit
+//! - short
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_formatted() {
+        cov_mark::check!(reformat_doc_comment_already_formatted);
+        check_assist_not_applicable(
+            reformat_doc_comment,
+            r#"
+/// Short$0 lines
+///
+/// are left alone.
+fn f() {}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_plain_comments() {
+        check_assist_not_applicable(
+            reformat_doc_comment,
+            r#"
+// A plain comment$0 that is far too long to fit in eighty columns, but isn't documentation.
+fn f() {}
+"#,
+        );
+    }
+}
@@ -41,7 +41,8 @@ pub(crate) fn remove_unused_imports(acc: &mut Assists, ctx: &AssistContext<'_>)
         .descendants()
         .filter(|x| x.text_range().intersect(ctx.selection_trimmed()).is_some())
         .filter_map(ast::Use::cast);
-    let uses = uses_up.chain(uses_down).collect::<Vec<_>>();
+    // Re-exports are used by other modules, even if nothing in this one refers to them.
+    let uses = uses_up.chain(uses_down).filter(|u| u.visibility().is_none()).collect::<Vec<_>>();
 
     // Maps use nodes to the scope that we should search through to find
     let mut search_scopes = FxHashMap::<Module, Vec<SearchScope>>::default();
@@ -794,6 +795,37 @@ fn f() {
         );
     }
 
+    #[test]
+    fn dont_remove_reexport() {
+        check_assist(
+            remove_unused_imports,
+            r#"
+struct X();
+struct Y();
+mod z {
+    $0pub use super::X;
+    use super::Y;$0
+}
+"#,
+            r#"
+struct X();
+struct Y();
+mod z {
+    pub use super::X;
+}
+"#,
+        );
+        check_assist_not_applicable(
+            remove_unused_imports,
+            r#"
+struct X();
+mod z {
+    pub(crate) use super::X$0;
+}
+"#,
+        );
+    }
+
     #[test]
     fn only_remove_from_selection() {
         check_assist(
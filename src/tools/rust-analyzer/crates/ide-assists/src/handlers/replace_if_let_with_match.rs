@@ -237,6 +237,18 @@ pub(crate) fn replace_match_with_if_let(acc: &mut Assists, ctx: &AssistContext<'
     )?;
     let scrutinee = match_expr.expr()?;
 
+    // Prefer `if cond { b } else { a }` to `if !cond { a } else { b }`.
+    let (if_let_pat, then_expr, else_expr) = match &if_let_pat {
+        ast::Pat::LiteralPat(p)
+            if p.literal().map_or(false, |it| it.token().kind() == T![false])
+                && !is_empty_expr(&else_expr) =>
+        {
+            cov_mark::hit!(replace_match_with_if_bool_swap_arms);
+            (make::literal_pat("true").into(), else_expr, then_expr)
+        }
+        _ => (if_let_pat, then_expr, else_expr),
+    };
+
     let let_ = match &if_let_pat {
         ast::Pat::LiteralPat(p)
             if p.literal()
@@ -1152,4 +1164,73 @@ fn main() {
 "#,
         )
     }
+
+    #[test]
+    fn test_replace_bool_match_with_if_else() {
+        check_assist(
+            replace_match_with_if_let,
+            r#"
+fn main() {
+    match$0 b {
+        true => foo(),
+        false => bar(),
+    }
+}
+"#,
+            r#"
+fn main() {
+    if b {
+        foo()
+    } else {
+        bar()
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_replace_swapped_bool_match_with_if_else() {
+        cov_mark::check!(replace_match_with_if_bool_swap_arms);
+        check_assist(
+            replace_match_with_if_let,
+            r#"
+fn main() {
+    match$0 b {
+        false => bar(),
+        true => foo(),
+    }
+}
+"#,
+            r#"
+fn main() {
+    if b {
+        foo()
+    } else {
+        bar()
+    }
+}
+"#,
+        );
+        check_assist(
+            replace_match_with_if_let,
+            r#"
+fn main() {
+    match$0 b {
+        false => bar(),
+        _ => foo(),
+    }
+}
+"#,
+            r#"
+fn main() {
+    if b {
+        foo()
+    } else {
+        bar()
+    }
+}
+"#,
+        );
+    }
 }
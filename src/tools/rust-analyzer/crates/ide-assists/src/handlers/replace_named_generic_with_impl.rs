@@ -56,6 +56,13 @@ pub(crate) fn replace_named_generic_with_impl(
         return None;
     }
 
+    // Each `impl Trait` is a type of its own, so replacing several uses of the generic would let
+    // them differ.
+    if usage_refs.iter().flat_map(|(_, refs)| refs).count() != 1 {
+        cov_mark::hit!(replace_named_generic_with_impl_used_twice);
+        return None;
+    }
+
     let mut path_types_to_replace = Vec::new();
     for (_a, refs) in usage_refs.iter() {
         for usage_ref in refs {
@@ -195,11 +202,11 @@ fn replace_generic_with_inner_associated_type() {
     }
 
     #[test]
-    fn replace_generic_trait_applies_to_all_matching_params() {
-        check_assist(
+    fn replace_generic_not_applicable_when_used_in_several_params() {
+        cov_mark::check!(replace_named_generic_with_impl_used_twice);
+        check_assist_not_applicable(
             replace_named_generic_with_impl,
             r#"fn new<T$0: ToString>(a: T, b: T) -> Self {}"#,
-            r#"fn new(a: impl ToString, b: impl ToString) -> Self {}"#,
         );
     }
 
@@ -207,24 +214,18 @@ fn replace_generic_trait_applies_to_all_matching_params() {
     fn replace_generic_trait_applies_to_generic_arguments_in_params() {
         check_assist(
             replace_named_generic_with_impl,
-            r#"
-            fn foo<P$0: Trait>(
-                _: P,
-                _: Option<P>,
-                _: Option<Option<P>>,
-                _: impl Iterator<Item = P>,
-                _: &dyn Iterator<Item = P>,
-            ) {}
-            "#,
-            r#"
-            fn foo(
-                _: impl Trait,
-                _: Option<impl Trait>,
-                _: Option<Option<impl Trait>>,
-                _: impl Iterator<Item = impl Trait>,
-                _: &dyn Iterator<Item = impl Trait>,
-            ) {}
-            "#,
+            r#"fn foo<P$0: Trait>(_: i32, _: Option<Option<P>>) {}"#,
+            r#"fn foo(_: i32, _: Option<Option<impl Trait>>) {}"#,
+        );
+        check_assist(
+            replace_named_generic_with_impl,
+            r#"fn foo<P$0: Trait>(_: impl Iterator<Item = P>) {}"#,
+            r#"fn foo(_: impl Iterator<Item = impl Trait>) {}"#,
+        );
+        check_assist(
+            replace_named_generic_with_impl,
+            r#"fn foo<P$0: Trait>(_: &dyn Iterator<Item = P>) {}"#,
+            r#"fn foo(_: &dyn Iterator<Item = impl Trait>) {}"#,
         );
     }
 
@@ -232,16 +233,15 @@ fn foo(
     fn replace_generic_not_applicable_when_one_param_type_is_invalid() {
         check_assist_not_applicable(
             replace_named_generic_with_impl,
-            r#"
-            fn foo<P$0: Trait>(
-                _: i32,
-                _: Option<P>,
-                _: Option<Option<P>>,
-                _: impl Iterator<Item = P>,
-                _: &dyn Iterator<Item = P>,
-                _: <P as Trait>::Assoc,
-            ) {}
-            "#,
+            r#"fn foo<P$0: Trait>(_: Option<P>, _: <P as Trait>::Assoc) {}"#,
+        );
+    }
+
+    #[test]
+    fn replace_generic_not_applicable_when_used_in_other_param_bounds() {
+        check_assist_not_applicable(
+            replace_named_generic_with_impl,
+            r#"fn foo<P$0: Trait, I: FromRef<P>>(p: P, i: I) {}"#,
         );
     }
 
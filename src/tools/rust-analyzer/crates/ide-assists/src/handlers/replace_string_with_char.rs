@@ -41,8 +41,12 @@ pub(crate) fn replace_string_with_char(acc: &mut Assists, ctx: &AssistContext<'_
             let (left, right) = quote_offsets.quotes;
             edit.replace(left, '\'');
             edit.replace(right, '\'');
-            if value == "'" {
-                edit.insert(left.end(), '\\');
+            // Characters that a char literal can't hold unescaped, but that a string (or a raw
+            // string) can.
+            let c = value.chars().next().unwrap();
+            let needs_escape = matches!(c, '\'' | '\\' | '\n' | '\r' | '\t');
+            if needs_escape && token.text_without_quotes() == value {
+                edit.replace(quote_offsets.contents, c.escape_default().to_string());
             }
         },
     )
@@ -301,6 +305,57 @@ fn f() {
 fn f() {
     find('\'');
 }
+"#,
+        )
+    }
+
+    #[test]
+    fn replace_raw_string_backslash_with_char() {
+        check_assist(
+            replace_string_with_char,
+            r#"
+fn f() {
+    find($0r"\");
+}
+"#,
+            r#"
+fn f() {
+    find('\\');
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn replace_string_with_literal_tab_with_char() {
+        check_assist(
+            replace_string_with_char,
+            "
+fn f() {
+    find($0\"\t\");
+}
+",
+            r#"
+fn f() {
+    find('\t');
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn replace_char_with_string_tab() {
+        check_assist(
+            replace_char_with_string,
+            r#"
+fn f() {
+    find($0'\t');
+}
+"#,
+            r#"
+fn f() {
+    find("\t");
+}
 "#,
         )
     }
@@ -3,15 +3,16 @@
 use itertools::Itertools;
 
 use syntax::{
-    ast::{self, HasName},
-    ted, AstNode, TextRange,
+    ast::{self, HasAttrs, HasModuleItem, HasName},
+    ted, AstNode, AstToken, Direction, SyntaxKind, SyntaxNode, TextRange,
 };
 
 use crate::{utils::get_methods, AssistContext, AssistId, AssistKind, Assists};
 
 // Assist: sort_items
 //
-// Sorts item members alphabetically: fields, enum variants and methods.
+// Sorts item members alphabetically: fields, enum variants and methods. In a module, sorts its
+// items by name within groups, keeping imports at the top.
 //
 // ```
 // struct $0Foo$0 { second: u32, first: String }
@@ -103,8 +104,12 @@ pub(crate) fn sort_items(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<(
         add_sort_methods_assist(acc, ctx, trait_ast.assoc_item_list()?)
     } else if let Some(impl_ast) = ctx.find_node_at_offset::<ast::Impl>() {
         add_sort_methods_assist(acc, ctx, impl_ast.assoc_item_list()?)
+    } else if let Some(module_ast) = ctx.find_node_at_offset::<ast::Module>() {
+        let item_list = module_ast.item_list()?;
+        add_sort_module_items_assist(acc, ctx, item_list.items().collect(), item_list.syntax())
     } else {
-        None
+        let source_file = ctx.find_node_at_offset::<ast::SourceFile>()?;
+        add_sort_module_items_assist(acc, ctx, source_file.items().collect(), source_file.syntax())
     }
 }
 
@@ -170,6 +175,100 @@ fn add_sort_methods_assist(
     acc.add_rewrite("Sort methods alphabetically", methods, sorted, item_list.syntax().text_range())
 }
 
+fn add_sort_module_items_assist(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+    items: Vec<ast::Item>,
+    target: &SyntaxNode,
+) -> Option<()> {
+    let selection = ctx.selection_trimmed();
+
+    // ignore assist if the selection intersects with an item.
+    if items.iter().any(|item| item.syntax().text_range().intersect(selection).is_some()) {
+        return None;
+    }
+    // Moving items out of or into a run of `#[cfg]`s could change what gets compiled together.
+    let is_cfg = |attr: ast::Attr| attr.simple_name().as_deref() == Some("cfg");
+    if items.iter().any(|item| item.attrs().any(is_cfg)) {
+        cov_mark::hit!(not_applicable_if_module_has_cfg_items);
+        return None;
+    }
+
+    let keys = items.iter().map(item_sort_key).collect::<Option<Vec<_>>>()?;
+    let sorted: Vec<usize> = (0..items.len()).sorted_by(|&a, &b| keys[a].cmp(&keys[b])).collect();
+
+    if sorted.iter().enumerate().all(|(i, &it)| i == it) {
+        cov_mark::hit!(not_applicable_if_sorted_or_empty_or_single);
+        return None;
+    }
+
+    // Comments aren't always attached to the item they describe, so move them by text instead of
+    // rewriting the item nodes.
+    let ranges: Vec<TextRange> = items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| range_with_leading_comments(item, i == 0))
+        .collect();
+    let text = target.text().to_string();
+    let offset = target.text_range().start();
+    acc.add(
+        AssistId("sort_items", AssistKind::RefactorRewrite),
+        "Sort items alphabetically",
+        target.text_range(),
+        |builder| {
+            for (range, &from) in ranges.iter().zip(&sorted) {
+                builder.replace(*range, &text[ranges[from] - offset]);
+            }
+        },
+    )
+}
+
+/// The range of `item` with the comments on the lines before it. For the first item, a comment
+/// followed by a blank line is left in place, as it is more likely about the whole module.
+fn range_with_leading_comments(item: &ast::Item, is_first: bool) -> TextRange {
+    let mut start = item.syntax().text_range().start();
+    for element in item.syntax().siblings_with_tokens(Direction::Prev).skip(1) {
+        let Some(token) = element.as_token() else { break };
+        if let Some(comment) = ast::Comment::cast(token.clone()) {
+            let starts_line = token
+                .prev_token()
+                .map_or(true, |it| it.kind() == SyntaxKind::WHITESPACE && it.text().contains('\n'));
+            if comment.is_inner() || !starts_line {
+                break;
+            }
+            start = token.text_range().start();
+        } else if token.kind() != SyntaxKind::WHITESPACE
+            || (is_first && token.text().contains("\n\n"))
+        {
+            break;
+        }
+    }
+    TextRange::new(start, item.syntax().text_range().end())
+}
+
+/// Items are sorted by name within groups: imports first (in their original order), then types,
+/// constants and statics, functions, modules, and finally unnamed items like impls.
+///
+/// Returns `None` for macros, whose position in the module matters.
+fn item_sort_key(item: &ast::Item) -> Option<(u8, String)> {
+    let (group, name) = match item {
+        ast::Item::Use(_) | ast::Item::ExternCrate(_) => (0, None),
+        ast::Item::Struct(it) => (1, it.name()),
+        ast::Item::Enum(it) => (1, it.name()),
+        ast::Item::Union(it) => (1, it.name()),
+        ast::Item::TypeAlias(it) => (1, it.name()),
+        ast::Item::Trait(it) => (1, it.name()),
+        ast::Item::TraitAlias(it) => (1, it.name()),
+        ast::Item::Const(it) => (2, it.name()),
+        ast::Item::Static(it) => (2, it.name()),
+        ast::Item::Fn(it) => (3, it.name()),
+        ast::Item::Module(it) => (4, it.name()),
+        ast::Item::Impl(_) | ast::Item::ExternBlock(_) => (5, None),
+        ast::Item::MacroCall(_) | ast::Item::MacroRules(_) | ast::Item::MacroDef(_) => return None,
+    };
+    Some((group, name.map_or_else(String::new, |it| it.to_string())))
+}
+
 fn add_sort_fields_assist(
     acc: &mut Assists,
     record_field_list: ast::RecordFieldList,
@@ -682,4 +781,98 @@ enum Bar {
         "#,
         )
     }
+
+    #[test]
+    fn sort_module() {
+        check_assist(
+            sort_items,
+            r#"
+$0mod bar$0 {
+    fn c() {}
+    /// Doc for a.
+    fn a() {}
+    fn b() {}
+}
+        "#,
+            r#"
+mod bar {
+    /// Doc for a.
+    fn a() {}
+    fn b() {}
+    fn c() {}
+}
+        "#,
+        )
+    }
+
+    #[test]
+    fn sort_module_groups() {
+        check_assist(
+            sort_items,
+            r#"
+$0mod bar$0 {
+    use std::fmt;
+    fn f() {}
+    impl S {}
+    const C: u8 = 0;
+    struct S;
+    use std::io;
+    enum E {}
+}
+        "#,
+            r#"
+mod bar {
+    use std::fmt;
+    use std::io;
+    enum E {}
+    struct S;
+    const C: u8 = 0;
+    fn f() {}
+    impl S {}
+}
+        "#,
+        )
+    }
+
+    #[test]
+    fn not_applicable_if_module_has_cfg_items() {
+        cov_mark::check!(not_applicable_if_module_has_cfg_items);
+        check_assist_not_applicable(
+            sort_items,
+            r#"
+$0mod bar$0 {
+    fn b() {}
+    #[cfg(test)]
+    fn a() {}
+}
+        "#,
+        )
+    }
+
+    #[test]
+    fn sort_file_items_with_their_comments() {
+        check_assist(
+            sort_items,
+            r#"
+$0// Helpers for the tests.$0
+
+fn c() {}
+// Runs first.
+
+// Called from `c`.
+fn a() {}
+fn b() {}
+        "#,
+            r#"
+// Helpers for the tests.
+
+// Runs first.
+
+// Called from `c`.
+fn a() {}
+fn b() {}
+fn c() {}
+        "#,
+        )
+    }
 }
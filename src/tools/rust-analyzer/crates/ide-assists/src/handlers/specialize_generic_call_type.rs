@@ -0,0 +1,180 @@
+use hir::{HirDisplay, ModuleDef, PathResolution};
+use ide_db::famous_defs::FamousDefs;
+use syntax::ast::{self, AstNode};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: specialize_generic_call_type
+//
+// Spells out the type a `Default::default()` call is inferred to produce.
+//
+// ```
+// # //- minicore: default
+// struct Config { verbose: bool }
+// impl Default for Config {
+//     fn default() -> Self { Config { verbose: false } }
+// }
+// fn main() {
+//     let config: Config = $0Default::default();
+// }
+// ```
+// ->
+// ```
+// struct Config { verbose: bool }
+// impl Default for Config {
+//     fn default() -> Self { Config { verbose: false } }
+// }
+// fn main() {
+//     let config: Config = Config::default();
+// }
+// ```
+pub(crate) fn specialize_generic_call_type(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let call = ctx.find_node_at_offset::<ast::CallExpr>()?;
+    if call.arg_list()?.args().next().is_some() {
+        return None;
+    }
+    let ast::Expr::PathExpr(callee) = call.expr()? else { return None };
+    let path = callee.path()?;
+    if path.segment()?.name_ref()?.text() != "default" {
+        return None;
+    }
+    let scope = ctx.sema.scope(call.syntax())?;
+    let default_trait = FamousDefs(&ctx.sema, scope.krate()).core_default_Default()?;
+    // `T::default()` and `<T as Default>::default()` already have the type.
+    let trait_path = path.qualifier()?;
+    match ctx.sema.resolve_path(&trait_path)? {
+        PathResolution::Def(ModuleDef::Trait(trait_)) if trait_ == default_trait => {}
+        _ => return None,
+    }
+
+    let ty = ctx.sema.type_of_expr(&ast::Expr::CallExpr(call.clone()))?.original;
+    if ty.contains_unknown() {
+        cov_mark::hit!(specialize_generic_call_type_unknown);
+        return None;
+    }
+    let ty_text = ty.display_source_code(ctx.db(), scope.module().into(), true).ok()?;
+    // In expressions, generic arguments of a path take a turbofish, and other types need angle
+    // brackets.
+    let self_ty = if ty.as_adt().is_some() {
+        ty_text.replacen('<', "::<", 1)
+    } else if ty.as_builtin().is_some() {
+        ty_text.clone()
+    } else {
+        format!("<{ty_text}>")
+    };
+
+    let target = callee.syntax().text_range();
+    let mut add = |qualified: String| {
+        acc.add(
+            AssistId("specialize_generic_call_type", AssistKind::RefactorRewrite),
+            format!("Specialize to `{qualified}()`"),
+            target,
+            |edit| edit.replace(target, qualified),
+        )
+    };
+    add(format!("{self_ty}::default"));
+    // Name the trait the way the call does, which is known to resolve here.
+    add(format!("<{ty_text} as {trait_path}>::default"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_by_label, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn specialize_to_expected_type() {
+        check_assist(
+            specialize_generic_call_type,
+            r#"
+//- minicore: default
+struct Vec<T>(T);
+impl<T> Default for Vec<T> {
+    fn default() -> Self { loop {} }
+}
+fn main() {
+    let x: Vec<i32> = Default::default$0();
+}
+"#,
+            r#"
+struct Vec<T>(T);
+impl<T> Default for Vec<T> {
+    fn default() -> Self { loop {} }
+}
+fn main() {
+    let x: Vec<i32> = Vec::<i32>::default();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn specialize_to_qualified_path() {
+        check_assist_by_label(
+            specialize_generic_call_type,
+            r#"
+//- minicore: default
+fn takes(pair: (u8, bool)) {}
+fn main() {
+    takes($0core::default::Default::default());
+}
+"#,
+            r#"
+fn takes(pair: (u8, bool)) {}
+fn main() {
+    takes(<(u8, bool) as core::default::Default>::default());
+}
+"#,
+            "Specialize to `<(u8, bool) as core::default::Default>::default()`",
+        );
+    }
+
+    #[test]
+    fn specialize_builtin_type() {
+        check_assist(
+            specialize_generic_call_type,
+            r#"
+//- minicore: default
+fn main() {
+    let n: u32 = $0Default::default();
+}
+"#,
+            r#"
+fn main() {
+    let n: u32 = u32::default();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_without_expected_type() {
+        cov_mark::check!(specialize_generic_call_type_unknown);
+        check_assist_not_applicable(
+            specialize_generic_call_type,
+            r#"
+//- minicore: default
+fn main() {
+    let x = $0Default::default();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_typed_call() {
+        check_assist_not_applicable(
+            specialize_generic_call_type,
+            r#"
+//- minicore: default
+fn main() {
+    let n = $0u32::default();
+}
+"#,
+        );
+    }
+}
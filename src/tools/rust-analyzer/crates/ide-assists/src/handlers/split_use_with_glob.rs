@@ -0,0 +1,148 @@
+use syntax::{
+    ast::{self, make, HasVisibility},
+    ted::{self, Position},
+    AstNode,
+};
+
+use crate::{
+    assist_context::{AssistContext, Assists},
+    handlers::unmerge_use::resolve_full_path,
+    AssistId, AssistKind,
+};
+
+// Assist: split_use_with_glob
+//
+// Moves the glob imports of a use list into their own use items.
+//
+// ```
+// use std::{fmt::Debug, io::*$0};
+// ```
+// ->
+// ```
+// use std::fmt::Debug;
+// use std::io::*;
+// ```
+pub(crate) fn split_use_with_glob(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let use_ = ctx.find_node_at_offset::<ast::Use>()?;
+    let (globs, others): (Vec<_>, Vec<_>) = use_
+        .use_tree()?
+        .syntax()
+        .descendants()
+        .filter_map(ast::UseTree::cast)
+        .filter(|tree| tree.use_tree_list().is_none())
+        .partition(|tree| tree.star_token().is_some());
+    // A glob that isn't in a use list already has its own use item.
+    let globs: Vec<_> = globs
+        .into_iter()
+        .filter(|glob| {
+            glob.syntax().parent().map_or(false, |it| ast::UseTreeList::can_cast(it.kind()))
+        })
+        .collect();
+    if globs.is_empty() {
+        return None;
+    }
+    if others.is_empty() {
+        cov_mark::hit!(split_use_with_glob_only_globs);
+        return None;
+    }
+
+    let target = use_.syntax().text_range();
+    acc.add(
+        AssistId("split_use_with_glob", AssistKind::RefactorRewrite),
+        "Split glob imports out of use",
+        target,
+        |builder| {
+            let use_ = builder.make_mut(use_);
+            let globs: Vec<_> = globs.into_iter().map(|glob| builder.make_mut(glob)).collect();
+
+            let new_uses: Vec<_> = globs
+                .iter()
+                .filter_map(|glob| {
+                    let tree = make::use_tree(resolve_full_path(glob)?, None, None, true);
+                    Some(make::use_(use_.visibility(), tree).clone_for_update())
+                })
+                .collect();
+            globs.into_iter().for_each(ast::UseTree::remove_recursive);
+            for new_use in new_uses.into_iter().rev() {
+                ted::insert(Position::after(use_.syntax()), new_use.syntax());
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn split_single_glob() {
+        check_assist(
+            split_use_with_glob,
+            r"
+use foo::{bar, baz::*$0};
+",
+            r"
+use foo::bar;
+use foo::baz::*;
+",
+        );
+    }
+
+    #[test]
+    fn split_glob_of_prefix() {
+        check_assist(
+            split_use_with_glob,
+            r"
+use foo::{*, bar$0};
+",
+            r"
+use foo::bar;
+use foo::*;
+",
+        );
+    }
+
+    #[test]
+    fn split_nested_globs() {
+        check_assist(
+            split_use_with_glob,
+            r"
+pub(crate) use$0 foo::{bar::{a, *}, baz, qux::*};
+",
+            r"
+pub(crate) use foo::{bar::a, baz};
+pub(crate) use foo::bar::*;
+pub(crate) use foo::qux::*;
+",
+        );
+    }
+
+    #[test]
+    fn not_applicable_without_globs() {
+        check_assist_not_applicable(
+            split_use_with_glob,
+            r"
+use foo::{bar, baz$0};
+",
+        );
+        check_assist_not_applicable(
+            split_use_with_glob,
+            r"
+use foo::*$0;
+",
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_only_globs() {
+        cov_mark::check!(split_use_with_glob_only_globs);
+        check_assist_not_applicable(
+            split_use_with_glob,
+            r"
+use foo::{bar::*, baz::*$0};
+",
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use syntax::{ast, AstNode, SyntaxKind, SyntaxToken, TextRange};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: toggle_mutability
+//
+// Toggles the mutability of a reference type, a reference expression or a `let` binding.
+//
+// ```
+// fn main() {
+//     let $0x = 1;
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let mut x = 1;
+// }
+// ```
+pub(crate) fn toggle_mutability(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let (target, mut_token, insert_at, what) = if let Some(ref_type) =
+        ctx.find_node_at_offset::<ast::RefType>()
+    {
+        let ty = ref_type.ty()?;
+        (ref_type.syntax().text_range(), ref_type.mut_token(), ty.syntax(), "reference")
+    } else if let Some(ref_expr) = ctx.find_node_at_offset::<ast::RefExpr>() {
+        if ref_expr.raw_token().is_some() {
+            return None;
+        }
+        let expr = ref_expr.expr()?;
+        (ref_expr.syntax().text_range(), ref_expr.mut_token(), expr.syntax(), "reference")
+    } else {
+        let ident_pat = ctx.find_node_at_offset::<ast::IdentPat>()?;
+        let let_stmt = ident_pat.syntax().ancestors().find_map(ast::LetStmt::cast)?;
+        if !let_stmt.pat()?.syntax().text_range().contains_range(ident_pat.syntax().text_range()) {
+            // The binding is in the initializer or the `else` block.
+            return None;
+        }
+        let name = ident_pat.name()?;
+        (ident_pat.syntax().text_range(), ident_pat.mut_token(), name.syntax(), "binding")
+    };
+
+    match mut_token {
+        Some(mut_token) => acc.add(
+            AssistId("toggle_mutability", AssistKind::RefactorRewrite),
+            format!("Make {what} immutable"),
+            target,
+            |builder| builder.delete(mut_token_range(&mut_token)),
+        ),
+        None => {
+            let insert_at = insert_at.text_range().start();
+            acc.add(
+                AssistId("toggle_mutability", AssistKind::RefactorRewrite),
+                format!("Make {what} mutable"),
+                target,
+                |builder| builder.insert(insert_at, "mut "),
+            )
+        }
+    }
+}
+
+/// The range of `mut_token` along with the whitespace that follows it.
+fn mut_token_range(mut_token: &SyntaxToken) -> TextRange {
+    let end = match mut_token.next_token() {
+        Some(it) if it.kind() == SyntaxKind::WHITESPACE => it.text_range().end(),
+        _ => mut_token.text_range().end(),
+    };
+    TextRange::new(mut_token.text_range().start(), end)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_by_label, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn toggle_let_binding() {
+        check_assist(
+            toggle_mutability,
+            r#"
+fn main() {
+    let $0x = 1;
+}
+"#,
+            r#"
+fn main() {
+    let mut x = 1;
+}
+"#,
+        );
+        check_assist(
+            toggle_mutability,
+            r#"
+fn main() {
+    let (a, mut $0b) = (1, 2);
+}
+"#,
+            r#"
+fn main() {
+    let (a, b) = (1, 2);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn toggle_ref_type() {
+        check_assist_by_label(
+            toggle_mutability,
+            r#"
+fn foo(x: &$0i32) {}
+"#,
+            r#"
+fn foo(x: &mut i32) {}
+"#,
+            "Make reference mutable",
+        );
+        check_assist_by_label(
+            toggle_mutability,
+            r#"
+fn foo<'a>(x: &'a mut$0 i32) {}
+"#,
+            r#"
+fn foo<'a>(x: &'a i32) {}
+"#,
+            "Make reference immutable",
+        );
+    }
+
+    #[test]
+    fn toggle_ref_expr() {
+        check_assist(
+            toggle_mutability,
+            r#"
+fn main() {
+    let mut v = [1];
+    let r = &$0mut v[0];
+}
+"#,
+            r#"
+fn main() {
+    let mut v = [1];
+    let r = &v[0];
+}
+"#,
+        );
+        check_assist(
+            toggle_mutability,
+            r#"
+fn main() {
+    let mut v = 1;
+    drop($0&v);
+}
+"#,
+            r#"
+fn main() {
+    let mut v = 1;
+    drop(&mut v);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_match_binding() {
+        check_assist_not_applicable(
+            toggle_mutability,
+            r#"
+fn main() {
+    match 1 {
+        $0x => {}
+    }
+}
+"#,
+        );
+    }
+}
@@ -57,7 +57,7 @@ pub(crate) fn unmerge_use(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<
     })
 }
 
-fn resolve_full_path(tree: &ast::UseTree) -> Option<ast::Path> {
+pub(crate) fn resolve_full_path(tree: &ast::UseTree) -> Option<ast::Path> {
     let paths = tree
         .syntax()
         .ancestors()
@@ -0,0 +1,271 @@
+use std::iter;
+
+use hir::{known, PathResolution};
+use ide_db::famous_defs::FamousDefs;
+use syntax::{
+    ast::{self, HasArgList},
+    AstNode,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: wrap_in_some
+//
+// Wraps an expression in `Some` where an `Option` of its type is expected.
+//
+// ```
+// # //- minicore: option
+// fn main() {
+//     let x: Option<i32> = 4$02;
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let x: Option<i32> = Some(42);
+// }
+// ```
+pub(crate) fn wrap_in_some(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let innermost = ctx.find_node_at_offset::<ast::Expr>()?;
+    let (expr, expected) = innermost
+        .syntax()
+        .ancestors()
+        .map_while(ast::Expr::cast)
+        .find_map(|expr| Some((expr.clone(), expected_type(ctx, &expr)?)))?;
+
+    let famous_defs = FamousDefs(&ctx.sema, ctx.sema.scope(expr.syntax())?.krate());
+    let option = hir::Adt::Enum(famous_defs.core_option_Option()?);
+    if expected.as_adt() != Some(option) {
+        return None;
+    }
+    let actual = ctx.sema.type_of_expr(&expr)?.original;
+    if !expected.could_unify_with(ctx.db(), &option.ty_with_args(ctx.db(), iter::once(actual))) {
+        cov_mark::hit!(wrap_in_some_type_mismatch);
+        return None;
+    }
+
+    let range = expr.syntax().text_range();
+    acc.add(
+        AssistId("wrap_in_some", AssistKind::RefactorRewrite),
+        "Wrap in `Some`",
+        range,
+        |builder| {
+            builder.insert(range.start(), "Some(");
+            builder.insert(range.end(), ")");
+        },
+    )
+}
+
+// Assist: unwrap_some
+//
+// Removes the `Some` around an expression.
+//
+// ```
+// # //- minicore: option
+// fn main() {
+//     let x = $0Some(42);
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let x = 42;
+// }
+// ```
+pub(crate) fn unwrap_some(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let call = ctx.find_node_at_offset::<ast::CallExpr>()?;
+    let ast::Expr::PathExpr(callee) = call.expr()? else { return None };
+    if !callee.syntax().text_range().contains_range(ctx.selection_trimmed()) {
+        return None;
+    }
+    let PathResolution::Def(hir::ModuleDef::Variant(variant)) =
+        ctx.sema.resolve_path(&callee.path()?)?
+    else {
+        return None;
+    };
+    let famous_defs = FamousDefs(&ctx.sema, ctx.sema.scope(call.syntax())?.krate());
+    if Some(variant.parent_enum(ctx.db())) != famous_defs.core_option_Option()
+        || variant.name(ctx.db()) == known::None
+    {
+        return None;
+    }
+    let mut args = call.arg_list()?.args();
+    let inner = args.next()?;
+    if args.next().is_some() {
+        return None;
+    }
+
+    let target = call.syntax().text_range();
+    acc.add(
+        AssistId("unwrap_some", AssistKind::RefactorRewrite),
+        "Unwrap `Some`",
+        target,
+        |builder| {
+            let needs_parens =
+                call.syntax().parent().map_or(false, |parent| inner.needs_parens_in(parent));
+            let replacement =
+                if needs_parens { format!("({inner})") } else { inner.syntax().text().to_string() };
+            builder.replace(target, replacement);
+        },
+    )
+}
+
+/// The type `expr` is expected to have from its position: the annotated type of a `let`, the type
+/// of a function parameter, or the return type of the enclosing function.
+fn expected_type(ctx: &AssistContext<'_>, expr: &ast::Expr) -> Option<hir::Type> {
+    let parent = expr.syntax().parent()?;
+    if let Some(let_stmt) = ast::LetStmt::cast(parent.clone()) {
+        if let_stmt.initializer()?.syntax() != expr.syntax() {
+            return None;
+        }
+        return ctx.sema.resolve_type(&let_stmt.ty()?);
+    }
+    if let Some(arg_list) = ast::ArgList::cast(parent.clone()) {
+        let idx = arg_list.args().position(|arg| arg.syntax() == expr.syntax())?;
+        let callable = match ast::Expr::cast(arg_list.syntax().parent()?)? {
+            ast::Expr::CallExpr(call) => ctx.sema.resolve_expr_as_callable(&call.expr()?)?,
+            ast::Expr::MethodCallExpr(call) => ctx.sema.resolve_method_call_as_callable(&call)?,
+            _ => return None,
+        };
+        return callable.params(ctx.db()).into_iter().nth(idx).map(|(_, ty)| ty);
+    }
+
+    let is_tail = ast::StmtList::cast(parent.clone())
+        .and_then(|stmt_list| stmt_list.tail_expr())
+        .map_or(false, |tail| tail.syntax() == expr.syntax());
+    let fn_ = if ast::ReturnExpr::can_cast(parent.kind()) {
+        parent
+            .ancestors()
+            .take_while(|it| !ast::ClosureExpr::can_cast(it.kind()))
+            .find_map(ast::Fn::cast)?
+    } else if is_tail {
+        // Only the tail of the function body itself, not of any nested block.
+        let body = parent.parent().and_then(ast::BlockExpr::cast)?;
+        body.syntax().parent().and_then(ast::Fn::cast)?
+    } else {
+        return None;
+    };
+    Some(ctx.sema.to_def(&fn_)?.ret_type(ctx.db()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn wrap_let_initializer() {
+        check_assist(
+            wrap_in_some,
+            r#"
+//- minicore: option
+fn main() {
+    let x: Option<u8> = 4$02;
+}
+"#,
+            r#"
+fn main() {
+    let x: Option<u8> = Some(42);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn wrap_argument_and_tail() {
+        check_assist(
+            wrap_in_some,
+            r#"
+//- minicore: option
+fn take(_: Option<u32>) {}
+fn main() {
+    take(1 $0+ 2);
+}
+"#,
+            r#"
+fn take(_: Option<u32>) {}
+fn main() {
+    take(Some(1 + 2));
+}
+"#,
+        );
+        check_assist(
+            wrap_in_some,
+            r#"
+//- minicore: option
+fn first(v: &[u32]) -> Option<u32> {
+    v[$00]
+}
+"#,
+            r#"
+fn first(v: &[u32]) -> Option<u32> {
+    Some(v[0])
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_option() {
+        cov_mark::check!(wrap_in_some_type_mismatch);
+        check_assist_not_applicable(
+            wrap_in_some,
+            r#"
+//- minicore: option
+fn main() {
+    let x: Option<u8> = None$0;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn unwrap_in_let() {
+        check_assist(
+            unwrap_some,
+            r#"
+//- minicore: option
+fn main() {
+    let x = So$0me(a + b);
+}
+"#,
+            r#"
+fn main() {
+    let x = a + b;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn unwrap_keeps_needed_parens() {
+        check_assist(
+            unwrap_some,
+            r#"
+//- minicore: option
+fn main() {
+    let x = $0Some(a + b).is_some();
+}
+"#,
+            r#"
+fn main() {
+    let x = (a + b).is_some();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_calls() {
+        check_assist_not_applicable(
+            unwrap_some,
+            r#"
+//- minicore: option, result
+fn main() {
+    let x: Result<u8, ()> = $0Ok(1);
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use syntax::{
+    ast::{self, make},
+    ted, AstNode,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: wrap_pattern_in_binding
+//
+// Binds a sub-pattern of a match arm to a new name with an `@` pattern.
+//
+// ```
+// fn foo(x: Option<i32>) {
+//     match x {
+//         Some$0(y) => {}
+//         None => {}
+//     }
+// }
+// ```
+// ->
+// ```
+// fn foo(x: Option<i32>) {
+//     match x {
+//         $0whole @ Some(y) => {}
+//         None => {}
+//     }
+// }
+// ```
+pub(crate) fn wrap_pattern_in_binding(acc: &mut Assists, ctx: &AssistContext<'_>) -> Option<()> {
+    let pat: ast::Pat = ctx.find_node_at_offset()?;
+    let parent = pat.syntax().parent()?;
+    match &pat {
+        // Already a binding.
+        ast::Pat::IdentPat(_) => return None,
+        // `whole @ _` is just a more verbose `whole`.
+        ast::Pat::WildcardPat(_) if ast::MatchArm::can_cast(parent.kind()) => {
+            cov_mark::hit!(wrap_pattern_in_binding_top_level_wildcard);
+            return None;
+        }
+        // `..` can't be bound, and `whole @ A | B` would only bind `A`.
+        ast::Pat::RestPat(_) | ast::Pat::OrPat(_) => return None,
+        _ => (),
+    }
+    if ast::IdentPat::can_cast(parent.kind()) {
+        // The sub-pattern of an existing `name @ pat`.
+        return None;
+    }
+    pat.syntax().ancestors().find_map(ast::MatchArm::cast)?;
+
+    acc.add(
+        AssistId("wrap_pattern_in_binding", AssistKind::RefactorRewrite),
+        "Bind pattern with `@`",
+        pat.syntax().text_range(),
+        |builder| {
+            let new_pat = make::ident_pat(false, false, make::name("whole")).clone_for_update();
+            new_pat.set_pat(Some(pat.clone_subtree().clone_for_update()));
+
+            let pat = builder.make_mut(pat);
+            ted::replace(pat.syntax(), new_pat.syntax());
+
+            if let Some(cap) = ctx.config.snippet_cap {
+                if let Some(name) = new_pat.name() {
+                    builder.add_tabstop_before(cap, name);
+                }
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn wrap_enum_pattern() {
+        check_assist(
+            wrap_pattern_in_binding,
+            r#"
+enum E { A(i32), B }
+
+fn foo(e: E) {
+    match e {
+        E::A$0(x) => {}
+        E::B => {}
+    }
+}
+"#,
+            r#"
+enum E { A(i32), B }
+
+fn foo(e: E) {
+    match e {
+        $0whole @ E::A(x) => {}
+        E::B => {}
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn wrap_nested_tuple_pattern() {
+        check_assist(
+            wrap_pattern_in_binding,
+            r#"
+fn foo(t: (i32, (bool, bool))) {
+    match t {
+        (0, $0(a, b)) => {}
+        _ => {}
+    }
+}
+"#,
+            r#"
+fn foo(t: (i32, (bool, bool))) {
+    match t {
+        (0, $0whole @ (a, b)) => {}
+        _ => {}
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_on_binding() {
+        check_assist_not_applicable(
+            wrap_pattern_in_binding,
+            r#"
+fn foo(x: Option<i32>) {
+    match x {
+        Some(y$0) => {}
+        None => {}
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_on_top_level_wildcard() {
+        cov_mark::check!(wrap_pattern_in_binding_top_level_wildcard);
+        check_assist_not_applicable(
+            wrap_pattern_in_binding,
+            r#"
+fn foo(x: Option<i32>) {
+    match x {
+        Some(y) => {}
+        _$0 => {}
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_outside_match_arm() {
+        check_assist_not_applicable(
+            wrap_pattern_in_binding,
+            r#"
+fn foo(t: (i32, i32)) {
+    let $0(a, b) = t;
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use ide_db::famous_defs::FamousDefs;
+use syntax::{
+    ast::{self, HasArgList},
+    AstNode,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: wrap_unwrap_in_question_mark
+//
+// Replaces an `unwrap` or `expect` call with the `?` operator, in a function returning the same
+// kind of `Option` or `Result`.
+//
+// ```
+// # //- minicore: result
+// fn parse(s: Result<i32, ()>) -> Result<i32, ()> {
+//     let x = s.unwrap$0();
+//     Ok(x + 1)
+// }
+// ```
+// ->
+// ```
+// fn parse(s: Result<i32, ()>) -> Result<i32, ()> {
+//     let x = s?;
+//     Ok(x + 1)
+// }
+// ```
+pub(crate) fn wrap_unwrap_in_question_mark(
+    acc: &mut Assists,
+    ctx: &AssistContext<'_>,
+) -> Option<()> {
+    let name_ref = ctx.find_node_at_offset::<ast::NameRef>()?;
+    let method_call = name_ref.syntax().parent().and_then(ast::MethodCallExpr::cast)?;
+    let method = match name_ref.text().as_str() {
+        "unwrap" if method_call.arg_list()?.args().next().is_none() => "unwrap",
+        "expect" if method_call.arg_list()?.args().count() == 1 => "expect",
+        _ => return None,
+    };
+    let receiver = method_call.receiver()?;
+
+    let krate = ctx.sema.scope(method_call.syntax())?.krate();
+    let famous_defs = FamousDefs(&ctx.sema, krate);
+    let wrapper = |ty: hir::Type| -> Option<hir::Enum> {
+        let hir::Adt::Enum(enum_) = ty.as_adt()? else { return None };
+        let is_wrapper = Some(enum_) == famous_defs.core_option_Option()
+            || Some(enum_) == famous_defs.core_result_Result();
+        is_wrapper.then_some(enum_)
+    };
+
+    let receiver_wrapper = wrapper(ctx.sema.type_of_expr(&receiver)?.original)?;
+    let fn_ret_wrapper = enclosing_fn(&method_call)
+        .and_then(|fn_| ctx.sema.to_def(&fn_))
+        .and_then(|fn_| wrapper(fn_.ret_type(ctx.db())));
+    if fn_ret_wrapper != Some(receiver_wrapper) {
+        cov_mark::hit!(wrap_unwrap_in_question_mark_incompatible_return_type);
+        return None;
+    }
+
+    let target = method_call.syntax().text_range();
+    acc.add(
+        AssistId("wrap_unwrap_in_question_mark", AssistKind::RefactorRewrite),
+        format!("Replace `{method}` with `?`"),
+        target,
+        |builder| builder.replace(target, format!("{receiver}?")),
+    )
+}
+
+/// The function `?` would return from at `expr`, if it isn't inside a closure or an async or try
+/// block.
+fn enclosing_fn(expr: &ast::MethodCallExpr) -> Option<ast::Fn> {
+    for node in expr.syntax().ancestors() {
+        if let Some(fn_) = ast::Fn::cast(node.clone()) {
+            return Some(fn_);
+        }
+        if ast::ClosureExpr::can_cast(node.kind()) {
+            return None;
+        }
+        if let Some(block) = ast::BlockExpr::cast(node) {
+            if matches!(
+                block.modifier(),
+                Some(ast::BlockModifier::Async(_) | ast::BlockModifier::Try(_))
+            ) {
+                return None;
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn unwrap_in_result_fn() {
+        check_assist(
+            wrap_unwrap_in_question_mark,
+            r#"
+//- minicore: result
+fn parse(s: Result<i32, ()>) -> Result<i32, ()> {
+    let x = s.unwrap$0();
+    Ok(x + 1)
+}
+"#,
+            r#"
+fn parse(s: Result<i32, ()>) -> Result<i32, ()> {
+    let x = s?;
+    Ok(x + 1)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn expect_in_option_fn() {
+        check_assist(
+            wrap_unwrap_in_question_mark,
+            r#"
+//- minicore: option
+fn first(v: Option<(i32, i32)>) -> Option<i32> {
+    Some(v.$0expect("no value").0)
+}
+"#,
+            r#"
+fn first(v: Option<(i32, i32)>) -> Option<i32> {
+    Some(v?.0)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_in_unit_fn() {
+        cov_mark::check!(wrap_unwrap_in_question_mark_incompatible_return_type);
+        check_assist_not_applicable(
+            wrap_unwrap_in_question_mark,
+            r#"
+//- minicore: result
+fn parse(s: Result<i32, ()>) -> () {
+    let x = s.unwrap$0();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_option_in_result_fn() {
+        cov_mark::check!(wrap_unwrap_in_question_mark_incompatible_return_type);
+        check_assist_not_applicable(
+            wrap_unwrap_in_question_mark,
+            r#"
+//- minicore: option, result
+fn parse(s: Option<i32>) -> Result<i32, ()> {
+    Ok(s.unwrap$0())
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_in_closure() {
+        cov_mark::check!(wrap_unwrap_in_question_mark_incompatible_return_type);
+        check_assist_not_applicable(
+            wrap_unwrap_in_question_mark,
+            r#"
+//- minicore: option
+fn parse(s: Option<i32>) -> Option<i32> {
+    let f = || s.unwrap$0();
+    Some(f())
+}
+"#,
+        );
+    }
+}
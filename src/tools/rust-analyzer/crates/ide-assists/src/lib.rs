@@ -114,20 +114,38 @@ mod handlers {
     mod bind_unused_param;
     mod bool_to_enum;
     mod change_visibility;
+    mod collapse_nested_or_patterns;
+    mod convert_array_to_vec;
+    mod convert_assert_to_assert_eq;
     mod convert_bool_then;
+    mod convert_closure_block_to_expr;
+    mod convert_closure_to_fn;
+    mod convert_closure_to_fn_trait_object;
     mod convert_comment_block;
+    mod convert_for_to_while_let;
+    mod convert_if_chain_to_match;
     mod convert_integer_literal;
     mod convert_into_to_from;
     mod convert_iter_for_each_to_for;
     mod convert_let_else_to_match;
+    mod convert_let_to_if_let_refutable;
     mod convert_match_to_let_else;
+    mod convert_named_arguments_to_struct_update;
     mod convert_named_struct_to_tuple_struct;
     mod convert_nested_function_to_closure;
+    mod convert_option_map_to_if_let;
+    mod convert_range_to_match_guard;
+    mod convert_string_format_to_inline_args;
+    mod convert_to_const_fn;
+    mod convert_to_guard_clauses;
     mod convert_to_guarded_return;
+    mod convert_to_inline_const;
+    mod convert_trait_object_to_generic;
     mod convert_tuple_return_type_to_struct;
     mod convert_tuple_struct_to_named_struct;
     mod convert_two_arm_bool_match_to_matches_macro;
     mod convert_while_to_loop;
+    mod convert_while_true_to_loop;
     mod destructure_struct_binding;
     mod destructure_tuple_binding;
     mod desugar_doc_comment;
@@ -184,9 +202,11 @@ mod handlers {
     mod number_representation;
     mod promote_local_to_const;
     mod pull_assignment_up;
+    mod pull_closure_arg_into_named;
     mod qualify_method_call;
     mod qualify_path;
     mod raw_string;
+    mod reformat_doc_comment;
     mod remove_dbg;
     mod remove_mut;
     mod remove_parentheses;
@@ -206,9 +226,12 @@ mod handlers {
     mod replace_try_expr_with_match;
     mod replace_turbofish_with_explicit_type;
     mod sort_items;
+    mod specialize_generic_call_type;
     mod split_import;
+    mod split_use_with_glob;
     mod term_search;
     mod toggle_ignore;
+    mod toggle_mutability;
     mod unmerge_match_arm;
     mod unmerge_use;
     mod unnecessary_async;
@@ -216,7 +239,10 @@ mod handlers {
     mod unwrap_block;
     mod unwrap_result_return_type;
     mod unwrap_tuple;
+    mod wrap_in_some;
+    mod wrap_pattern_in_binding;
     mod wrap_return_type_in_result;
+    mod wrap_unwrap_in_question_mark;
 
     pub(crate) fn all() -> &'static [Handler] {
         &[
@@ -234,22 +260,42 @@ pub(crate) fn all() -> &'static [Handler] {
             bind_unused_param::bind_unused_param,
             bool_to_enum::bool_to_enum,
             change_visibility::change_visibility,
+            collapse_nested_or_patterns::collapse_nested_or_patterns,
+            convert_array_to_vec::convert_array_to_vec,
+            convert_array_to_vec::convert_vec_to_array,
+            convert_assert_to_assert_eq::convert_assert_to_assert_eq,
             convert_bool_then::convert_bool_then_to_if,
             convert_bool_then::convert_if_to_bool_then,
+            convert_closure_block_to_expr::convert_closure_block_to_expr,
+            convert_closure_to_fn::convert_closure_to_fn,
+            convert_closure_to_fn_trait_object::convert_closure_to_fn_trait_object,
             convert_comment_block::convert_comment_block,
+            convert_for_to_while_let::convert_for_to_while_let,
+            convert_if_chain_to_match::convert_if_chain_to_match,
             convert_integer_literal::convert_integer_literal,
             convert_into_to_from::convert_into_to_from,
             convert_iter_for_each_to_for::convert_iter_for_each_to_for,
             convert_iter_for_each_to_for::convert_for_loop_with_for_each,
             convert_let_else_to_match::convert_let_else_to_match,
+            convert_let_to_if_let_refutable::convert_let_to_if_let_refutable,
             convert_match_to_let_else::convert_match_to_let_else,
             convert_tuple_return_type_to_struct::convert_tuple_return_type_to_struct,
+            convert_named_arguments_to_struct_update::convert_named_arguments_to_struct_update,
             convert_named_struct_to_tuple_struct::convert_named_struct_to_tuple_struct,
             convert_nested_function_to_closure::convert_nested_function_to_closure,
+            convert_option_map_to_if_let::convert_option_map_to_if_let,
+            convert_range_to_match_guard::convert_range_to_match_guard,
+            convert_string_format_to_inline_args::convert_string_format_to_inline_args,
+            convert_to_const_fn::convert_to_const_fn,
+            convert_to_guard_clauses::convert_to_guard_clauses,
             convert_to_guarded_return::convert_to_guarded_return,
+            convert_to_inline_const::convert_to_inline_const,
+            convert_trait_object_to_generic::convert_trait_object_to_generic,
             convert_tuple_struct_to_named_struct::convert_tuple_struct_to_named_struct,
             convert_two_arm_bool_match_to_matches_macro::convert_two_arm_bool_match_to_matches_macro,
             convert_while_to_loop::convert_while_to_loop,
+            convert_while_true_to_loop::convert_while_true_to_loop,
+            convert_while_true_to_loop::convert_loop_to_while_true,
             desugar_doc_comment::desugar_doc_comment,
             destructure_tuple_binding::destructure_tuple_binding,
             destructure_struct_binding::destructure_struct_binding,
@@ -304,12 +350,14 @@ pub(crate) fn all() -> &'static [Handler] {
             normalize_import::normalize_import,
             number_representation::reformat_number_literal,
             pull_assignment_up::pull_assignment_up,
+            pull_closure_arg_into_named::pull_closure_arg_into_named,
             promote_local_to_const::promote_local_to_const,
             qualify_path::qualify_path,
             qualify_method_call::qualify_method_call,
             raw_string::add_hash,
             raw_string::make_usual_string,
             raw_string::remove_hash,
+            reformat_doc_comment::reformat_doc_comment,
             remove_mut::remove_mut,
             remove_unused_imports::remove_unused_imports,
             remove_unused_param::remove_unused_param,
@@ -331,9 +379,12 @@ pub(crate) fn all() -> &'static [Handler] {
             replace_arith_op::replace_arith_with_checked,
             replace_arith_op::replace_arith_with_saturating,
             sort_items::sort_items,
+            specialize_generic_call_type::specialize_generic_call_type,
             split_import::split_import,
+            split_use_with_glob::split_use_with_glob,
             term_search::term_search,
             toggle_ignore::toggle_ignore,
+            toggle_mutability::toggle_mutability,
             unmerge_match_arm::unmerge_match_arm,
             unmerge_use::unmerge_use,
             unnecessary_async::unnecessary_async,
@@ -341,7 +392,11 @@ pub(crate) fn all() -> &'static [Handler] {
             unwrap_result_return_type::unwrap_result_return_type,
             unwrap_tuple::unwrap_tuple,
             unqualify_method_call::unqualify_method_call,
+            wrap_in_some::wrap_in_some,
+            wrap_in_some::unwrap_some,
+            wrap_pattern_in_binding::wrap_pattern_in_binding,
             wrap_return_type_in_result::wrap_return_type_in_result,
+            wrap_unwrap_in_question_mark::wrap_unwrap_in_question_mark,
             // These are manually sorted for better priorities. By default,
             // priority is determined by the size of the target range (smaller
             // target wins). If the ranges are equal, position in this list is
@@ -33,6 +33,7 @@
     prefer_no_std: false,
     prefer_prelude: true,
     assist_emit_must_use: false,
+    doc_comment_width: 80,
 };
 
 pub(crate) const TEST_CONFIG_NO_SNIPPET_CAP: AssistConfig = AssistConfig {
@@ -48,6 +49,7 @@
     prefer_no_std: false,
     prefer_prelude: true,
     assist_emit_must_use: false,
+    doc_comment_width: 80,
 };
 
 pub(crate) const TEST_CONFIG_IMPORT_ONE: AssistConfig = AssistConfig {
@@ -63,6 +65,7 @@
     prefer_no_std: false,
     prefer_prelude: true,
     assist_emit_must_use: false,
+    doc_comment_width: 80,
 };
 
 pub(crate) fn with_single_file(text: &str) -> (RootDatabase, FileId) {
@@ -345,6 +345,65 @@ pub(crate) fn frobnicate() {}
     )
 }
 
+#[test]
+fn doctest_collapse_nested_or_patterns() {
+    check_doc_test(
+        "collapse_nested_or_patterns",
+        r#####"
+//- minicore: option
+fn classify(x: Option<u8>) -> bool {
+    match x {
+        $0Some(1) | Some(2) | Some(3) => true,
+        _ => false,
+    }
+}
+"#####,
+        r#####"
+fn classify(x: Option<u8>) -> bool {
+    match x {
+        Some(1 | 2 | 3) => true,
+        _ => false,
+    }
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_array_to_vec() {
+    check_doc_test(
+        "convert_array_to_vec",
+        r#####"
+fn main() {
+    let v = [1, 2$0, 3];
+}
+"#####,
+        r#####"
+fn main() {
+    let v = vec![1, 2, 3];
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_assert_to_assert_eq() {
+    check_doc_test(
+        "convert_assert_to_assert_eq",
+        r#####"
+//- minicore: assert, fmt
+fn check(answer: u32) {
+    $0assert!(answer == 42, "wrong answer");
+}
+"#####,
+        r#####"
+fn check(answer: u32) {
+    assert_eq!(answer, 42, "wrong answer");
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_convert_bool_then_to_if() {
     check_doc_test(
@@ -367,6 +426,62 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_convert_closure_block_to_expr() {
+    check_doc_test(
+        "convert_closure_block_to_expr",
+        r#####"
+fn main() {
+    let inc = |x$0| { x + 1 };
+}
+"#####,
+        r#####"
+fn main() {
+    let inc = |x| x + 1;
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_closure_to_fn() {
+    check_doc_test(
+        "convert_closure_to_fn",
+        r#####"
+fn main() {
+    let $0add_one = |x: i32| -> i32 { x + 1 };
+
+    add_one(2);
+}
+"#####,
+        r#####"
+fn main() {
+    fn add_one(x: i32) -> i32 { x + 1 }
+
+    add_one(2);
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_closure_to_fn_trait_object() {
+    check_doc_test(
+        "convert_closure_to_fn_trait_object",
+        r#####"
+//- minicore: fn
+fn main() {
+    let f: _ = $0|x: u32| x + 1;
+}
+"#####,
+        r#####"
+fn main() {
+    let f: _ = Box::new(|x: u32| x + 1) as Box<dyn Fn(u32) -> u32>;
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_convert_for_loop_with_for_each() {
     check_doc_test(
@@ -390,6 +505,55 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_convert_for_to_while_let() {
+    check_doc_test(
+        "convert_for_to_while_let",
+        r#####"
+fn main() {
+    for$0 i in 0..10 {
+        foo(i);
+    }
+}
+"#####,
+        r#####"
+fn main() {
+    let mut it = 0..10;
+    while let Some(i) = it.next() {
+        foo(i);
+    }
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_if_chain_to_match() {
+    check_doc_test(
+        "convert_if_chain_to_match",
+        r#####"
+fn f(x: i32) -> &'static str {
+    $0if x == 1 {
+        "one"
+    } else if x == 2 {
+        "two"
+    } else {
+        "many"
+    }
+}
+"#####,
+        r#####"
+fn f(x: i32) -> &'static str {
+    match x {
+        1 => "one",
+        2 => "two",
+        _ => "many",
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_convert_if_to_bool_then() {
     check_doc_test(
@@ -499,6 +663,49 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_convert_let_to_if_let_refutable() {
+    check_doc_test(
+        "convert_let_to_if_let_refutable",
+        r#####"
+enum Shape { Circle(f32), Square(f32) }
+fn area(shape: Shape) {
+    $0let Shape::Circle(r) = shape;
+    let area = r * r * 3.14;
+}
+"#####,
+        r#####"
+enum Shape { Circle(f32), Square(f32) }
+fn area(shape: Shape) {
+    if let Shape::Circle(r) = shape {
+        let area = r * r * 3.14;
+    }
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_loop_to_while_true() {
+    check_doc_test(
+        "convert_loop_to_while_true",
+        r#####"
+fn main() {
+    $0loop {
+        foo();
+    }
+}
+"#####,
+        r#####"
+fn main() {
+    while true {
+        foo();
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_convert_match_to_let_else() {
     check_doc_test(
@@ -520,6 +727,25 @@ fn foo(opt: Option<()>) {
     )
 }
 
+#[test]
+fn doctest_convert_named_arguments_to_struct_update() {
+    check_doc_test(
+        "convert_named_arguments_to_struct_update",
+        r#####"
+struct Foo { a: u32, b: u32, c: u32 }
+fn with_c(base: Foo) -> Foo {
+    Foo { a: base.a, b: base.b, $0c: 3 }
+}
+"#####,
+        r#####"
+struct Foo { a: u32, b: u32, c: u32 }
+fn with_c(base: Foo) -> Foo {
+    Foo { c: 3, ..base }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_convert_named_struct_to_tuple_struct() {
     check_doc_test(
@@ -586,6 +812,111 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_convert_option_map_to_if_let() {
+    check_doc_test(
+        "convert_option_map_to_if_let",
+        r#####"
+//- minicore: option
+fn main() {
+    let name = Some("world");
+    name.map$0(|n| {
+        greet(n);
+    });
+}
+"#####,
+        r#####"
+fn main() {
+    let name = Some("world");
+    if let Some(n) = name {
+        greet(n);
+    }
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_range_to_match_guard() {
+    check_doc_test(
+        "convert_range_to_match_guard",
+        r#####"
+fn handle(n: u32) {
+    match n {
+        x => $0if (1..=9).contains(&x) { digit(x) },
+    }
+}
+"#####,
+        r#####"
+fn handle(n: u32) {
+    match n {
+        x @ 1..=9 => digit(x),
+        x => {}
+    }
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_string_format_to_inline_args() {
+    check_doc_test(
+        "convert_string_format_to_inline_args",
+        r#####"
+//- minicore: fmt
+fn main() {
+    print!("{} {}$0", x, y + 1);
+}
+"#####,
+        r#####"
+fn main() {
+    print!("{x} {}", y + 1);
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_to_const_fn() {
+    check_doc_test(
+        "convert_to_const_fn",
+        r#####"
+fn $0double(x: u32) -> u32 {
+    x * 2
+}
+"#####,
+        r#####"
+const fn double(x: u32) -> u32 {
+    x * 2
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_to_guard_clauses() {
+    check_doc_test(
+        "convert_to_guard_clauses",
+        r#####"
+//- minicore: option
+fn main() {
+    $0if let Some(a) = foo() {
+        if let Some(b) = bar(a) {
+            baz(b);
+        }
+    }
+}
+"#####,
+        r#####"
+fn main() {
+    let Some(a) = foo() else { return };
+    let Some(b) = bar(a) else { return };
+    baz(b);
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_convert_to_guarded_return() {
     check_doc_test(
@@ -610,6 +941,57 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_convert_to_inline_const() {
+    check_doc_test(
+        "convert_to_inline_const",
+        r#####"
+const $0N: usize = 4;
+
+fn zeros() -> [u8; N] {
+    [0; 4]
+}
+"#####,
+        r#####"
+fn zeros() -> [u8; 4] {
+    [0; 4]
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_trait_object_to_generic() {
+    check_doc_test(
+        "convert_trait_object_to_generic",
+        r#####"
+//- minicore: deref
+#[lang = "owned_box"]
+struct Box<T: ?Sized>(*mut T);
+impl<T: ?Sized> core::ops::Deref for Box<T> {
+    type Target = T;
+    fn deref(&self) -> &T { loop {} }
+}
+trait Shape { fn area(&self) -> f64; }
+fn report(shape: Box<dyn $0Shape>) -> f64 {
+    shape.area()
+}
+"#####,
+        r#####"
+#[lang = "owned_box"]
+struct Box<T: ?Sized>(*mut T);
+impl<T: ?Sized> core::ops::Deref for Box<T> {
+    type Target = T;
+    fn deref(&self) -> &T { loop {} }
+}
+trait Shape { fn area(&self) -> f64; }
+fn report<S: Shape>(shape: S) -> f64 {
+    shape.area()
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_convert_tuple_return_type_to_struct() {
     check_doc_test(
@@ -698,6 +1080,23 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_convert_vec_to_array() {
+    check_doc_test(
+        "convert_vec_to_array",
+        r#####"
+fn main() {
+    let v = vec!$0[0; 4];
+}
+"#####,
+        r#####"
+fn main() {
+    let v = [0; 4];
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_convert_while_to_loop() {
     check_doc_test(
@@ -722,6 +1121,27 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_convert_while_true_to_loop() {
+    check_doc_test(
+        "convert_while_true_to_loop",
+        r#####"
+fn main() {
+    $0while true {
+        foo();
+    }
+}
+"#####,
+        r#####"
+fn main() {
+    loop {
+        foo();
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_destructure_struct_binding() {
     check_doc_test(
@@ -2350,6 +2770,26 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_pull_closure_arg_into_named() {
+    check_doc_test(
+        "pull_closure_arg_into_named",
+        r#####"
+//- minicore: fn
+fn apply(f: impl Fn(u32, bool) -> u32) {}
+fn main() {
+    apply(|$0x, y| x);
+}
+"#####,
+        r#####"
+fn apply(f: impl Fn(u32, bool) -> u32) {}
+fn main() {
+    apply(|x: u32, y: bool| x);
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_qualify_method_call() {
     check_doc_test(
@@ -2396,6 +2836,22 @@ pub mod std { pub mod collections { pub struct HashMap { } } }
     )
 }
 
+#[test]
+fn doctest_reformat_doc_comment() {
+    check_doc_test(
+        "reformat_doc_comment",
+        r#####"
+/// A doc comment$0 that was written as a single line which runs well past the end of the line.
+fn f() {}
+"#####,
+        r#####"
+/// A doc comment that was written as a single line which runs well past the end
+/// of the line.
+fn f() {}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_reformat_number_literal() {
     check_doc_test(
@@ -2977,6 +3433,32 @@ enum Animal {
     )
 }
 
+#[test]
+fn doctest_specialize_generic_call_type() {
+    check_doc_test(
+        "specialize_generic_call_type",
+        r#####"
+//- minicore: default
+struct Config { verbose: bool }
+impl Default for Config {
+    fn default() -> Self { Config { verbose: false } }
+}
+fn main() {
+    let config: Config = $0Default::default();
+}
+"#####,
+        r#####"
+struct Config { verbose: bool }
+impl Default for Config {
+    fn default() -> Self { Config { verbose: false } }
+}
+fn main() {
+    let config: Config = Config::default();
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_split_import() {
     check_doc_test(
@@ -2990,6 +3472,20 @@ fn doctest_split_import() {
     )
 }
 
+#[test]
+fn doctest_split_use_with_glob() {
+    check_doc_test(
+        "split_use_with_glob",
+        r#####"
+use std::{fmt::Debug, io::*$0};
+"#####,
+        r#####"
+use std::fmt::Debug;
+use std::io::*;
+"#####,
+    )
+}
+
 #[test]
 fn doctest_toggle_ignore() {
     check_doc_test(
@@ -3010,6 +3506,23 @@ fn arithmetics {
     )
 }
 
+#[test]
+fn doctest_toggle_mutability() {
+    check_doc_test(
+        "toggle_mutability",
+        r#####"
+fn main() {
+    let $0x = 1;
+}
+"#####,
+        r#####"
+fn main() {
+    let mut x = 1;
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_unmerge_match_arm() {
     check_doc_test(
@@ -3119,6 +3632,24 @@ fn foo() -> i32 { 42i32 }
     )
 }
 
+#[test]
+fn doctest_unwrap_some() {
+    check_doc_test(
+        "unwrap_some",
+        r#####"
+//- minicore: option
+fn main() {
+    let x = $0Some(42);
+}
+"#####,
+        r#####"
+fn main() {
+    let x = 42;
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_unwrap_tuple() {
     check_doc_test(
@@ -3138,6 +3669,47 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_wrap_in_some() {
+    check_doc_test(
+        "wrap_in_some",
+        r#####"
+//- minicore: option
+fn main() {
+    let x: Option<i32> = 4$02;
+}
+"#####,
+        r#####"
+fn main() {
+    let x: Option<i32> = Some(42);
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_wrap_pattern_in_binding() {
+    check_doc_test(
+        "wrap_pattern_in_binding",
+        r#####"
+fn foo(x: Option<i32>) {
+    match x {
+        Some$0(y) => {}
+        None => {}
+    }
+}
+"#####,
+        r#####"
+fn foo(x: Option<i32>) {
+    match x {
+        $0whole @ Some(y) => {}
+        None => {}
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_wrap_return_type_in_result() {
     check_doc_test(
@@ -3151,3 +3723,23 @@ fn foo() -> Result<i32, ${0:_}> { Ok(42i32) }
 "#####,
     )
 }
+
+#[test]
+fn doctest_wrap_unwrap_in_question_mark() {
+    check_doc_test(
+        "wrap_unwrap_in_question_mark",
+        r#####"
+//- minicore: result
+fn parse(s: Result<i32, ()>) -> Result<i32, ()> {
+    let x = s.unwrap$0();
+    Ok(x + 1)
+}
+"#####,
+        r#####"
+fn parse(s: Result<i32, ()>) -> Result<i32, ()> {
+    let x = s?;
+    Ok(x + 1)
+}
+"#####,
+    )
+}
@@ -168,6 +168,12 @@ pub(crate) fn for_variable(expr: &ast::Expr, sema: &Semantics<'_, RootDatabase>)
     "var_name".to_owned()
 }
 
+/// Suggest a name for a value of type `ty`, looking through references and wrapper types.
+pub(crate) fn for_type(ty: &hir::Type, db: &RootDatabase) -> Option<String> {
+    let ty = ty.remove_ref().unwrap_or_else(|| ty.clone());
+    name_of_type(&ty, db)
+}
+
 fn normalize(name: &str) -> Option<String> {
     let name = to_lower_snake_case(name);
 
@@ -3,6 +3,7 @@
 use test_utils::{assert_eq_text, CURSOR_MARKER};
 
 use super::*;
+use crate::imports::merge_imports::try_merge_import_group;
 
 #[test]
 fn trailing_comment_in_empty_file() {
@@ -985,6 +986,24 @@ fn merge_last_fail3() {
     );
 }
 
+#[test]
+fn merge_many_imports_as_a_group() {
+    let imports: String = (0..200).map(|i| format!("use std::m{i:03};\n")).collect();
+    let file = ast::SourceFile::parse(&imports).tree();
+    let group: Vec<_> = file.syntax().children().filter_map(ast::Use::cast).collect();
+
+    let merged = try_merge_import_group(&group, MergeBehavior::Crate).unwrap();
+    let names: Vec<_> = (0..200).map(|i| format!("m{i:03}")).collect();
+    assert_eq!(merged.to_string(), format!("use std::{{{}}};", names.join(", ")));
+}
+
+#[test]
+fn merge_import_group_with_other_visibility_fails() {
+    let file = ast::SourceFile::parse("use std::fmt;\npub use std::io;\n").tree();
+    let group: Vec<_> = file.syntax().children().filter_map(ast::Use::cast).collect();
+    assert!(try_merge_import_group(&group, MergeBehavior::Crate).is_none());
+}
+
 #[test]
 fn guess_empty() {
     check_guess("", ImportGranularityGuess::Unknown);
@@ -84,6 +84,30 @@ pub fn try_merge_trees(
     Some(lhs)
 }
 
+/// Merges all imports of `group` into its first one, keeping them intact.
+///
+/// Unlike folding [`try_merge_imports`] over the group, this copies and normalizes the merged
+/// import only once. Returned AST is mutable.
+pub fn try_merge_import_group(group: &[ast::Use], merge: MergeBehavior) -> Option<ast::Use> {
+    let (first, rest) = group.split_first()?;
+    let lhs = first.clone_subtree().clone_for_update();
+    for rhs in rest {
+        if !eq_visibility(first.visibility(), rhs.visibility())
+            || !eq_attrs(first.attrs(), rhs.attrs())
+        {
+            return None;
+        }
+        let rhs_tree = rhs.use_tree()?.clone_subtree().clone_for_update();
+        // The merge may replace the use tree of `lhs`, so it has to be looked up every time.
+        try_merge_trees_mut(&lhs.use_tree()?, &rhs_tree, merge)?;
+    }
+
+    // Ignore `None` result because normalization should not affect the merge result.
+    try_normalize_use_tree_mut(&lhs.use_tree()?, merge.into());
+
+    Some(lhs)
+}
+
 fn try_merge_trees_mut(lhs: &ast::UseTree, rhs: &ast::UseTree, merge: MergeBehavior) -> Option<()> {
     if merge == MergeBehavior::One {
         lhs.wrap_in_tree_list();
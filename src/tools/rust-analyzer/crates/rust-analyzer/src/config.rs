@@ -62,6 +62,8 @@
 // parsing the old name.
 config_data! {
     struct ConfigData {
+        /// Column width that doc comments are reflowed to by the `reformat_doc_comment` assist.
+        assist_docCommentWidth: usize          = "80",
         /// Whether to insert #[must_use] when generating `as_` methods
         /// for enum variants.
         assist_emitMustUse: bool               = "false",
@@ -1581,6 +1583,7 @@ pub fn assist(&self) -> AssistConfig {
             prefer_no_std: self.data.imports_preferNoStd,
             prefer_prelude: self.data.imports_preferPrelude,
             assist_emit_must_use: self.data.assist_emitMustUse,
+            doc_comment_width: self.data.assist_docCommentWidth,
         }
     }
 
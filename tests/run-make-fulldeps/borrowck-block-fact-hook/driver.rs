@@ -0,0 +1,87 @@
+#![feature(rustc_private)]
+
+//! This program implements a rustc driver that retrieves the borrowck facts of each function one
+//! basic block at a time, and checks that it gets each block once, in order, and the same facts
+//! as when retrieving them all at once. It also streams the facts themselves at the same time, and
+//! checks that all the blocks are handed over before their CFG edges are streamed.
+
+extern crate rustc_borrowck;
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_session;
+
+use rustc_borrowck::consumers::{self, ConsumerHooks, ConsumerOptions, PoloniusFact};
+use rustc_hir::def_id::LocalDefId;
+use rustc_interface::Config;
+use rustc_middle::mir::BasicBlock;
+use rustc_middle::query::queries::mir_borrowck::ProvidedValue;
+use rustc_middle::ty::TyCtxt;
+use rustc_middle::util::Providers;
+use rustc_session::Session;
+use std::cell::RefCell;
+
+fn main() {
+    let exit_code = rustc_driver::catch_with_exit_code(move || {
+        let rustc_args: Vec<_> = std::env::args().collect();
+        let mut callbacks = CompilerCalls;
+        rustc_driver::RunCompiler::new(&rustc_args, &mut callbacks).run()
+    });
+    std::process::exit(exit_code);
+}
+
+pub struct CompilerCalls;
+
+impl rustc_driver::Callbacks for CompilerCalls {
+    // In this callback we override the mir_borrowck query.
+    fn config(&mut self, config: &mut Config) {
+        assert!(config.override_queries.is_none());
+        config.override_queries = Some(override_queries);
+    }
+}
+
+fn override_queries(_session: &Session, local: &mut Providers) {
+    local.mir_borrowck = mir_borrowck;
+}
+
+fn mir_borrowck<'tcx>(tcx: TyCtxt<'tcx>, def_id: LocalDefId) -> ProvidedValue<'tcx> {
+    let opts = ConsumerOptions::PoloniusInputFacts;
+    let name = tcx.item_name(def_id.to_def_id());
+
+    let blocks = RefCell::new(Vec::new());
+    let mut cfg_edges = Vec::new();
+    let mut block_fact_hook = |block: BasicBlock, facts: &consumers::PoloniusInput| {
+        blocks.borrow_mut().push(block);
+        cfg_edges.push(facts.cfg_edge.len());
+    };
+    // The number of blocks seen by the time the first CFG edge is streamed.
+    let mut blocks_before_cfg_edges = None;
+    let mut fact_hook = |fact: &PoloniusFact| {
+        if let PoloniusFact::CfgEdge(..) = fact {
+            blocks_before_cfg_edges.get_or_insert_with(|| blocks.borrow().len());
+        }
+    };
+    let hooks = ConsumerHooks {
+        fact_hook: Some(&mut fact_hook),
+        block_fact_hook: Some(&mut block_fact_hook),
+        ..Default::default()
+    };
+    let streamed = consumers::get_body_with_borrowck_facts_with_hooks(tcx, def_id, opts, hooks);
+    let all_at_once = consumers::get_body_with_borrowck_facts(tcx, def_id, opts);
+
+    let blocks = blocks.into_inner();
+    let in_order = blocks.iter().copied().eq(streamed.body.basic_blocks.indices());
+    let blocks_first = blocks_before_cfg_edges == Some(blocks.len());
+    let growing = cfg_edges.windows(2).all(|counts| counts[0] < counts[1]);
+    let same_facts = streamed.input_facts_in_memory() == all_at_once.input_facts_in_memory();
+    println!("{name}: hook called once per block in order: {in_order}");
+    println!("{name}: new `cfg_edge` facts in each block: {growing}");
+    println!("{name}: same facts as all at once: {same_facts}");
+    println!("{name}: all blocks before the first streamed CFG edge: {blocks_first}");
+
+    let mut providers = Providers::default();
+    rustc_borrowck::provide(&mut providers);
+    let original_mir_borrowck = providers.mir_borrowck;
+    original_mir_borrowck(tcx, def_id)
+}
@@ -0,0 +1,19 @@
+pub fn straight_line(x: u32) -> u32 {
+    let y = x + 1;
+    y * 2
+}
+
+pub fn branches(v: &mut Vec<u32>, flag: bool) -> Option<&u32> {
+    if flag {
+        v.push(1);
+    }
+    v.first()
+}
+
+pub fn loops(v: &[u32]) -> u32 {
+    let mut sum = 0;
+    for x in v {
+        sum += x;
+    }
+    sum
+}
@@ -0,0 +1,71 @@
+#![feature(rustc_private)]
+
+//! This program implements a rustc driver that re-solves the region
+//! constraints of a function with a borrow error, once as computed by the
+//! borrow checker and once without the constraint introduced by returning the
+//! borrowed argument, which makes the error disappear.
+
+extern crate rustc_borrowck;
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_infer;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_session;
+
+use rustc_borrowck::consumers::{self, ConsumerOptions};
+use rustc_hir::def_id::LocalDefId;
+use rustc_infer::infer::TyCtxtInferExt;
+use rustc_interface::Config;
+use rustc_middle::mir::ConstraintCategory;
+use rustc_middle::query::queries::mir_borrowck::ProvidedValue;
+use rustc_middle::ty::TyCtxt;
+use rustc_middle::util::Providers;
+use rustc_session::Session;
+
+fn main() {
+    let exit_code = rustc_driver::catch_with_exit_code(move || {
+        let rustc_args: Vec<_> = std::env::args().collect();
+        let mut callbacks = CompilerCalls;
+        rustc_driver::RunCompiler::new(&rustc_args, &mut callbacks).run()
+    });
+    std::process::exit(exit_code);
+}
+
+pub struct CompilerCalls;
+
+impl rustc_driver::Callbacks for CompilerCalls {
+    // In this callback we override the mir_borrowck query.
+    fn config(&mut self, config: &mut Config) {
+        assert!(config.override_queries.is_none());
+        config.override_queries = Some(override_queries);
+    }
+}
+
+fn override_queries(_session: &Session, local: &mut Providers) {
+    local.mir_borrowck = mir_borrowck;
+}
+
+fn mir_borrowck<'tcx>(tcx: TyCtxt<'tcx>, def_id: LocalDefId) -> ProvidedValue<'tcx> {
+    let opts = ConsumerOptions::RegionInferenceContext;
+    let body_with_facts = consumers::get_body_with_borrowck_facts(tcx, def_id, opts);
+    let name = tcx.item_name(def_id.to_def_id());
+    let regioncx = &body_with_facts.region_inference_context;
+    let body = &body_with_facts.body;
+    let infcx = tcx.infer_ctxt().build();
+
+    let errors = regioncx.solve_with_constraint_delta(&infcx, body, &[], &[]);
+    println!("{}: {} region error(s)", name, errors.len());
+
+    let returns: Vec<_> = regioncx
+        .outlives_constraints()
+        .filter(|constraint| matches!(constraint.category, ConstraintCategory::Return(_)))
+        .collect();
+    let errors = regioncx.solve_with_constraint_delta(&infcx, body, &[], &returns);
+    println!("{}: region error(s) without the return constraint: {}", name, errors.len());
+
+    let mut providers = Providers::default();
+    rustc_borrowck::provide(&mut providers);
+    let original_mir_borrowck = providers.mir_borrowck;
+    original_mir_borrowck(tcx, def_id)
+}
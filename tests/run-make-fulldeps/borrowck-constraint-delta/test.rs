@@ -0,0 +1,3 @@
+pub fn escape<'a, 'b>(x: &'a u32, _y: &'b u32) -> &'b u32 {
+    x
+}
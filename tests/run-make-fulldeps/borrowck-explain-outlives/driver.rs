@@ -0,0 +1,80 @@
+#![feature(rustc_private)]
+
+//! This program implements a rustc driver that asks the region inference
+//! context to explain why two regions are related, for every pair of regions
+//! connected by two consecutive outlives constraints.
+
+extern crate rustc_borrowck;
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_session;
+
+use rustc_borrowck::consumers::{self, ConsumerOptions};
+use rustc_hir::def_id::LocalDefId;
+use rustc_interface::Config;
+use rustc_middle::query::queries::mir_borrowck::ProvidedValue;
+use rustc_middle::ty::TyCtxt;
+use rustc_middle::util::Providers;
+use rustc_session::Session;
+
+fn main() {
+    let exit_code = rustc_driver::catch_with_exit_code(move || {
+        let rustc_args: Vec<_> = std::env::args().collect();
+        let mut callbacks = CompilerCalls;
+        rustc_driver::RunCompiler::new(&rustc_args, &mut callbacks).run()
+    });
+    std::process::exit(exit_code);
+}
+
+pub struct CompilerCalls;
+
+impl rustc_driver::Callbacks for CompilerCalls {
+    // In this callback we override the mir_borrowck query.
+    fn config(&mut self, config: &mut Config) {
+        assert!(config.override_queries.is_none());
+        config.override_queries = Some(override_queries);
+    }
+}
+
+fn override_queries(_session: &Session, local: &mut Providers) {
+    local.mir_borrowck = mir_borrowck;
+}
+
+fn mir_borrowck<'tcx>(tcx: TyCtxt<'tcx>, def_id: LocalDefId) -> ProvidedValue<'tcx> {
+    let opts = ConsumerOptions::RegionInferenceContext;
+    let body_with_facts = consumers::get_body_with_borrowck_facts(tcx, def_id, opts);
+    let name = tcx.item_name(def_id.to_def_id());
+    let regioncx = &body_with_facts.region_inference_context;
+    // `'static` outlives every region, so it is related to everything in one hop.
+    let fr_static = regioncx.to_region_vid(tcx.lifetimes.re_static);
+
+    let constraints: Vec<_> = regioncx.outlives_constraints().collect();
+    let mut two_hop_chains = 0;
+    for first in &constraints {
+        for second in constraints.iter().filter(|c| c.sup == first.sub) {
+            let (sup, sub) = (first.sup, second.sub);
+            let direct = constraints.iter().any(|c| c.sup == sup && c.sub == sub);
+            if sup == fr_static || sup == sub || direct {
+                continue;
+            }
+            let path = regioncx.explain_outlives(sup, sub).expect("regions should be related");
+            assert_eq!(path.len(), 2);
+            assert_eq!((path[0].sup, path[1].sub), (sup, sub));
+            assert_eq!(path[0].sub, path[1].sup);
+            two_hop_chains += 1;
+        }
+    }
+    println!("{}: two-hop chains found: {}", name, two_hop_chains > 0);
+
+    // Explaining the reverse relation must terminate, even on cycles.
+    for constraint in &constraints {
+        let _ = regioncx.explain_outlives(constraint.sub, constraint.sup);
+    }
+
+    let mut providers = Providers::default();
+    rustc_borrowck::provide(&mut providers);
+    let original_mir_borrowck = providers.mir_borrowck;
+    original_mir_borrowck(tcx, def_id)
+}
@@ -0,0 +1,10 @@
+pub fn two_hops(x: &u32) -> &u32 {
+    let y: &u32 = x;
+    let z: &u32 = y;
+    z
+}
+
+// Invariance relates the regions both ways, which makes a cycle.
+pub fn cycle<'a>(x: &mut &'a u32, y: &'a u32) {
+    *x = y;
+}
@@ -0,0 +1,66 @@
+#![feature(rustc_private)]
+
+//! This program implements a rustc driver that serializes the Polonius input
+//! facts of each function in memory, and checks that they match the facts
+//! returned alongside the body.
+
+extern crate rustc_borrowck;
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_session;
+
+use rustc_borrowck::consumers::{self, ConsumerOptions};
+use rustc_hir::def_id::LocalDefId;
+use rustc_interface::Config;
+use rustc_middle::query::queries::mir_borrowck::ProvidedValue;
+use rustc_middle::ty::TyCtxt;
+use rustc_middle::util::Providers;
+use rustc_session::Session;
+
+fn main() {
+    let exit_code = rustc_driver::catch_with_exit_code(move || {
+        let rustc_args: Vec<_> = std::env::args().collect();
+        let mut callbacks = CompilerCalls;
+        rustc_driver::RunCompiler::new(&rustc_args, &mut callbacks).run()
+    });
+    std::process::exit(exit_code);
+}
+
+pub struct CompilerCalls;
+
+impl rustc_driver::Callbacks for CompilerCalls {
+    // In this callback we override the mir_borrowck query.
+    fn config(&mut self, config: &mut Config) {
+        assert!(config.override_queries.is_none());
+        config.override_queries = Some(override_queries);
+    }
+}
+
+fn override_queries(_session: &Session, local: &mut Providers) {
+    local.mir_borrowck = mir_borrowck;
+}
+
+fn mir_borrowck<'tcx>(tcx: TyCtxt<'tcx>, def_id: LocalDefId) -> ProvidedValue<'tcx> {
+    let opts = ConsumerOptions::PoloniusInputFacts;
+    let body_with_facts = consumers::get_body_with_borrowck_facts(tcx, def_id, opts);
+    let relations = body_with_facts.input_facts_in_memory().unwrap();
+
+    // Each fact is serialized on its own line.
+    let cfg_edge = &relations["cfg_edge"];
+    assert!(!cfg_edge.is_empty());
+    let lines = cfg_edge.iter().filter(|&&byte| byte == b'\n').count();
+    assert_eq!(lines, body_with_facts.input_facts.as_ref().unwrap().cfg_edge.len());
+
+    println!(
+        "{}: {} relations, cfg_edge is non-empty",
+        tcx.item_name(def_id.to_def_id()),
+        relations.len()
+    );
+
+    let mut providers = Providers::default();
+    rustc_borrowck::provide(&mut providers);
+    let original_mir_borrowck = providers.mir_borrowck;
+    original_mir_borrowck(tcx, def_id)
+}
@@ -0,0 +1,3 @@
+pub fn first(v: &Vec<u32>) -> Option<&u32> {
+    if v.is_empty() { None } else { Some(&v[0]) }
+}
@@ -0,0 +1,72 @@
+#![feature(rustc_private)]
+
+//! This program implements a rustc driver that retrieves the Polonius input
+//! facts of each function, and decodes a `loan_killed_at` fact using the
+//! borrow set and location table returned along with them.
+
+extern crate rustc_borrowck;
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_session;
+
+use rustc_borrowck::consumers::{self, ConsumerOptions, RichLocation};
+use rustc_hir::def_id::LocalDefId;
+use rustc_interface::Config;
+use rustc_middle::mir::StatementKind;
+use rustc_middle::query::queries::mir_borrowck::ProvidedValue;
+use rustc_middle::ty::TyCtxt;
+use rustc_middle::util::Providers;
+use rustc_session::Session;
+
+fn main() {
+    let exit_code = rustc_driver::catch_with_exit_code(move || {
+        let rustc_args: Vec<_> = std::env::args().collect();
+        let mut callbacks = CompilerCalls;
+        rustc_driver::RunCompiler::new(&rustc_args, &mut callbacks).run()
+    });
+    std::process::exit(exit_code);
+}
+
+pub struct CompilerCalls;
+
+impl rustc_driver::Callbacks for CompilerCalls {
+    // In this callback we override the mir_borrowck query.
+    fn config(&mut self, config: &mut Config) {
+        assert!(config.override_queries.is_none());
+        config.override_queries = Some(override_queries);
+    }
+}
+
+fn override_queries(_session: &Session, local: &mut Providers) {
+    local.mir_borrowck = mir_borrowck;
+}
+
+fn mir_borrowck<'tcx>(tcx: TyCtxt<'tcx>, def_id: LocalDefId) -> ProvidedValue<'tcx> {
+    let opts = ConsumerOptions::PoloniusInputFacts;
+    let body_with_facts = consumers::get_body_with_borrowck_facts(tcx, def_id, opts);
+    let input_facts = body_with_facts.input_facts.as_ref().unwrap();
+    let location_table = body_with_facts.location_table.as_ref().unwrap();
+
+    // The loan of `x` is killed when `x` is overwritten.
+    let &(loan, point) = input_facts.loan_killed_at.first().unwrap();
+    let borrowed_place = body_with_facts.borrow_set[loan].borrowed_place;
+    let RichLocation::Mid(location) = location_table.to_location(point) else {
+        panic!("loans are killed at mid points");
+    };
+    let statement = body_with_facts.body.stmt_at(location).left().unwrap();
+    let StatementKind::Assign(assign) = &statement.kind else {
+        panic!("unexpected statement killing a loan: {statement:?}");
+    };
+    println!(
+        "{}: the loan of `{borrowed_place:?}` is killed by an assignment to `{:?}`",
+        tcx.item_name(def_id.to_def_id()),
+        assign.0,
+    );
+
+    let mut providers = Providers::default();
+    rustc_borrowck::provide(&mut providers);
+    let original_mir_borrowck = providers.mir_borrowck;
+    original_mir_borrowck(tcx, def_id)
+}
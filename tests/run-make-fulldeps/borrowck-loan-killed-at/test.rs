@@ -0,0 +1,5 @@
+pub fn scoped(mut x: u32) -> u32 {
+    let _r = &x;
+    x = 1;
+    x
+}
@@ -0,0 +1,4 @@
+pub fn pass_through(v: Vec<u32>) -> Vec<u32> {
+    let w = v;
+    w
+}
@@ -0,0 +1,65 @@
+#![feature(rustc_private)]
+
+//! This program implements a rustc driver that runs borrowck on each function,
+//! with and without Polonius, and checks the timings of each phase of computing
+//! the regions.
+
+extern crate rustc_borrowck;
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_session;
+
+use rustc_borrowck::consumers::{self, ConsumerOptions};
+use rustc_hir::def_id::LocalDefId;
+use rustc_interface::Config;
+use rustc_middle::query::queries::mir_borrowck::ProvidedValue;
+use rustc_middle::ty::TyCtxt;
+use rustc_middle::util::Providers;
+use rustc_session::Session;
+
+fn main() {
+    let exit_code = rustc_driver::catch_with_exit_code(move || {
+        let rustc_args: Vec<_> = std::env::args().collect();
+        let mut callbacks = CompilerCalls;
+        rustc_driver::RunCompiler::new(&rustc_args, &mut callbacks).run()
+    });
+    std::process::exit(exit_code);
+}
+
+pub struct CompilerCalls;
+
+impl rustc_driver::Callbacks for CompilerCalls {
+    // In this callback we override the mir_borrowck query.
+    fn config(&mut self, config: &mut Config) {
+        assert!(config.override_queries.is_none());
+        config.override_queries = Some(override_queries);
+    }
+}
+
+fn override_queries(_session: &Session, local: &mut Providers) {
+    local.mir_borrowck = mir_borrowck;
+}
+
+fn mir_borrowck<'tcx>(tcx: TyCtxt<'tcx>, def_id: LocalDefId) -> ProvidedValue<'tcx> {
+    let name = tcx.item_name(def_id.to_def_id());
+
+    // Polonius ran, so the type check and region solving did some work.
+    let opts = ConsumerOptions::PoloniusOutputFacts;
+    let timings = consumers::get_body_with_borrowck_facts(tcx, def_id, opts).timings;
+    assert!(!timings.type_check.is_zero(), "{name}: type check not timed");
+    assert!(!timings.solve.is_zero(), "{name}: solve not timed");
+    println!("{name}: type check and solve timed");
+
+    // Without output facts, Polonius doesn't run at all.
+    let opts = ConsumerOptions::PoloniusInputFacts;
+    let timings = consumers::get_body_with_borrowck_facts(tcx, def_id, opts).timings;
+    assert!(timings.polonius.is_zero(), "{name}: polonius timed without output facts");
+    println!("{name}: polonius not timed without output facts");
+
+    let mut providers = Providers::default();
+    rustc_borrowck::provide(&mut providers);
+    let original_mir_borrowck = providers.mir_borrowck;
+    original_mir_borrowck(tcx, def_id)
+}
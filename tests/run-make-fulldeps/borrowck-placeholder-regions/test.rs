@@ -0,0 +1,7 @@
+pub fn pick<'a, 'b>(x: &'a u32, _y: &'b u32) -> &'a u32 {
+    x
+}
+
+pub fn narrow() -> for<'x> fn(&'x u32, &'x u32) -> &'x u32 {
+    pick
+}
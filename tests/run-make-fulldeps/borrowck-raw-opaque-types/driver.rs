@@ -0,0 +1,72 @@
+#![feature(rustc_private)]
+
+//! This program implements a rustc driver that retrieves the borrowck facts of each function
+//! twice, and prints the hidden types of its opaque types with and without remapping them.
+
+extern crate rustc_borrowck;
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_session;
+
+use rustc_borrowck::consumers::{self, ConsumerHooks, ConsumerOptions};
+use rustc_hir::def_id::LocalDefId;
+use rustc_interface::Config;
+use rustc_middle::query::queries::mir_borrowck::ProvidedValue;
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_middle::ty::{GenericArgKind, Ty, TyCtxt};
+use rustc_middle::util::Providers;
+use rustc_session::Session;
+
+fn main() {
+    let exit_code = rustc_driver::catch_with_exit_code(move || {
+        let rustc_args: Vec<_> = std::env::args().collect();
+        let mut callbacks = CompilerCalls;
+        rustc_driver::RunCompiler::new(&rustc_args, &mut callbacks).run()
+    });
+    std::process::exit(exit_code);
+}
+
+pub struct CompilerCalls;
+
+impl rustc_driver::Callbacks for CompilerCalls {
+    // In this callback we override the mir_borrowck query.
+    fn config(&mut self, config: &mut Config) {
+        assert!(config.override_queries.is_none());
+        config.override_queries = Some(override_queries);
+    }
+}
+
+fn override_queries(_session: &Session, local: &mut Providers) {
+    local.mir_borrowck = mir_borrowck;
+}
+
+fn mir_borrowck<'tcx>(tcx: TyCtxt<'tcx>, def_id: LocalDefId) -> ProvidedValue<'tcx> {
+    let opts = ConsumerOptions::RegionInferenceContext;
+    let name = tcx.item_name(def_id.to_def_id());
+    let remapped = consumers::get_body_with_borrowck_facts(tcx, def_id, opts);
+    for hidden in remapped.opaque_type_values.values() {
+        println!("{name}: remapped hidden type {}", describe(hidden.ty));
+    }
+    let hooks = ConsumerHooks { remap_opaque_types: false, ..Default::default() };
+    let raw = consumers::get_body_with_borrowck_facts_with_hooks(tcx, def_id, opts, hooks);
+    assert!(raw.opaque_type_values.is_empty());
+    for hidden in raw.raw_opaque_type_values.unwrap().values() {
+        println!("{name}: raw hidden type {}", describe(hidden.ty));
+    }
+
+    let mut providers = Providers::default();
+    rustc_borrowck::provide(&mut providers);
+    let original_mir_borrowck = providers.mir_borrowck;
+    original_mir_borrowck(tcx, def_id)
+}
+
+fn describe(ty: Ty<'_>) -> String {
+    let has_vars = ty.walk().any(|arg| match arg.unpack() {
+        GenericArgKind::Lifetime(region) => region.is_var(),
+        _ => false,
+    });
+    let vars = if has_vars { "with region variables" } else { "without region variables" };
+    with_no_trimmed_paths!(format!("{ty}, {vars}"))
+}
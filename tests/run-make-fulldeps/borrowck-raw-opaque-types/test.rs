@@ -0,0 +1,3 @@
+pub fn first<'a>(v: &'a [u32]) -> impl Iterator<Item = &'a u32> + 'a {
+    v.iter()
+}
@@ -0,0 +1,66 @@
+#![feature(rustc_private)]
+
+//! This program implements a rustc driver that counts the region errors of each
+//! function as the borrow checker finds them, before they are reported.
+
+extern crate rustc_borrowck;
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_session;
+
+use rustc_borrowck::consumers::{self, ConsumerHooks, ConsumerOptions, RegionErrorKind};
+use rustc_hir::def_id::LocalDefId;
+use rustc_interface::Config;
+use rustc_middle::query::queries::mir_borrowck::ProvidedValue;
+use rustc_middle::ty::TyCtxt;
+use rustc_middle::util::Providers;
+use rustc_session::Session;
+
+fn main() {
+    let exit_code = rustc_driver::catch_with_exit_code(move || {
+        let rustc_args: Vec<_> = std::env::args().collect();
+        let mut callbacks = CompilerCalls;
+        rustc_driver::RunCompiler::new(&rustc_args, &mut callbacks).run()
+    });
+    std::process::exit(exit_code);
+}
+
+pub struct CompilerCalls;
+
+impl rustc_driver::Callbacks for CompilerCalls {
+    // In this callback we override the mir_borrowck query.
+    fn config(&mut self, config: &mut Config) {
+        assert!(config.override_queries.is_none());
+        config.override_queries = Some(override_queries);
+    }
+}
+
+fn override_queries(_session: &Session, local: &mut Providers) {
+    local.mir_borrowck = mir_borrowck;
+}
+
+fn mir_borrowck<'tcx>(tcx: TyCtxt<'tcx>, def_id: LocalDefId) -> ProvidedValue<'tcx> {
+    let mut errors = 0;
+    let mut outlives_errors = 0;
+    let opts = ConsumerOptions::RegionInferenceContext;
+    let mut region_error_hook = |error: &RegionErrorKind<'tcx>| {
+        errors += 1;
+        if let RegionErrorKind::RegionError { .. } = error {
+            outlives_errors += 1;
+        }
+    };
+    let hooks =
+        ConsumerHooks { region_error_hook: Some(&mut region_error_hook), ..Default::default() };
+    consumers::get_body_with_borrowck_facts_with_hooks(tcx, def_id, opts, hooks);
+    println!(
+        "{}: {errors} region errors, {outlives_errors} of them `RegionError`s",
+        tcx.item_name(def_id.to_def_id()),
+    );
+
+    let mut providers = Providers::default();
+    rustc_borrowck::provide(&mut providers);
+    let original_mir_borrowck = providers.mir_borrowck;
+    original_mir_borrowck(tcx, def_id)
+}
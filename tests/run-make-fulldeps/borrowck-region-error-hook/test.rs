@@ -0,0 +1,7 @@
+pub fn fine<'a>(x: &'a u32) -> &'a u32 {
+    x
+}
+
+pub fn mismatch<'a, 'b>(x: &'a u32, _y: &'b u32) -> &'b u32 {
+    x
+}
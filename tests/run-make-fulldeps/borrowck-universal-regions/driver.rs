@@ -0,0 +1,70 @@
+#![feature(rustc_private)]
+
+//! This program implements a rustc driver that prints the named lifetimes of
+//! each function, as found in its universal regions, along with how the
+//! borrow checker classifies their region vids.
+
+extern crate rustc_borrowck;
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_session;
+extern crate rustc_span;
+
+use rustc_borrowck::consumers::{self, ConsumerOptions};
+use rustc_hir::def_id::LocalDefId;
+use rustc_interface::Config;
+use rustc_middle::query::queries::mir_borrowck::ProvidedValue;
+use rustc_middle::ty::TyCtxt;
+use rustc_middle::util::Providers;
+use rustc_session::Session;
+use rustc_span::symbol::kw;
+
+fn main() {
+    let exit_code = rustc_driver::catch_with_exit_code(move || {
+        let rustc_args: Vec<_> = std::env::args().collect();
+        let mut callbacks = CompilerCalls;
+        rustc_driver::RunCompiler::new(&rustc_args, &mut callbacks).run()
+    });
+    std::process::exit(exit_code);
+}
+
+pub struct CompilerCalls;
+
+impl rustc_driver::Callbacks for CompilerCalls {
+    // In this callback we override the mir_borrowck query.
+    fn config(&mut self, config: &mut Config) {
+        assert!(config.override_queries.is_none());
+        config.override_queries = Some(override_queries);
+    }
+}
+
+fn override_queries(_session: &Session, local: &mut Providers) {
+    local.mir_borrowck = mir_borrowck;
+}
+
+fn mir_borrowck<'tcx>(tcx: TyCtxt<'tcx>, def_id: LocalDefId) -> ProvidedValue<'tcx> {
+    let opts = ConsumerOptions::RegionInferenceContext;
+    let body_with_facts = consumers::get_body_with_borrowck_facts(tcx, def_id, opts);
+    let universal_regions = body_with_facts.region_inference_context.universal_regions();
+
+    let mut named: Vec<_> = universal_regions
+        .named_universal_regions()
+        .filter_map(|(region, vid)| {
+            let name = region.get_name().filter(|name| *name != kw::StaticLifetime)?;
+            assert_eq!(universal_regions.to_region_vid(region), vid);
+            assert!(universal_regions.is_universal_region(vid));
+            let classification = universal_regions.region_classification(vid).unwrap();
+            Some(format!("{name} ({classification:?})"))
+        })
+        .collect();
+    named.sort();
+    named.dedup();
+    println!("{}: {}", tcx.item_name(def_id.to_def_id()), named.join(", "));
+
+    let mut providers = Providers::default();
+    rustc_borrowck::provide(&mut providers);
+    let original_mir_borrowck = providers.mir_borrowck;
+    original_mir_borrowck(tcx, def_id)
+}
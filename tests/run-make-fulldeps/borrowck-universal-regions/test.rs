@@ -0,0 +1,7 @@
+pub fn late_bound<'a, 'b>(x: &'a u32, _y: &'b u32) -> &'a u32 {
+    x
+}
+
+pub fn early_bound<'a: 'b, 'b>(x: &'a u32) -> &'b u32 {
+    x
+}
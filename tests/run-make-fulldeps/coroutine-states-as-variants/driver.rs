@@ -0,0 +1,80 @@
+#![feature(rustc_private)]
+
+//! This program implements a rustc driver that lists the states of every
+//! coroutine in the crate, as seen by pattern analysis when
+//! `coroutine_states_as_variants` is enabled.
+
+extern crate rustc_arena;
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_pattern_analysis;
+
+use rustc_arena::DroplessArena;
+use rustc_driver::Compilation;
+use rustc_hir::def::DefKind;
+use rustc_interface::interface::Compiler;
+use rustc_interface::Queries;
+use rustc_middle::ty;
+use rustc_pattern_analysis::constructor::ConstructorSet;
+use rustc_pattern_analysis::rustc::RustcMatchCheckCtxt;
+
+fn main() {
+    let exit_code = rustc_driver::catch_with_exit_code(move || {
+        let rustc_args: Vec<_> = std::env::args().collect();
+        let mut callbacks = CompilerCalls;
+        rustc_driver::RunCompiler::new(&rustc_args, &mut callbacks).run()
+    });
+    std::process::exit(exit_code);
+}
+
+pub struct CompilerCalls;
+
+impl rustc_driver::Callbacks for CompilerCalls {
+    fn after_analysis<'tcx>(
+        &mut self,
+        compiler: &Compiler,
+        queries: &'tcx Queries<'tcx>,
+    ) -> Compilation {
+        compiler.sess.dcx().abort_if_errors();
+        queries.global_ctxt().unwrap().enter(|tcx| {
+            let dropless_arena = DroplessArena::default();
+            for def_id in tcx.hir().body_owners() {
+                let is_coroutine = matches!(tcx.def_kind(def_id), DefKind::Closure)
+                    && tcx.is_coroutine(def_id.to_def_id());
+                if !is_coroutine {
+                    continue;
+                }
+                let cx = RustcMatchCheckCtxt {
+                    tcx,
+                    typeck_results: tcx.typeck(def_id),
+                    module: tcx.parent_module_from_def_id(def_id).to_def_id(),
+                    param_env: tcx.param_env(def_id),
+                    dropless_arena: &dropless_arena,
+                    match_lint_level: tcx.local_def_id_to_hir_id(def_id),
+                    whole_match_span: None,
+                    scrut_span: tcx.def_span(def_id),
+                    refutable: true,
+                    known_valid_scrutinee: true,
+                    witness_display_limit: 3,
+                    exclusive_range_witnesses: false,
+                    coroutine_states_as_variants: true,
+                };
+                let coroutine_ty = tcx.type_of(def_id).instantiate_identity();
+                let Ok(ConstructorSet::Variants { variants, non_exhaustive }) =
+                    cx.ctors_for_ty(cx.reveal_opaque_ty(coroutine_ty))
+                else {
+                    panic!("coroutine states should be listed as variants");
+                };
+                assert!(!non_exhaustive);
+                println!("{}:", tcx.def_path_str(def_id));
+                for (idx, _) in variants.iter_enumerated() {
+                    println!("    {}", ty::CoroutineArgs::variant_name(idx));
+                }
+            }
+        });
+
+        Compilation::Continue
+    }
+}
@@ -0,0 +1,10 @@
+#![feature(coroutines, coroutine_trait)]
+
+use std::ops::Coroutine;
+
+pub fn two_yields() -> impl Coroutine<Yield = u32, Return = ()> {
+    || {
+        yield 1;
+        yield 2;
+    }
+}
@@ -18,7 +18,9 @@
 extern crate rustc_middle;
 extern crate rustc_session;
 
-use rustc_borrowck::consumers::{self, BodyWithBorrowckFacts, ConsumerOptions};
+use rustc_borrowck::consumers::{
+    self, BodyWithBorrowckFacts, ConsumerHooks, ConsumerOptions, PoloniusFact,
+};
 use rustc_driver::Compilation;
 use rustc_hir::def::DefKind;
 use rustc_hir::def_id::LocalDefId;
@@ -98,11 +100,27 @@ fn after_analysis<'tcx>(
 
             // See what bodies were borrow checked.
             let mut bodies = get_bodies(tcx);
-            bodies.sort_by(|(def_id1, _), (def_id2, _)| def_id1.cmp(def_id2));
+            bodies.sort_by(|(def_path1, ..), (def_path2, ..)| def_path1.cmp(def_path2));
             println!("Bodies retrieved for:");
-            for (def_id, body) in bodies {
-                println!("{}", def_id);
-                assert!(body.input_facts.unwrap().cfg_edge.len() > 0);
+            for (def_path, _, body) in &bodies {
+                println!("{}", def_path);
+                assert!(body.input_facts.as_ref().unwrap().cfg_edge.len() > 0);
+            }
+
+            // Closures propagate their outlives requirements to their creator.
+            println!("Closure requirements:");
+            for (def_path, def_id, _) in &bodies {
+                if !tcx.is_closure_like(def_id.to_def_id()) {
+                    continue;
+                }
+                let Some(requirements) = &tcx.mir_borrowck(*def_id).closure_requirements else {
+                    continue;
+                };
+                for (subject, outlived_free_region) in
+                    consumers::closure_outlives_requirements(tcx, requirements)
+                {
+                    println!("{}: {:?}: {:?}", def_path, subject, outlived_free_region);
+                }
             }
         });
 
@@ -128,7 +146,17 @@ fn override_queries(_session: &Session, local: &mut Providers) {
 
 fn mir_borrowck<'tcx>(tcx: TyCtxt<'tcx>, def_id: LocalDefId) -> ProvidedValue<'tcx> {
     let opts = ConsumerOptions::PoloniusInputFacts;
-    let body_with_facts = consumers::get_body_with_borrowck_facts(tcx, def_id, opts);
+    let mut cfg_edges = 0;
+    let mut fact_hook = |fact: &PoloniusFact| {
+        if let PoloniusFact::CfgEdge(..) = fact {
+            cfg_edges += 1;
+        }
+    };
+    let hooks = ConsumerHooks { fact_hook: Some(&mut fact_hook), ..Default::default() };
+    let body_with_facts =
+        consumers::get_body_with_borrowck_facts_with_hooks(tcx, def_id, opts, hooks);
+    // The hook sees every fact that ends up in the input facts.
+    assert_eq!(cfg_edges, body_with_facts.input_facts.as_ref().unwrap().cfg_edge.len());
     // SAFETY: The reader casts the 'static lifetime to 'tcx before using it.
     let body_with_facts: BodyWithBorrowckFacts<'static> =
         unsafe { std::mem::transmute(body_with_facts) };
@@ -143,7 +171,9 @@ fn mir_borrowck<'tcx>(tcx: TyCtxt<'tcx>, def_id: LocalDefId) -> ProvidedValue<'t
 }
 
 /// Pull MIR bodies stored in the thread-local.
-fn get_bodies<'tcx>(tcx: TyCtxt<'tcx>) -> Vec<(String, BodyWithBorrowckFacts<'tcx>)> {
+fn get_bodies<'tcx>(
+    tcx: TyCtxt<'tcx>,
+) -> Vec<(String, LocalDefId, BodyWithBorrowckFacts<'tcx>)> {
     MIR_BODIES.with(|state| {
         let mut map = state.borrow_mut();
         map.drain()
@@ -152,7 +182,9 @@ fn get_bodies<'tcx>(tcx: TyCtxt<'tcx>) -> Vec<(String, BodyWithBorrowckFacts<'tc
                 // SAFETY: For soundness we need to ensure that the bodies have
                 // the same lifetime (`'tcx`), which they had before they were
                 // stored in the thread local.
-                (def_path.to_string_no_crate_verbose(), unsafe { std::mem::transmute(body) })
+                (def_path.to_string_no_crate_verbose(), def_id, unsafe {
+                    std::mem::transmute(body)
+                })
             })
             .collect()
     })
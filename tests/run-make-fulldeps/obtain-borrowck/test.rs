@@ -26,7 +26,17 @@ const fn foo() -> usize {
     1
 }
 
+fn store_ref() {
+    let x = 44;
+    let y = 22;
+    let mut p = &x;
+    let mut closure = || p = &y;
+    closure();
+    let _ = *p;
+}
+
 fn main() {
     let bar: [Bar; foo()] = [Bar::new()];
     assert_eq!(bar[0].provided(), foo());
+    store_ref();
 }
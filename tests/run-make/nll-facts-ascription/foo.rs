@@ -0,0 +1,9 @@
+pub fn ascribed<'a>(x: &'a u32) -> u32 {
+    let y: &'a u32 = x;
+    *y
+}
+
+pub fn unascribed<'a>(x: &'a u32) -> u32 {
+    let y = x;
+    *y
+}
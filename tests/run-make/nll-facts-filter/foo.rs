@@ -0,0 +1,9 @@
+pub fn keep_me(x: &mut Vec<u32>) -> &u32 {
+    x.push(1);
+    &x[0]
+}
+
+pub fn skip_me(x: &mut Vec<u32>) -> &u32 {
+    x.push(2);
+    &x[0]
+}
@@ -0,0 +1,3 @@
+pub fn shorten<'a, 'b: 'a, 'c: 'b>(x: &'c u32) -> &'a u32 {
+    x
+}
@@ -0,0 +1,3 @@
+pub fn push_len(v: &mut Vec<usize>) {
+    v.push(v.len());
+}
@@ -0,0 +1,19 @@
+pub fn describe(x: Option<&mut Vec<u32>>) -> &'static str {
+    match x {
+        Some(v) if v.is_empty() => "empty",
+        Some(v) => {
+            v.push(1);
+            "non-empty"
+        }
+        None => "none",
+    }
+}
+
+pub fn scoped_borrow(v: &[u32]) -> u32 {
+    let first = {
+        let x = v[0];
+        let r = &x;
+        *r
+    };
+    first + 1
+}
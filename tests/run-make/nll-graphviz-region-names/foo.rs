@@ -0,0 +1,3 @@
+pub fn first<'a>(x: &'a [u8]) -> &'a u8 {
+    &x[0]
+}
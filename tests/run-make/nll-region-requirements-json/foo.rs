@@ -0,0 +1,19 @@
+#![feature(rustc_attrs)]
+
+use std::cell::Cell;
+
+fn establish_relationships<'a, F>(_cell_a: Cell<&'a u32>, _closure: F)
+where
+    F: for<'x, 'y> FnMut(Cell<&'a &'x u32>, Cell<&'y &'a u32>, Cell<&'x u32>, Cell<&'y u32>),
+{
+}
+
+fn demand_y<'x, 'y>(_cell_x: Cell<&'x u32>, _cell_y: Cell<&'y u32>, _y: &'y u32) {}
+
+#[rustc_regions]
+pub fn supply<'a>(cell_a: Cell<&'a u32>) {
+    establish_relationships(cell_a, |_outlives1, _outlives2, x, y| {
+        let p = x.get();
+        demand_y(x, y, p)
+    });
+}
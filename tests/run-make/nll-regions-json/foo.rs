@@ -0,0 +1,4 @@
+pub fn first(v: &[u32]) -> u32 {
+    let r = &v[0];
+    *r
+}
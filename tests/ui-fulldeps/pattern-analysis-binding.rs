@@ -0,0 +1,94 @@
+//@ run-pass
+// Test that lowering a pattern for exhaustiveness checking records the binding a subpattern came
+// from, and that the innermost binding is the one kept for nested bindings.
+
+//@ ignore-stage1
+//@ ignore-cross-compile
+//@ ignore-remote
+
+#![feature(rustc_private)]
+
+extern crate rustc_arena;
+extern crate rustc_driver;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_pattern_analysis;
+
+use std::io::Write;
+
+use rustc_arena::DroplessArena;
+use rustc_driver::Compilation;
+use rustc_interface::interface;
+use rustc_interface::Queries;
+use rustc_middle::thir::PatKind;
+use rustc_pattern_analysis::rustc::RustcMatchCheckCtxt;
+
+struct LowerBinding {
+    checked: bool,
+}
+
+impl rustc_driver::Callbacks for LowerBinding {
+    fn after_expansion<'tcx>(
+        &mut self,
+        _compiler: &interface::Compiler,
+        queries: &'tcx Queries<'tcx>,
+    ) -> Compilation {
+        queries.global_ctxt().unwrap().enter(|tcx| {
+            let def_id = tcx
+                .hir()
+                .body_owners()
+                .find(|&def_id| tcx.item_name(def_id.to_def_id()).as_str() == "nested")
+                .unwrap();
+            let (thir, _) = tcx.thir_body(def_id).unwrap();
+            let thir = thir.borrow();
+            let pat = thir.params.iter().next().unwrap().pat.as_deref().unwrap();
+
+            // `x @ y @ (_, _)`: the outer binding's subpattern is the binding of `y`.
+            let PatKind::Binding { subpattern: Some(inner), .. } = &pat.kind else { panic!() };
+            let PatKind::Binding { var: y, subpattern: Some(_), .. } = inner.kind else { panic!() };
+
+            let arena = DroplessArena::default();
+            let lint_level = tcx.local_def_id_to_hir_id(def_id);
+            let cx = RustcMatchCheckCtxt {
+                tcx,
+                typeck_results: tcx.typeck(def_id),
+                module: tcx.parent_module(lint_level).to_def_id(),
+                param_env: tcx.param_env(def_id),
+                dropless_arena: &arena,
+                match_lint_level: lint_level,
+                whole_match_span: None,
+                scrut_span: pat.span,
+                refutable: false,
+                known_valid_scrutinee: true,
+                witness_display_limit: 3,
+                exclusive_range_witnesses: false,
+                coroutine_states_as_variants: false,
+            };
+            let lowered = cx.lower_pat(pat);
+            assert_eq!(RustcMatchCheckCtxt::binding_hir_id(&lowered), Some(y.0));
+            // The fields of the tuple are not bound themselves.
+            assert!(lowered.iter_fields().all(|field| field.binding().is_none()));
+        });
+        self.checked = true;
+        Compilation::Stop
+    }
+}
+
+fn main() {
+    let path = "pattern_analysis_binding_input.rs";
+    let mut file = std::fs::File::create(path).unwrap();
+    write!(file, "pub fn nested(x @ y @ (_, _): (u8, u8)) -> u8 {{ x.0 + y.1 }}").unwrap();
+    let args = vec![
+        "rustc".to_string(),
+        "--crate-type=lib".to_string(),
+        "--crate-name".to_string(),
+        "input".to_string(),
+        path.to_string(),
+    ];
+    let mut callbacks = LowerBinding { checked: false };
+    rustc_driver::catch_fatal_errors(|| {
+        rustc_driver::RunCompiler::new(&args, &mut callbacks).run().ok();
+    })
+    .ok();
+    assert!(callbacks.checked);
+}
@@ -0,0 +1,110 @@
+//@ run-pass
+// Test that `pattern_is_irrefutable` tells patterns that match every value of the scrutinee from
+// the ones that don't.
+
+//@ ignore-stage1
+//@ ignore-cross-compile
+//@ ignore-remote
+
+#![feature(rustc_private)]
+
+extern crate rustc_arena;
+extern crate rustc_driver;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_pattern_analysis;
+extern crate rustc_span;
+
+use std::io::Write;
+
+use rustc_arena::DroplessArena;
+use rustc_driver::Compilation;
+use rustc_interface::Queries;
+use rustc_interface::interface;
+use rustc_middle::thir::Pat;
+use rustc_middle::ty::TyCtxt;
+use rustc_pattern_analysis::rustc::RustcMatchCheckCtxt;
+use rustc_span::def_id::LocalDefId;
+
+struct CheckIrrefutable {
+    checked: bool,
+}
+
+fn body_named(tcx: TyCtxt<'_>, name: &str) -> LocalDefId {
+    tcx.hir()
+        .body_owners()
+        .find(|&def_id| tcx.item_name(def_id.to_def_id()).as_str() == name)
+        .unwrap()
+}
+
+fn is_irrefutable<'tcx>(tcx: TyCtxt<'tcx>, def_id: LocalDefId, pat: &Pat<'tcx>) -> bool {
+    let arena = DroplessArena::default();
+    let lint_level = tcx.local_def_id_to_hir_id(def_id);
+    let cx = RustcMatchCheckCtxt {
+        tcx,
+        typeck_results: tcx.typeck(def_id),
+        module: tcx.parent_module(lint_level).to_def_id(),
+        param_env: tcx.param_env(def_id),
+        dropless_arena: &arena,
+        match_lint_level: lint_level,
+        whole_match_span: None,
+        scrut_span: pat.span,
+        refutable: true,
+        known_valid_scrutinee: true,
+        witness_display_limit: 3,
+        exclusive_range_witnesses: false,
+        coroutine_states_as_variants: false,
+    };
+    let lowered = Box::leak(Box::new(cx.lower_pat(pat)));
+    cx.pattern_is_irrefutable(lowered, pat.ty)
+}
+
+impl rustc_driver::Callbacks for CheckIrrefutable {
+    fn after_expansion<'tcx>(
+        &mut self,
+        _compiler: &interface::Compiler,
+        queries: &'tcx Queries<'tcx>,
+    ) -> Compilation {
+        queries.global_ctxt().unwrap().enter(|tcx| {
+            // `(a, b)` binds both fields of the tuple, whatever they are.
+            let def_id = body_named(tcx, "tuple");
+            let (thir, _) = tcx.thir_body(def_id).unwrap();
+            let thir = thir.borrow();
+            let pat = thir.params.iter().next().unwrap().pat.as_deref().unwrap();
+            assert!(is_irrefutable(tcx, def_id, pat));
+
+            // `Some(_)` doesn't match `None`.
+            let def_id = body_named(tcx, "option");
+            let (thir, _) = tcx.thir_body(def_id).unwrap();
+            let thir = thir.borrow();
+            let pat = &thir.arms.iter().next().unwrap().pattern;
+            assert!(!is_irrefutable(tcx, def_id, pat));
+        });
+        self.checked = true;
+        Compilation::Stop
+    }
+}
+
+fn main() {
+    let path = "pattern_analysis_irrefutable_input.rs";
+    let mut file = std::fs::File::create(path).unwrap();
+    write!(
+        file,
+        "pub fn tuple((a, b): (u8, Option<u8>)) -> u8 {{ a + b.unwrap_or(0) }}
+         pub fn option(x: Option<u8>) -> u8 {{ match x {{ Some(_) => 1, None => 0 }} }}"
+    )
+    .unwrap();
+    let args = vec![
+        "rustc".to_string(),
+        "--crate-type=lib".to_string(),
+        "--crate-name".to_string(),
+        "input".to_string(),
+        path.to_string(),
+    ];
+    let mut callbacks = CheckIrrefutable { checked: false };
+    rustc_driver::catch_fatal_errors(|| {
+        rustc_driver::RunCompiler::new(&args, &mut callbacks).run().ok();
+    })
+    .ok();
+    assert!(callbacks.checked);
+}
@@ -0,0 +1,12 @@
+//@ check-pass
+
+// Only patterns that match every value of the scrutinee make the `else` useless.
+
+fn main() {
+    let pair = (1, Some(2));
+
+    let (a, b) = pair else { return }; //~ WARN irrefutable `let...else` pattern
+    let _ = (a, b);
+
+    let (_, Some(_)) = pair else { return };
+}
@@ -0,0 +1,17 @@
+// Check that the inferred opaque types are dumped in a stable order.
+
+//@ check-pass
+//@ compile-flags:-Zverbose-internals
+//@ normalize-stderr-test: "DefId\(0:\d+ ~ (\w+)\[[0-9a-f]{4}\]" -> "DefId(0:ID ~ $1[HASH]"
+//@ normalize-stderr-test: "span: .*" -> "span: SPAN,"
+
+#![feature(rustc_attrs)]
+
+use std::fmt::Debug;
+
+#[rustc_regions]
+fn two_opaques() -> (impl Debug, impl Debug) {
+    (0u8, 1u16)
+}
+
+fn main() {}
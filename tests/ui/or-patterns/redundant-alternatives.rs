@@ -0,0 +1,21 @@
+#![deny(unreachable_patterns)]
+
+// Check that redundant alternatives of an or-pattern are reported even when the arm as a whole is
+// reachable, both at the top level of an arm and nested inside another pattern.
+#[rustfmt::skip]
+fn main() {
+    match Some(0u8) {
+        Some(1) | Some(1) | None => {} //~ ERROR unreachable pattern
+        _ => {}
+    }
+    match Some(0u8) {
+        Some(1 | 1) => {} //~ ERROR unreachable pattern
+        _ => {}
+    }
+    // Alternatives can also be subsumed by earlier arms.
+    match Some(0u8) {
+        Some(1) => {}
+        Some(1 | 2) => {} //~ ERROR unreachable pattern
+        _ => {}
+    }
+}
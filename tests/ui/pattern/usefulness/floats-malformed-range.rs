@@ -0,0 +1,25 @@
+#![deny(unreachable_patterns)]
+
+// Check that float ranges that are reversed or have a NaN endpoint are rejected with E0030 when
+// the pattern is built, before exhaustiveness checking ever sees them, and that the rest of the
+// match doesn't get missing or unreachable pattern errors.
+
+const NAN: f64 = f64::NAN;
+
+fn main() {
+    match 0.0f64 {
+        5.0..=1.0 => {} //~ ERROR lower range bound must be less than or equal to upper
+        0.0..=1.0 => {}
+    }
+
+    match 0.0f32 {
+        1.0..=-1.0 => {} //~ ERROR lower range bound must be less than or equal to upper
+        _ => {}
+    }
+
+    match 0.0f64 {
+        NAN..=1.0 => {} //~ ERROR cannot use NaN in patterns
+        //~^ ERROR lower range bound must be less than or equal to upper
+        0.0..=1.0 => {}
+    }
+}
@@ -0,0 +1,24 @@
+#![feature(exclusive_range_pattern)]
+#![deny(unreachable_patterns)]
+
+// Check that an arm made unreachable by a single earlier range points at that range.
+fn main() {
+    match 0u8 {
+        0..=255 => {}
+        5 => {} //~ ERROR unreachable pattern
+    }
+    match 0i32 {
+        0..10 => {}
+        10 => {}
+        3 => {} //~ ERROR unreachable pattern
+        5..=7 => {} //~ ERROR unreachable pattern
+        _ => {}
+    }
+    // Jointly covered arms don't point anywhere.
+    match 0u8 {
+        0..5 => {}
+        5..10 => {}
+        3..=7 => {} //~ ERROR unreachable pattern
+        _ => {}
+    }
+}
@@ -0,0 +1,16 @@
+#![feature(exclusive_range_pattern)]
+
+// Check that matches written with exclusive ranges get exclusive range witnesses, as long as the
+// witness doesn't extend to the maximum of the type.
+fn main() {
+    match 0u8 {
+        //~^ ERROR non-exhaustive patterns: `10_u8..20_u8` not covered
+        0..10 => {}
+        20..255 => {}
+        255 => {}
+    }
+    match 0u8 {
+        //~^ ERROR non-exhaustive patterns: `10_u8..=u8::MAX` not covered
+        0..10 => {}
+    }
+}
@@ -0,0 +1,11 @@
+//@ compile-flags: -Zpattern-witness-display-limit=0
+// Check that a `-Zpattern-witness-display-limit` of 0 still lists one witness.
+enum Enum {
+    A, B, C, D, E, F, G, H, I, J, K
+}
+
+fn main() {
+    match (Enum::A, ()) { //~ ERROR non-exhaustive
+        (Enum::A, _) => {}
+    }
+}
@@ -0,0 +1,11 @@
+//@ compile-flags: -Zpattern-witness-display-limit=5
+// Check that `-Zpattern-witness-display-limit` sets how many witnesses are listed.
+enum Enum {
+    A, B, C, D, E, F, G, H, I, J, K
+}
+
+fn main() {
+    match (Enum::A, ()) { //~ ERROR non-exhaustive
+        (Enum::A, _) => {}
+    }
+}
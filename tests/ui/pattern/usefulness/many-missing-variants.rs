@@ -0,0 +1,10 @@
+// Check that a match missing many variants lists the first few witnesses and counts the rest.
+enum Enum {
+    A, B, C, D, E, F, G, H, I, J, K
+}
+
+fn main() {
+    match (Enum::A, ()) { //~ ERROR non-exhaustive
+        (Enum::A, _) => {}
+    }
+}
@@ -0,0 +1,10 @@
+// Witnesses for `&mut T` scrutinees keep the mutability of the reference.
+fn check(list: &mut [bool]) {
+    match list {
+    //~^ ERROR `&mut [false, ..]` not covered
+        &mut [] => {},
+        &mut [true, ..] => {},
+    }
+}
+
+fn main() {}
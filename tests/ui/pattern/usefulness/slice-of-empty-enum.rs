@@ -0,0 +1,45 @@
+//@ check-pass
+// Slices whose element type is empty only have the empty slice as a constructor. An enum is empty
+// when all of its variants are, so a partially-inhabited enum doesn't count.
+#![feature(exhaustive_patterns)]
+#![feature(never_type)]
+#![deny(unreachable_patterns)]
+
+enum Void {}
+
+enum AllEmpty {
+    A(!),
+    B(Void),
+}
+
+enum PartiallyInhabited {
+    A(Void),
+    B,
+}
+
+fn never(x: &[!]) {
+    match x {
+        &[] => {}
+    }
+}
+
+fn empty_enum(x: &[Void]) {
+    match x {
+        &[] => {}
+    }
+}
+
+fn all_variants_empty(x: &[AllEmpty]) {
+    match x {
+        &[] => {}
+    }
+}
+
+fn partially_inhabited(x: &[PartiallyInhabited]) {
+    match x {
+        &[] => {}
+        &[PartiallyInhabited::B, ..] => {}
+    }
+}
+
+fn main() {}
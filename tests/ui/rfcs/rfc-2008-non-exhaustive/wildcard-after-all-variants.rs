@@ -0,0 +1,37 @@
+//@ aux-build:enums.rs
+// A wildcard after all the known variants of a `#[non_exhaustive]` enum is only required when the
+// enum comes from another crate. In the defining crate the attribute doesn't apply, so the
+// wildcard is unreachable.
+
+#![deny(unreachable_patterns)]
+
+extern crate enums;
+
+use enums::NonExhaustiveEnum;
+
+#[non_exhaustive]
+pub enum LocalNonExhaustiveEnum {
+    Unit,
+    Tuple(u32),
+    Struct { field: u32 },
+}
+
+fn foreign(x: NonExhaustiveEnum) {
+    match x {
+        NonExhaustiveEnum::Unit => {}
+        NonExhaustiveEnum::Tuple(_) => {}
+        NonExhaustiveEnum::Struct { .. } => {}
+        _ => {} // required
+    }
+}
+
+fn local(x: LocalNonExhaustiveEnum) {
+    match x {
+        LocalNonExhaustiveEnum::Unit => {}
+        LocalNonExhaustiveEnum::Tuple(_) => {}
+        LocalNonExhaustiveEnum::Struct { .. } => {}
+        _ => {} //~ ERROR unreachable pattern
+    }
+}
+
+fn main() {}